@@ -0,0 +1,600 @@
+use crate::util::urlencode;
+use crate::{Client, Queue};
+use anyhow::{anyhow, Context, Error};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::json;
+use std::io;
+use std::time::Duration;
+use tokio::io::AsyncRead;
+use tokio_util::io::StreamReader;
+
+/// The maximum number of redirects to follow when downloading an artifact.  Reference artifacts
+/// may redirect to URLs that redirect again, but not indefinitely.
+const MAX_REDIRECTS: usize = 10;
+
+/// An artifact of a task run, as listed by the `listArtifacts` and `listLatestArtifacts`
+/// endpoints.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Artifact {
+    /// Name of the artifact, such as `public/logs/live.log`
+    pub name: String,
+
+    /// Expected content-type of the artifact
+    pub content_type: String,
+
+    /// How the artifact is stored, such as `s3`, `object`, `reference`, or `error`
+    pub storage_type: String,
+
+    /// The time at which the artifact expires
+    pub expires: DateTime<Utc>,
+}
+
+impl Artifact {
+    /// Keep only the artifacts whose names start with the given prefix, such as `public/`.
+    pub fn filter_by_prefix(artifacts: Vec<Artifact>, prefix: &str) -> Vec<Artifact> {
+        artifacts
+            .into_iter()
+            .filter(|artifact| artifact.name.starts_with(prefix))
+            .collect()
+    }
+}
+
+impl Queue {
+    /// Download an artifact from a specific run of a task, returning its content.
+    ///
+    /// This makes a signed request to the queue's `getArtifact` endpoint and then follows any
+    /// redirect to the artifact's storage location, with an unsigned request.  Artifacts served
+    /// directly by the queue are returned as-is.  Each request is retried as configured for the
+    /// client, and error responses are returned as [`ApiError`](crate::ApiError).
+    ///
+    /// The client's [`timeout`](crate::ClientBuilder::timeout) limits the wait for the storage
+    /// location to respond, and then for each part of the content, rather than the time taken to
+    /// download the whole artifact.
+    ///
+    /// The entire artifact is held in memory; for large artifacts, consider
+    /// [`download_artifact_stream`](crate::Queue::download_artifact_stream).
+    pub async fn download_artifact(
+        &self,
+        task_id: &str,
+        run_id: &str,
+        name: &str,
+    ) -> Result<Bytes, Error> {
+        let path = format!(
+            "task/{}/runs/{}/artifacts/{}",
+            urlencode(task_id),
+            urlencode(run_id),
+            urlencode(name)
+        );
+        let resp = self
+            .artifact_response(&path, name, self.0.timeout())
+            .await?;
+        read_content(resp, self.0.timeout()).await
+    }
+
+    /// Download an artifact from the latest run of a task, as for
+    /// [`download_artifact`](crate::Queue::download_artifact).
+    pub async fn download_latest_artifact(
+        &self,
+        task_id: &str,
+        name: &str,
+    ) -> Result<Bytes, Error> {
+        let path = format!("task/{}/artifacts/{}", urlencode(task_id), urlencode(name));
+        let resp = self
+            .artifact_response(&path, name, self.0.timeout())
+            .await?;
+        read_content(resp, self.0.timeout()).await
+    }
+
+    /// Download an artifact as for [`download_artifact`](crate::Queue::download_artifact), but
+    /// return a reader for its content instead of reading it into memory.  Errors while reading
+    /// the content are not retried.
+    pub async fn download_artifact_stream(
+        &self,
+        task_id: &str,
+        run_id: &str,
+        name: &str,
+    ) -> Result<impl AsyncRead, Error> {
+        let path = format!(
+            "task/{}/runs/{}/artifacts/{}",
+            urlencode(task_id),
+            urlencode(run_id),
+            urlencode(name)
+        );
+        let resp = self
+            .artifact_response(&path, name, self.0.timeout())
+            .await?;
+        Ok(StreamReader::new(content_stream(resp, self.0.timeout())))
+    }
+
+    /// List the artifacts of the latest run of a task, following pagination to get all of them.
+    ///
+    /// This fails if the results do not fit in the number of pages configured with
+    /// [`ClientBuilder::max_pages`](crate::ClientBuilder::max_pages).
+    pub async fn list_latest_artifacts(&self, task_id: &str) -> Result<Vec<Artifact>, Error> {
+        let path = format!("task/{}/artifacts", urlencode(task_id));
+        self.0
+            .request_all("GET", &path, None, "artifacts")
+            .await?
+            .into_iter()
+            .map(|artifact| {
+                serde_json::from_value(artifact).context("Invalid artifact in artifacts response")
+            })
+            .collect()
+    }
+
+    /// Upload an artifact for a specific run of a task, with the given content.
+    ///
+    /// This creates an `s3` artifact with the queue's `createArtifact` endpoint, and then uploads
+    /// the data to the returned `putUrl` with the required `Content-Type` and `Content-Length`
+    /// headers.  Both requests are retried as configured for the client.
+    pub async fn upload_artifact(
+        &self,
+        task_id: &str,
+        run_id: &str,
+        name: &str,
+        content_type: &str,
+        expires: &DateTime<Utc>,
+        data: &[u8],
+    ) -> Result<(), Error> {
+        let res = self
+            .createArtifact(
+                task_id,
+                run_id,
+                name,
+                &json!({
+                    "storageType": "s3",
+                    "contentType": content_type,
+                    "expires": expires,
+                }),
+            )
+            .await?;
+        let put_url = res
+            .get("putUrl")
+            .and_then(|u| u.as_str())
+            .ok_or_else(|| anyhow!("createArtifact response for {} has no putUrl", name))?;
+
+        let req = self
+            .0
+            .unsigned_request(Method::PUT, put_url.parse()?)
+            .header(CONTENT_TYPE, content_type)
+            .header(CONTENT_LENGTH, data.len())
+            .body(data.to_vec())
+            .build()?;
+        self.0.execute_with_retries(req).await?;
+        Ok(())
+    }
+
+    /// Get the response containing an artifact's content from the given queue path, following
+    /// redirects.  The request to the queue is limited to the given timeout, if any.  The requests
+    /// to the storage location have no timeout, since the content may take arbitrarily long to
+    /// read, but the wait for each response is limited to the given timeout.
+    pub(crate) async fn artifact_response(
+        &self,
+        path: &str,
+        name: &str,
+        timeout: Option<Duration>,
+    ) -> Result<reqwest::Response, Error> {
+        // a zero timeout means none
+        let queue_timeout = timeout.unwrap_or_else(|| Duration::from_secs(0));
+        let mut resp = self
+            .0
+            .request_with_timeout("GET", path, None, None, queue_timeout)
+            .await?;
+
+        for _ in 0..MAX_REDIRECTS {
+            if !resp.status().is_redirection() {
+                return Ok(resp);
+            }
+
+            let url = Client::redirect_location(&resp)
+                .ok_or_else(|| anyhow!("Redirect for artifact {} has no valid location", name))?;
+            let mut req = self.0.unsigned_request(Method::GET, url).build()?;
+            *req.timeout_mut() = None;
+            let response = self.0.execute_with_retries(req);
+            resp = match timeout {
+                Some(timeout) => tokio::time::timeout(timeout, response)
+                    .await
+                    .map_err(|_| anyhow!("Timed out waiting for storage of artifact {}", name))??,
+                None => response.await?,
+            };
+        }
+
+        Err(anyhow!("Too many redirects downloading artifact {}", name))
+    }
+}
+
+/// Stream the content of the given response, failing if any part of it takes longer than the
+/// given timeout, if any, to arrive.
+fn content_stream(
+    resp: reqwest::Response,
+    timeout: Option<Duration>,
+) -> impl Stream<Item = Result<Bytes, io::Error>> {
+    stream::unfold(Box::pin(resp.bytes_stream()), move |mut body| async move {
+        let chunk = match timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, body.next()).await {
+                Ok(chunk) => chunk,
+                Err(_) => {
+                    let err = io::Error::new(io::ErrorKind::TimedOut, "Timed out reading artifact");
+                    return Some((Err(err), body));
+                }
+            },
+            None => body.next().await,
+        };
+        // convert the Result::Err type to std::io::Error
+        chunk.map(|chunk| {
+            (
+                chunk.map_err(|e| io::Error::new(io::ErrorKind::Other, e)),
+                body,
+            )
+        })
+    })
+}
+
+/// Read the content of the given response, as for `content_stream`.
+async fn read_content(resp: reqwest::Response, timeout: Option<Duration>) -> Result<Bytes, Error> {
+    let content: Vec<u8> = content_stream(resp, timeout)
+        .try_fold(Vec::new(), |mut content, chunk| async move {
+            content.extend_from_slice(&chunk);
+            Ok(content)
+        })
+        .await?;
+    Ok(content.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err_status_code, ClientBuilder, Retry, StatusCode};
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+
+    const ARTIFACT_PATH: &str = "/api/queue/v1/task/abc/runs/0/artifacts/public%2Fdata.txt";
+
+    fn queue(server: &Server) -> Result<Queue, Error> {
+        let root_url = format!("http://{}", server.addr());
+        Queue::new(ClientBuilder::new(&root_url).retry(Retry {
+            retries: 2,
+            max_delay: Duration::from_millis(1),
+            ..Default::default()
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_get_artifact_redirect() -> Result<(), Error> {
+        let server = Server::run();
+        let body = json!({"storageType": "s3", "url": server.url_str("/storage/data.txt")});
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH)).respond_with(
+                status_code(303)
+                    .insert_header("location", server.url_str("/storage/data.txt"))
+                    .insert_header("content-type", "application/json")
+                    .body(body.to_string()),
+            ),
+        );
+
+        // the redirect is not followed, and its body is the result
+        let res = queue(&server)?
+            .getArtifact("abc", "0", "public/data.txt")
+            .await?;
+        assert_eq!(res, body);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_redirect() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH)).respond_with(
+                status_code(303)
+                    .insert_header("location", server.url_str("/storage/data.txt"))
+                    .insert_header("content-type", "application/json")
+                    .body("{}"),
+            ),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/storage/data.txt"),
+                // the storage request is not signed
+                request::headers(not(contains(key("authorization")))),
+            ])
+            .respond_with(status_code(200).body("hello, world")),
+        );
+
+        let data = queue(&server)?
+            .download_artifact("abc", "0", "public/data.txt")
+            .await?;
+        assert_eq!(&data[..], b"hello, world");
+        Ok(())
+    }
+
+    fn artifact_json(name: &str, storage_type: &str) -> serde_json::Value {
+        json!({
+            "name": name,
+            "contentType": "text/plain",
+            "storageType": storage_type,
+            "expires": "2030-01-01T00:00:00.000Z",
+        })
+    }
+
+    fn expect_artifact_pages(server: &Server) {
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/task/abc/artifacts"),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(
+                        json!({
+                            "artifacts": [
+                                artifact_json("public/logs/live.log", "reference"),
+                                artifact_json("public/build/target.zip", "s3"),
+                            ],
+                            "continuationToken": "page2",
+                        })
+                        .to_string(),
+                    ),
+            ),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/task/abc/artifacts"),
+                request::query(url_decoded(contains(("continuationToken", "page2")))),
+            ])
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(
+                        json!({
+                            "artifacts": [
+                                artifact_json("public/logs/live_backing.log", "s3"),
+                                artifact_json("private/secret.txt", "object"),
+                            ],
+                        })
+                        .to_string(),
+                    ),
+            ),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_latest_artifacts() -> Result<(), Error> {
+        let server = Server::run();
+        expect_artifact_pages(&server);
+
+        let artifacts = queue(&server)?.list_latest_artifacts("abc").await?;
+        let names: Vec<_> = artifacts.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "public/logs/live.log",
+                "public/build/target.zip",
+                "public/logs/live_backing.log",
+                "private/secret.txt",
+            ]
+        );
+        assert_eq!(
+            artifacts[1],
+            Artifact {
+                name: "public/build/target.zip".to_owned(),
+                content_type: "text/plain".to_owned(),
+                storage_type: "s3".to_owned(),
+                expires: "2030-01-01T00:00:00Z".parse()?,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_latest_artifacts_filter_by_prefix() -> Result<(), Error> {
+        let server = Server::run();
+        expect_artifact_pages(&server);
+
+        let artifacts = queue(&server)?.list_latest_artifacts("abc").await?;
+        let logs = Artifact::filter_by_prefix(artifacts, "public/logs/");
+        let names: Vec<_> = logs.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["public/logs/live.log", "public/logs/live_backing.log"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_direct() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH))
+                .respond_with(status_code(200).body("hello, world")),
+        );
+
+        let data = queue(&server)?
+            .download_artifact("abc", "0", "public/data.txt")
+            .await?;
+        assert_eq!(&data[..], b"hello, world");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_latest() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "GET",
+                "/api/queue/v1/task/abc/artifacts/public%2Fdata.txt",
+            ))
+            .respond_with(status_code(303).insert_header("location", "/storage/data.txt")),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/storage/data.txt"))
+                .respond_with(status_code(200).body("hello, world")),
+        );
+
+        let data = queue(&server)?
+            .download_latest_artifact("abc", "public/data.txt")
+            .await?;
+        assert_eq!(&data[..], b"hello, world");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_storage_retry() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH))
+                .respond_with(status_code(303).insert_header("location", "/storage/data.txt")),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/storage/data.txt"))
+                .times(2)
+                .respond_with(cycle![
+                    status_code(500),
+                    status_code(200).body("hello, world"),
+                ]),
+        );
+
+        let data = queue(&server)?
+            .download_artifact("abc", "0", "public/data.txt")
+            .await?;
+        assert_eq!(&data[..], b"hello, world");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_storage_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH))
+                .respond_with(status_code(303).insert_header("location", "/storage/data.txt")),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/storage/data.txt"))
+                .times(..)
+                .respond_with(delay_and_then(
+                    Duration::from_secs(5),
+                    status_code(200).body("hello, world"),
+                )),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let queue = Queue::new(
+            ClientBuilder::new(&root_url)
+                .timeout(Duration::from_millis(100))
+                .retry(Retry {
+                    retries: 0,
+                    ..Default::default()
+                }),
+        )?;
+
+        let err = queue
+            .download_artifact("abc", "0", "public/data.txt")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Timed out waiting for storage of artifact public/data.txt"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_not_found() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH))
+                .respond_with(status_code(404)),
+        );
+
+        let err = queue(&server)?
+            .download_artifact("abc", "0", "public/data.txt")
+            .await
+            .unwrap_err();
+        assert_eq!(err_status_code(&err), Some(StatusCode::NOT_FOUND));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upload() -> Result<(), Error> {
+        let server = Server::run();
+        let expires: DateTime<Utc> = "2030-01-01T00:00:00Z".parse()?;
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", ARTIFACT_PATH),
+                request::body(json_decoded(eq(json!({
+                    "storageType": "s3",
+                    "contentType": "text/plain",
+                    "expires": expires,
+                })))),
+            ])
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(
+                        json!({
+                            "storageType": "s3",
+                            "contentType": "text/plain",
+                            "expires": expires,
+                            "putUrl": server.url_str("/storage/data.txt?signature=sig"),
+                        })
+                        .to_string(),
+                    ),
+            ),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("PUT", "/storage/data.txt"),
+                request::query(url_decoded(contains(("signature", "sig")))),
+                request::headers(contains(("content-type", "text/plain"))),
+                request::headers(contains(("content-length", "12"))),
+                request::headers(not(contains(key("authorization")))),
+                request::body("hello, world"),
+            ])
+            .times(2)
+            .respond_with(cycle![status_code(503), status_code(200)]),
+        );
+
+        queue(&server)?
+            .upload_artifact(
+                "abc",
+                "0",
+                "public/data.txt",
+                "text/plain",
+                &expires,
+                b"hello, world",
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_stream() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH)).respond_with(
+                status_code(303).insert_header("location", server.url_str("/storage/data.txt")),
+            ),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/storage/data.txt"))
+                .respond_with(status_code(200).body("hello, world")),
+        );
+
+        let reader = queue(&server)?
+            .download_artifact_stream("abc", "0", "public/data.txt")
+            .await?;
+        tokio::pin!(reader);
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).await?;
+        assert_eq!(&data, b"hello, world");
+        Ok(())
+    }
+}
@@ -0,0 +1,664 @@
+use crate::util::collect_scopes;
+use crate::Auth;
+use anyhow::{anyhow, bail, Error};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The details of an incoming Hawk-signed request, for verification with
+/// [`Auth::authenticate_request`](crate::Auth::authenticate_request).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HawkRequest<'a> {
+    /// The HTTP method of the request, such as `GET`
+    pub method: &'a str,
+
+    /// The host to which the request was made, typically the `Host` header without any port
+    pub host: &'a str,
+
+    /// The port on which the request was received.  Behind a reverse proxy, this is typically
+    /// given in the `X-Forwarded-Port` header.
+    pub port: u16,
+
+    /// The path of the request, including any query string
+    pub resource: &'a str,
+
+    /// The `Authorization` header of the request, if it has one
+    pub authorization: Option<&'a str>,
+
+    /// The IP address from which the request was made, used only for audit logging
+    pub source_ip: Option<&'a str>,
+}
+
+/// The result of a successful call to
+/// [`Auth::authenticate_request`](crate::Auth::authenticate_request).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthResult {
+    /// The clientId that made the request, or None if the request had no credentials.  This is
+    /// for logging and auditing, and must not be used for access control.
+    pub client_id: Option<String>,
+
+    /// The scopes available to the request.  A request without credentials has only the scopes
+    /// granted to anonymous requests.
+    pub scopes: Vec<String>,
+
+    /// The time until which these scopes are valid
+    pub expires: DateTime<Utc>,
+
+    /// The payload hash from the `Authorization` header, if any
+    pub hash: Option<String>,
+}
+
+/// The error from [`Auth::authenticate_request`](crate::Auth::authenticate_request) when the
+/// auth service rejects the request, such as for an invalid signature or expired credentials.
+///
+/// `authenticate_request` returns `anyhow::Error`, which can be downcast to this type to tell a
+/// rejected request, which should be refused with a 401, apart from a failure to reach the auth
+/// service, which is an [`ApiError`](crate::ApiError).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticationFailed {
+    /// The auth service's explanation of why the request was rejected
+    pub message: String,
+}
+
+impl fmt::Display for AuthenticationFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Authentication failed: {}", self.message)
+    }
+}
+
+impl std::error::Error for AuthenticationFailed {}
+
+impl Auth {
+    /// Get the scopes this client effectively holds: the scopes of its credentials, as
+    /// restricted by any temporary credentials or authorized scopes, fully expanded.  The result
+    /// is sorted and contains no duplicates.
+    ///
+    /// This calls `currentScopes` and then `expandScopes`, so it is most useful as a diagnostic.
+    pub async fn effective_scopes(&self) -> Result<Vec<String>, Error> {
+        let current = self.currentScopes().await?;
+        let current = scopes_from(&current, "currentScopes")?;
+
+        let expanded = self.expandScopes(&json!({ "scopes": current })).await?;
+        let mut scopes = scopes_from(&expanded, "expandScopes")?;
+        scopes.sort();
+        scopes.dedup();
+        Ok(scopes)
+    }
+
+    /// Verify an incoming Hawk-signed request with the auth service's `authenticateHawk`
+    /// method, returning the client and scopes it was made with.  This does not check that the
+    /// request's scopes satisfy any requirement; that is up to the caller.
+    ///
+    /// If the auth service rejects the request, such as for an invalid signature, this returns
+    /// an [`AuthenticationFailed`] error with the service's explanation.
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// # async fn f() -> Result<()> {
+    /// use taskcluster::{Auth, ClientBuilder, HawkRequest};
+    ///
+    /// let auth = Auth::new(ClientBuilder::new("https://tc.example.com"))?;
+    /// let result = auth
+    ///     .authenticate_request(&HawkRequest {
+    ///         method: "GET",
+    ///         host: "my-service.example.com",
+    ///         port: 443,
+    ///         resource: "/things?limit=10",
+    ///         authorization: Some("Hawk id=\"...\", ts=\"...\", nonce=\"...\", mac=\"...\""),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// if !result.scopes.iter().any(|s| s == "my-service:list-things") {
+    ///     // reject the request
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn authenticate_request(
+        &self,
+        request: &HawkRequest<'_>,
+    ) -> Result<AuthResult, Error> {
+        let mut payload = json!({
+            "method": request.method.to_lowercase(),
+            "host": request.host,
+            "port": request.port,
+            "resource": request.resource,
+        });
+        if let Some(authorization) = request.authorization {
+            payload["authorization"] = json!(authorization);
+        }
+        if let Some(source_ip) = request.source_ip {
+            payload["sourceIp"] = json!(source_ip);
+        }
+
+        let res = self.authenticateHawk(&payload).await?;
+        match res.get("status").and_then(|s| s.as_str()) {
+            Some("auth-success") | Some("no-auth") => Ok(serde_json::from_value(res)?),
+            Some("auth-failed") => Err(AuthenticationFailed {
+                message: res
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("no message")
+                    .to_owned(),
+            }
+            .into()),
+            _ => bail!("Unexpected authenticateHawk response status"),
+        }
+    }
+
+    /// Create a URL for the given resource on this deployment, such as a private artifact,
+    /// which can be fetched with `GET` by a client without credentials until `expires`.  The
+    /// resource is given as a full URL, such as one made by `Queue::getArtifact_url`.
+    ///
+    /// The URL is signed with this client's credentials using a Hawk "bewit", a signature in the
+    /// URL's `bewit` query parameter, which Taskcluster services accept in place of an
+    /// `Authorization` header.  The request made with the URL has this client's scopes, as
+    /// restricted by any authorized scopes.  Taskcluster services accept bewits only for `GET`
+    /// requests.  This is computed locally, without calling the auth service.
+    pub fn create_signed_url(
+        &self,
+        resource: &str,
+        expires: DateTime<Utc>,
+    ) -> Result<String, Error> {
+        let url = reqwest::Url::parse(resource)
+            .map_err(|e| anyhow!("Invalid resource URL {}: {}", resource, e))?;
+        let ttl = (expires - Utc::now())
+            .to_std()
+            .map_err(|_| anyhow!("Expiration time {} is in the past", expires))?;
+        Ok(self.0.sign_url("GET", url, ttl)?.into())
+    }
+}
+
+/// An [`Auth`](crate::Auth) client that caches the results of `expandScopes`, for services that
+/// expand the same sets of scopes repeatedly.
+///
+/// Results are cached for the given time-to-live, keyed on the set of input scopes regardless
+/// of their order or any duplicates.  When the cache is full, the least-recently-used result is
+/// discarded.  A cached result does not reflect changes to roles made since it was fetched, so
+/// the TTL bounds how long such a change may go unnoticed.
+pub struct CachingAuth {
+    auth: Auth,
+    ttl: Duration,
+    capacity: usize,
+    cache: Mutex<ScopeCache>,
+}
+
+/// The cache for `CachingAuth`, mapping sorted input scopes to their expansion
+#[derive(Default)]
+struct ScopeCache {
+    entries: HashMap<Vec<String>, CachedExpansion>,
+
+    /// A counter incremented on each use of the cache, to track recency of use
+    clock: u64,
+}
+
+struct CachedExpansion {
+    scopes: Vec<String>,
+    fetched: Instant,
+    last_used: u64,
+}
+
+impl CachingAuth {
+    /// Wrap the given client, caching up to `capacity` expansions for `ttl` each.
+    pub fn new(auth: Auth, capacity: usize, ttl: Duration) -> Self {
+        Self {
+            auth,
+            ttl,
+            capacity,
+            cache: Mutex::new(ScopeCache::default()),
+        }
+    }
+
+    /// Get the wrapped client, for calls that are not cached.
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    /// Expand the given scopes, as for `expandScopes`, returning the expanded scopes.  If the
+    /// same set of scopes was expanded within the TTL, the cached result is returned without
+    /// calling the auth service.
+    pub async fn expand_scopes(
+        &self,
+        scopes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Vec<String>, Error> {
+        let mut key: Vec<String> = collect_scopes(scopes);
+        key.sort();
+        key.dedup();
+
+        if let Some(scopes) = self.cache.lock().unwrap().get(&key, self.ttl) {
+            return Ok(scopes);
+        }
+
+        let res = self.auth.expandScopes(&json!({ "scopes": key })).await?;
+        let scopes = scopes_from(&res, "expandScopes")?;
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(key, scopes.clone(), self.capacity);
+        Ok(scopes)
+    }
+}
+
+impl ScopeCache {
+    /// Get the cached expansion of the given scopes, if it is younger than `ttl`.
+    fn get(&mut self, key: &[String], ttl: Duration) -> Option<Vec<String>> {
+        self.clock += 1;
+        let clock = self.clock;
+        match self.entries.get_mut(key) {
+            Some(entry) if entry.fetched.elapsed() < ttl => {
+                entry.last_used = clock;
+                Some(entry.scopes.clone())
+            }
+            Some(_) => {
+                self.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache an expansion, first discarding the least-recently-used entry if the cache holds
+    /// `capacity` entries.
+    fn insert(&mut self, key: Vec<String>, scopes: Vec<String>, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        if self.entries.len() >= capacity && !self.entries.contains_key(&key) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.clock += 1;
+        self.entries.insert(
+            key,
+            CachedExpansion {
+                scopes,
+                fetched: Instant::now(),
+                last_used: self.clock,
+            },
+        );
+    }
+}
+
+/// Get the `scopes` property of a response from the given method.
+fn scopes_from(res: &Value, method: &str) -> Result<Vec<String>, Error> {
+    res.get("scopes")
+        .and_then(|s| s.as_array())
+        .ok_or_else(|| anyhow!("{} response has no scopes", method))?
+        .iter()
+        .map(|s| {
+            s.as_str()
+                .map(|s| s.to_owned())
+                .ok_or_else(|| anyhow!("{} response has a non-string scope", method))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::json_response;
+    use crate::{ApiError, ClientBuilder, Credentials, Retry};
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+
+    /// An httptest matcher that validates the `bewit` query parameter of a request made to the
+    /// given host and port, as a Taskcluster service would, and that there is no Authorization
+    /// header.
+    #[derive(Debug)]
+    struct ValidBewit(Credentials, String, u16);
+
+    impl<B> Matcher<httptest::http::Request<B>> for ValidBewit {
+        fn matches(
+            &mut self,
+            input: &httptest::http::Request<B>,
+            _ctx: &mut ExecutionContext,
+        ) -> bool {
+            if input.headers().contains_key("authorization") {
+                return false;
+            }
+            // the bewit is appended last, so the query that was signed precedes it
+            let path_and_query = input.uri().path_and_query().unwrap().as_str();
+            let (signed, bewit) = match path_and_query.rfind("bewit=") {
+                Some(i) => (&path_and_query[..i - 1], &path_and_query[i + 6..]),
+                None => return false,
+            };
+            let bewit: hawk::Bewit = match bewit.parse() {
+                Ok(bewit) => bewit,
+                Err(_) => return false,
+            };
+            let req = hawk::RequestBuilder::new("GET", &self.1, self.2, signed).request();
+            let key = hawk::Key::new(&self.0.access_token, hawk::SHA256).unwrap();
+            req.validate_bewit(&bewit, &key)
+        }
+
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            <Self as std::fmt::Debug>::fmt(self, f)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_signed_url() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        let addr = server.addr();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/task/abc/artifacts/private%2Flog.txt"),
+                ValidBewit(creds.clone(), addr.ip().to_string(), addr.port()),
+            ])
+            .times(1)
+            .respond_with(status_code(200).body("log")),
+        );
+        let root_url = format!("http://{}", addr);
+        let auth = Auth::new(ClientBuilder::new(&root_url).credentials(creds))?;
+
+        let resource = format!(
+            "{}/api/queue/v1/task/abc/artifacts/private%2Flog.txt",
+            root_url
+        );
+        let url = auth.create_signed_url(&resource, Utc::now() + chrono::Duration::hours(1))?;
+        assert!(url.starts_with(&format!("{}?bewit=", resource)));
+
+        // fetch the URL without credentials
+        let body = reqwest::get(&url).await?.error_for_status()?.text().await?;
+        assert_eq!(body, "log");
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_signed_url_other_deployment() -> Result<(), Error> {
+        let auth = Auth::new(
+            ClientBuilder::new("https://tc.example.com")
+                .credentials(Credentials::new("clientId", "accessToken")),
+        )?;
+        let expires = Utc::now() + chrono::Duration::hours(1);
+        assert!(auth
+            .create_signed_url("https://elsewhere.example.com/api/queue/v1/x", expires)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_signed_url_expired() -> Result<(), Error> {
+        let auth = Auth::new(
+            ClientBuilder::new("https://tc.example.com")
+                .credentials(Credentials::new("clientId", "accessToken")),
+        )?;
+        let expires = Utc::now() - chrono::Duration::hours(1);
+        let err = auth
+            .create_signed_url("https://tc.example.com/api/queue/v1/x", expires)
+            .unwrap_err();
+        assert!(err.to_string().contains("is in the past"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_effective_scopes() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/auth/v1/scopes/current"),
+                request::headers(contains(key("authorization"))),
+            ])
+            .times(1)
+            .respond_with(json_response(
+                json!({"scopes": ["assume:project:x", "queue:create-task:*"]}),
+            )),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/auth/v1/scopes/expand"),
+                request::body(json_decoded(eq(json!({
+                    "scopes": ["assume:project:x", "queue:create-task:*"],
+                })))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "scopes": [
+                    "queue:create-task:*",
+                    "assume:project:x",
+                    "secrets:get:project/x/*",
+                    "queue:create-task:*",
+                ],
+            }))),
+        );
+
+        let root_url = format!("http://{}", server.addr());
+        let auth = Auth::new(
+            ClientBuilder::new(&root_url).credentials(Credentials::new("a-client", "a-token")),
+        )?;
+        assert_eq!(
+            auth.effective_scopes().await?,
+            vec![
+                "assume:project:x",
+                "queue:create-task:*",
+                "secrets:get:project/x/*",
+            ]
+        );
+        Ok(())
+    }
+
+    fn hawk_request() -> HawkRequest<'static> {
+        HawkRequest {
+            method: "GET",
+            host: "svc.example.com",
+            port: 443,
+            resource: "/things?limit=10",
+            authorization: Some("Hawk id=\"a-client\""),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_request() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/auth/v1/authenticate-hawk"),
+                request::body(json_decoded(eq(json!({
+                    "method": "get",
+                    "host": "svc.example.com",
+                    "port": 443,
+                    "resource": "/things?limit=10",
+                    "authorization": "Hawk id=\"a-client\"",
+                })))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "status": "auth-success",
+                "scopes": ["svc:list-things"],
+                "scheme": "hawk",
+                "clientId": "a-client",
+                "expires": "2030-01-01T00:00:00.000Z",
+            }))),
+        );
+
+        let root_url = format!("http://{}", server.addr());
+        let auth = Auth::new(ClientBuilder::new(&root_url))?;
+        assert_eq!(
+            auth.authenticate_request(&hawk_request()).await?,
+            AuthResult {
+                client_id: Some("a-client".into()),
+                scopes: vec!["svc:list-things".into()],
+                expires: "2030-01-01T00:00:00Z".parse()?,
+                hash: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_request_no_auth() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "POST",
+                "/api/auth/v1/authenticate-hawk",
+            ))
+            .times(1)
+            .respond_with(json_response(json!({
+                "status": "no-auth",
+                "scheme": "none",
+                "scopes": ["assume:anonymous"],
+                "expires": "2030-01-01T00:00:00.000Z",
+            }))),
+        );
+
+        let root_url = format!("http://{}", server.addr());
+        let auth = Auth::new(ClientBuilder::new(&root_url))?;
+        let result = auth
+            .authenticate_request(&HawkRequest {
+                authorization: None,
+                ..hawk_request()
+            })
+            .await?;
+        assert_eq!(result.client_id, None);
+        assert_eq!(result.scopes, vec!["assume:anonymous".to_owned()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_request_failed() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "POST",
+                "/api/auth/v1/authenticate-hawk",
+            ))
+            .times(1)
+            .respond_with(json_response(json!({
+                "status": "auth-failed",
+                "message": "Bad mac",
+            }))),
+        );
+
+        let root_url = format!("http://{}", server.addr());
+        let auth = Auth::new(ClientBuilder::new(&root_url))?;
+        let err = auth
+            .authenticate_request(&hawk_request())
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Authentication failed: Bad mac");
+        assert_eq!(
+            err.downcast_ref::<AuthenticationFailed>(),
+            Some(&AuthenticationFailed {
+                message: "Bad mac".to_owned()
+            })
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_request_server_error() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "POST",
+                "/api/auth/v1/authenticate-hawk",
+            ))
+            .times(1)
+            .respond_with(status_code(500)),
+        );
+
+        let root_url = format!("http://{}", server.addr());
+        let auth = Auth::new(ClientBuilder::new(&root_url).retry(Retry {
+            retries: 0,
+            ..Default::default()
+        }))?;
+        let err = auth
+            .authenticate_request(&hawk_request())
+            .await
+            .unwrap_err();
+        // a failure to reach the auth service is not a rejection of the request
+        assert!(err.downcast_ref::<AuthenticationFailed>().is_none());
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::Status { code: 500, .. })
+        ));
+        Ok(())
+    }
+
+    fn caching_auth(server: &Server, capacity: usize, ttl: Duration) -> Result<CachingAuth, Error> {
+        let root_url = format!("http://{}", server.addr());
+        let auth = Auth::new(ClientBuilder::new(&root_url))?;
+        Ok(CachingAuth::new(auth, capacity, ttl))
+    }
+
+    fn expect_expansions(server: &Server, scopes: Value, times: usize) {
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/auth/v1/scopes/expand"),
+                request::body(json_decoded(eq(json!({ "scopes": scopes })))),
+            ])
+            .times(times)
+            .respond_with(json_response(json!({
+                "scopes": ["assume:project:x", "secrets:get:project/x/*"],
+            }))),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_caching_auth_hit() -> Result<(), Error> {
+        let server = Server::run();
+        expect_expansions(&server, json!(["assume:project:x", "queue:route:x"]), 1);
+
+        let auth = caching_auth(&server, 10, Duration::from_secs(60))?;
+        let expected = vec!["assume:project:x", "secrets:get:project/x/*"];
+        assert_eq!(
+            auth.expand_scopes(vec!["assume:project:x", "queue:route:x"])
+                .await?,
+            expected
+        );
+        // the same set of scopes, in a different order and with a duplicate, is cached
+        assert_eq!(
+            auth.expand_scopes(vec!["queue:route:x", "assume:project:x", "queue:route:x"])
+                .await?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_caching_auth_expired() -> Result<(), Error> {
+        let server = Server::run();
+        expect_expansions(&server, json!(["assume:project:x"]), 2);
+
+        let auth = caching_auth(&server, 10, Duration::from_secs(0))?;
+        auth.expand_scopes(vec!["assume:project:x"]).await?;
+        auth.expand_scopes(vec!["assume:project:x"]).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_caching_auth_evicts_least_recently_used() -> Result<(), Error> {
+        let server = Server::run();
+        expect_expansions(&server, json!(["a"]), 1);
+        expect_expansions(&server, json!(["b"]), 2);
+        expect_expansions(&server, json!(["c"]), 1);
+
+        let auth = caching_auth(&server, 2, Duration::from_secs(60))?;
+        auth.expand_scopes(vec!["a"]).await?;
+        auth.expand_scopes(vec!["b"]).await?;
+        auth.expand_scopes(vec!["a"]).await?; // cached, and now more recently used than b
+        auth.expand_scopes(vec!["c"]).await?; // evicts b
+        auth.expand_scopes(vec!["a"]).await?; // still cached
+        auth.expand_scopes(vec!["b"]).await?; // fetched again
+        Ok(())
+    }
+
+    #[test]
+    fn test_scopes_from_missing() {
+        let err = scopes_from(&json!({}), "currentScopes").unwrap_err();
+        assert_eq!(err.to_string(), "currentScopes response has no scopes");
+    }
+}
@@ -0,0 +1,178 @@
+/*!
+Blocking (synchronous) versions of the service clients, for use outside of an asynchronous
+runtime.  This module is only available with the `blocking` feature.
+
+Each type in this module has the same methods as the corresponding asynchronous type, with the
+same arguments, but blocks the current thread until the call is complete.  Requests are signed
+and retried exactly as for the asynchronous types, which these types wrap.  The `_paginated`
+methods return an iterator over the pages of results, rather than a stream, fetching each page
+as it is needed.
+
+Only the generated API methods, `health`, `is_alive`, and `with_authorized_scopes` have blocking
+versions.  The hand-written helpers on the asynchronous types, such as
+[`Queue::download_artifact`](crate::Queue::download_artifact) or
+[`Secrets::get_typed`](crate::Secrets::get_typed), are not available here.
+
+```
+# use httptest::{matchers::*, responders::*, Expectation, Server};
+# use anyhow::Result;
+# use serde_json::json;
+# fn main() -> Result<()> {
+# let server = Server::run();
+# server.expect(
+#    Expectation::matching(request::method_path("GET", "/api/auth/v1/clients/static%2Ftaskcluster%2Froot"))
+#   .respond_with(
+#       status_code(200)
+#       .append_header("Content-Type", "application/json")
+#       .body("{\"clientId\": \"static/taskcluster/root\"}"))
+# );
+# let root_url = format!("http://{}", server.addr());
+use taskcluster::blocking::Auth;
+let auth = Auth::new(root_url)?;
+let resp = auth.client("static/taskcluster/root")?;
+assert_eq!(resp, json!({"clientId": "static/taskcluster/root"}));
+# Ok(())
+# }
+```
+
+Each blocking client runs its own single-threaded Tokio runtime, so these types must not be used
+from within an asynchronous context.
+*/
+
+use anyhow::Error;
+use futures_util::stream::{Stream, StreamExt};
+use tokio::runtime::Runtime;
+
+pub use crate::generated::blocking::*;
+
+/// Create a runtime on which to run the asynchronous client methods.
+pub(crate) fn new_runtime() -> Result<Runtime, Error> {
+    Ok(tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?)
+}
+
+/// Iterate over the items of the given stream, blocking on the given runtime for each.
+pub(crate) fn block_on_stream<'a, S>(
+    runtime: &'a Runtime,
+    stream: S,
+) -> impl Iterator<Item = S::Item> + 'a
+where
+    S: Stream + 'a,
+{
+    let mut stream = Box::pin(stream);
+    std::iter::from_fn(move || runtime.block_on(stream.next()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ClientBuilder, Credentials, Retry};
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use std::time::Duration;
+
+    #[test]
+    fn test_ping() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                request::headers(contains(key("authorization"))),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let queue = Queue::new(
+            ClientBuilder::new(&root_url).credentials(Credentials::new("clientId", "accessToken")),
+        )?;
+        queue.ping()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(3)
+                .respond_with(cycle![status_code(500), status_code(500), status_code(200)]),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let queue = Queue::new(ClientBuilder::new(&root_url).retry(Retry {
+            retries: 2,
+            max_delay: Duration::from_millis(1),
+            ..Default::default()
+        }))?;
+        queue.ping()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_paginated() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/auth/v1/clients/"),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(r#"{"clients": [], "continuationToken": "page2"}"#),
+            ),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/auth/v1/clients/"),
+                request::query(url_decoded(contains(("continuationToken", "page2")))),
+            ])
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(r#"{"clients": []}"#),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let auth = Auth::new(&root_url)?;
+        let pages = auth
+            .listClients_paginated(None, None)
+            .collect::<Result<Vec<_>, Error>>()?;
+        assert_eq!(pages.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_authorized_scopes() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                request::headers(contains(key("authorization"))),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let queue = Queue::new(
+            ClientBuilder::new(&root_url).credentials(Credentials::new("clientId", "accessToken")),
+        )?;
+        queue.with_authorized_scopes(&["queue:ping"])?.ping()?;
+
+        let unsigned = Queue::new(&root_url)?;
+        assert!(unsigned.with_authorized_scopes(&["queue:ping"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_url() -> Result<(), Error> {
+        let queue = Queue::new("https://tc-tests.example.com")?;
+        assert_eq!(
+            queue.ping_url()?,
+            "https://tc-tests.example.com/api/queue/v1/ping"
+        );
+        Ok(())
+    }
+}
@@ -1,24 +1,181 @@
+use crate::concurrency::ConcurrencyLimit;
+use crate::credentials::certificate_expired;
+#[cfg(feature = "mock")]
+use crate::mock::MockTransport;
 use crate::retry::Backoff;
-use crate::util::collect_scopes;
-use crate::{Credentials, Retry};
+use crate::util::{collect_scopes, is_retryable, parse_retry_after, read_body};
+use crate::{ApiError, Credentials, Retry};
 use anyhow::{anyhow, bail, Context, Error, Result};
-use reqwest::header::HeaderValue;
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, LOCATION, USER_AGENT};
+use serde::Deserialize;
 use serde_json::json;
 use serde_json::Value;
+use std::future::Future;
 use std::iter::IntoIterator;
 use std::str::FromStr;
-use std::time::Duration;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+/// The response from a service's `ping` endpoint.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PingResponse {
+    /// True if the service is up
+    pub alive: bool,
+
+    /// The service's uptime, in seconds
+    pub uptime: f64,
+}
+
+/// Statistics about a request made with
+/// [`Client::request_with_stats`](crate::Client::request_with_stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RequestStats {
+    /// The number of times the request was sent, including the first try
+    pub attempts: u32,
+
+    /// The total time spent waiting between attempts
+    pub total_wait: Duration,
+}
+
+/// Options for a single request made with
+/// [`Client::request_with_options`](crate::Client::request_with_options), overriding the
+/// client's configuration for that request only.  Any combination of options can be given.
+///
+/// ```
+/// # use taskcluster::RequestOptions;
+/// # use std::time::Duration;
+/// let options = RequestOptions::new()
+///     .timeout(Duration::from_secs(5))
+///     .trace_id("my-job-123");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
+    authorized_scopes: Option<Vec<String>>,
+    trace_id: Option<String>,
+}
+
+impl RequestOptions {
+    /// Create options that change nothing about a request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the given timeout in place of the client's configured timeout.  The timeout applies
+    /// to each attempt, so a retried request may take longer than this in total.  A timeout of
+    /// zero means no timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Give up once the given deadline passes, such as the deadline of a larger operation of
+    /// which this request is a part.  Each attempt's timeout is limited to the time remaining
+    /// before the deadline, and no retry is begun that would start after it.  If the deadline
+    /// has already passed, the request is not sent at all.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Use the given authorized scopes in place of any configured for the client with
+    /// [`ClientBuilder::authorized_scopes`](crate::ClientBuilder::authorized_scopes).  This
+    /// restricts a single request to a narrower set of scopes, without building a new client.  A
+    /// client without credentials cannot make such a request.
+    pub fn authorized_scopes(
+        mut self,
+        authorized_scopes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        self.authorized_scopes = Some(collect_scopes(authorized_scopes));
+        self
+    }
+
+    /// Send the given trace ID in the request's `X-Taskcluster-Trace-Id` header, in place of any
+    /// configured with [`ClientBuilder::trace_id`](crate::ClientBuilder::trace_id).  The same
+    /// trace ID is sent with every retry of the request.  To trace each request separately,
+    /// generate a new ID, such as with `slugid::v4()`, and log it along with the request.
+    pub fn trace_id<S: Into<String>>(mut self, trace_id: S) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+}
+
+/// Metrics for a single attempt at a request, passed to the callback given to
+/// [`ClientBuilder::on_response`](crate::ClientBuilder::on_response).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetrics {
+    /// The name of the service, such as `queue`, or empty if the client has no service path
+    /// prefix
+    pub service: String,
+
+    /// The HTTP method of the request
+    pub method: String,
+
+    /// The path of the request URL
+    pub path: String,
+
+    /// The response status, or None if no response was received, such as for a timeout
+    pub status: Option<reqwest::StatusCode>,
+
+    /// The time from sending the request to receiving the response headers or an error
+    pub duration: Duration,
+}
+
+/// The minimum timeout for long-polling API methods such as `queue.claimWork`, which may wait
+/// about 20 seconds before responding.
+const LONG_POLL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The maximum time for which `is_alive` waits for a service to respond
+const IS_ALIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The `User-Agent` header sent with every request.
+const DEFAULT_USER_AGENT: &str = concat!("taskcluster-client-rust/", env!("CARGO_PKG_VERSION"));
+
+/// The header carrying a trace ID, used to correlate a request with the services' logs.
+const TRACE_ID_HEADER: &str = "x-taskcluster-trace-id";
+
+/// The maximum number of characters of a request or response body to log
+const MAX_LOGGED_BODY: usize = 1000;
 
 /// ClientBuilder implements the builder pattern for building a Client, allowing
 /// optional configuration of features such as authorized scopes and retry.
 #[derive(Default, Debug, Clone)]
 pub struct ClientBuilder {
     root_url: String,
+    is_base_url: bool,
     retry: Retry,
+    retry_on_429: bool,
+    max_elapsed_time: Option<Duration>,
     credentials: Option<Credentials>,
     path_prefix: Option<String>,
     authorized_scopes: Option<Vec<String>>,
     timeout: Duration,
+    connect_timeout: Duration,
+    proxies: Vec<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+    identity: Option<reqwest::Identity>,
+    danger_accept_invalid_certs: bool,
+    http_client: Option<reqwest::Client>,
+    user_agent_suffix: Option<String>,
+    default_headers: HeaderMap,
+    max_pages: usize,
+    http2_prior_knowledge: bool,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    cancellation_token: Option<CancellationToken>,
+    concurrency_limit: Option<ConcurrencyLimit>,
+    credentials_refresh: Option<CredentialsRefresh>,
+    dry_run: bool,
+    trace_id: Option<String>,
+    on_response: Option<ResponseCallback>,
+    retry_predicate: Option<RetryPredicate>,
+    max_response_bytes: Option<usize>,
+    #[cfg(feature = "mock")]
+    mock: Option<MockTransport>,
 }
 
 impl ClientBuilder {
@@ -28,44 +185,314 @@ impl ClientBuilder {
         Self {
             root_url: root_url.into(),
             timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            max_pages: 1000,
+            retry_on_429: true,
             ..Self::default()
         }
     }
 
+    /// Create a new ClientBuilder for the given base URL, to which request paths are joined
+    /// verbatim, such as `https://queue.taskcluster.net/v1/` for a legacy deployment, or a
+    /// service behind a non-standard path.  Unlike [`new`](crate::ClientBuilder::new), no
+    /// `api/<service>/<version>/` prefix is added, even when the builder is used to create a
+    /// service client such as [`Queue`](crate::Queue).
+    pub fn from_base_url<S: Into<String>>(base_url: S) -> Self {
+        Self {
+            is_base_url: true,
+            ..Self::new(base_url)
+        }
+    }
+
     /// Add credentials to the client
     pub fn credentials(mut self, credentials: Credentials) -> Self {
         self.credentials = Some(credentials);
         self
     }
 
+    /// Refresh expired temporary credentials with the given function.  When a request fails with
+    /// 401 Unauthorized and the client's credentials have a certificate that has expired (see
+    /// [`Credentials::is_expired`](crate::Credentials::is_expired)), the client calls this
+    /// function for fresh credentials, and makes the request again with them.  The fresh
+    /// credentials are used for all later requests, too.  If the function returns an error, the
+    /// request fails with that error.
+    ///
+    /// The function is called synchronously while the client waits, so it should return
+    /// promptly, such as by reading credentials that another task keeps up to date.  Concurrent
+    /// requests that find the credentials expired may each call it, after which they all use the
+    /// first credentials it returned.
+    pub fn credentials_refresh<F>(mut self, refresh: F) -> Self
+    where
+        F: Fn() -> Result<Credentials> + Send + Sync + 'static,
+    {
+        self.credentials_refresh = Some(CredentialsRefresh(Arc::new(refresh)));
+        self
+    }
+
+    /// Call the given function after each attempt at a request, including each retry, with
+    /// [`RequestMetrics`](crate::RequestMetrics) giving the attempt's service, method, path,
+    /// status, and duration.  This is useful for recording metrics, such as with Prometheus or
+    /// statsd.  The function is called synchronously, so it should return promptly.
+    pub fn on_response<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RequestMetrics) + Send + Sync + 'static,
+    {
+        self.on_response = Some(ResponseCallback(Arc::new(callback)));
+        self
+    }
+
+    /// Decide whether to retry each response with the given function, in place of the default
+    /// of retrying 5xx responses and, unless disabled with
+    /// [`retry_on_429`](crate::ClientBuilder::retry_on_429), 429 responses.  When the function
+    /// returns true, the response is retried as configured with
+    /// [`retry`](crate::ClientBuilder::retry), honoring any `Retry-After` header up to
+    /// [`Retry::max_delay`](crate::Retry::max_delay).  When it returns false, a 4xx or 5xx
+    /// response is returned as an error and any other response as usual.  Failures to get a
+    /// response at all are retried as usual.
+    ///
+    /// This allows, for example, retrying a 409 that is known to be transient, or never retrying
+    /// a slow endpoint.
+    pub fn retry_predicate<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&reqwest::Response) -> bool + Send + Sync + 'static,
+    {
+        self.retry_predicate = Some(RetryPredicate(Arc::new(predicate)));
+        self
+    }
+
     /// Set the retry configuration for the client
     pub fn retry(mut self, retry: Retry) -> Self {
         self.retry = retry;
         self
     }
 
+    /// Set whether to retry requests that fail with 429 (Too Many Requests).  If the response
+    /// has a `Retry-After` header, the retry waits for the duration it specifies, up to
+    /// [`Retry::max_delay`](crate::Retry::max_delay), instead of the usual backoff interval.  The
+    /// default is true.
+    pub fn retry_on_429(mut self, retry_on_429: bool) -> Self {
+        self.retry_on_429 = retry_on_429;
+        self
+    }
+
+    /// Set the maximum total time to spend on each request, including the time taken by each
+    /// try and the delays between them.  A retry that would begin after this time has elapsed is
+    /// not made, even if [`Retry::retries`](crate::Retry::retries) would allow it.  By default,
+    /// there is no limit.
+    pub fn max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = Some(max_elapsed_time);
+        self
+    }
+
     /// Set the timeout for each HTTP request made by the client.  The default is
-    /// 30 seconds.
+    /// 30 seconds.  A timeout of zero (`Duration::ZERO`) means no timeout.
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
     }
 
+    /// Set the timeout for establishing a connection to the server.  This is included in, and
+    /// should be shorter than, the overall [`timeout`](crate::ClientBuilder::timeout).  The
+    /// default is 10 seconds.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Send requests via the given proxy.  This may be called multiple times to configure
+    /// several proxies, which are consulted in order.  By default, the proxy configuration is
+    /// read from the standard environment variables such as `HTTPS_PROXY` and `NO_PROXY`; calling
+    /// this method disables that behavior.  This is ignored if
+    /// [`http_client`](crate::ClientBuilder::http_client) is used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Use the given `reqwest::Client` to make HTTP requests, instead of building a new one.  This
+    /// allows several clients, such as one for each service, to share a connection pool.
+    ///
+    /// The client built by default does not follow redirects, as Taskcluster APIs use redirect
+    /// responses to communicate URLs to the caller.  When supplying a client, you are responsible
+    /// for configuring it with `reqwest::redirect::Policy::none()`.  The timeout configured with
+    /// [`timeout`](crate::ClientBuilder::timeout) is applied to each request, but the
+    /// [`connect_timeout`](crate::ClientBuilder::connect_timeout) is not, and must be configured
+    /// on the supplied client.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Set the maximum number of idle connections to keep open to the deployment, for reuse by
+    /// later requests.  By default there is no limit, which suits most uses; a lower limit
+    /// saves resources for a client that makes bursts of many concurrent requests.  This is
+    /// ignored if [`http_client`](crate::ClientBuilder::http_client) is used.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Set how long an idle connection is kept open for reuse by later requests.  The default is
+    /// 90 seconds.  A longer timeout avoids reconnecting for a client that makes requests in
+    /// regular, widely-spaced bursts.  This is ignored if
+    /// [`http_client`](crate::ClientBuilder::http_client) is used.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Trust the given root certificate when verifying the deployment's TLS certificate, in
+    /// addition to the system's trusted roots.  This allows use of a deployment whose certificate
+    /// is issued by a private CA, or is self-signed.  This may be called multiple times to trust
+    /// several certificates.  This is ignored if
+    /// [`http_client`](crate::ClientBuilder::http_client) is used.
+    pub fn add_root_certificate(mut self, cert: reqwest::Certificate) -> Self {
+        self.root_certificates.push(cert);
+        self
+    }
+
+    /// Present the given client certificate to the deployment when connecting, for deployments
+    /// fronted by mutual TLS.  The identity can be read from a PKCS#12 archive with
+    /// [`reqwest::Identity::from_pkcs12_der`], or from PEM-encoded certificate and key with
+    /// [`reqwest::Identity::from_pkcs8_pem`].  This is ignored if
+    /// [`http_client`](crate::ClientBuilder::http_client) is used.
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Disable verification of TLS certificates, if `accept` is true.
+    ///
+    /// **Warning**: this is dangerous!  Any certificate is then trusted, including one presented
+    /// by an attacker intercepting the connection, who would see the client's requests and
+    /// could forge responses.  It is intended only for testing, such as against a staging
+    /// deployment with a self-signed certificate; prefer
+    /// [`add_root_certificate`](crate::ClientBuilder::add_root_certificate) where possible.
+    /// This is ignored if [`http_client`](crate::ClientBuilder::http_client) is used.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = accept;
+        self
+    }
+
+    /// Use HTTP/2 for all requests, without first negotiating it with the server.  This allows
+    /// many concurrent calls to share a single connection, but only works for deployments that
+    /// accept HTTP/2 directly, such as those behind an HTTP/2-capable load balancer.  By default,
+    /// HTTP/1.1 is used.  This is ignored if [`http_client`](crate::ClientBuilder::http_client) is
+    /// used.
+    ///
+    /// Hawk signatures cover the method, path, host, and port of the root URL, none of which
+    /// are affected by the HTTP version, so signed requests work the same over HTTP/2.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Append the given string to the `User-Agent` header sent with each request, which is
+    /// otherwise `taskcluster-client-rust/<version>`.  This allows applications using this crate
+    /// to identify themselves, for example with `my-app/1.2.3`.
+    pub fn user_agent_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Include the given headers in every request to the Taskcluster deployment, such as a header
+    /// required by a gateway in front of the deployment.  These headers are not sent with
+    /// requests to other URLs, such as artifact storage locations.  The `Authorization` header is
+    /// always replaced by the Hawk signature when the client has credentials.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Set the maximum number of pages fetched by
+    /// [`Client::request_all`](crate::Client::request_all), as a guard against runaway
+    /// pagination.  The default is 1000.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Stop making requests once the given token is cancelled, such as when an application is
+    /// shutting down.  An in-flight request, or a retry waiting to be made, then fails promptly
+    /// with [`ApiError::Cancelled`](crate::ApiError::Cancelled), as do any later requests.
+    /// Reading the body of a response that has already been returned is not affected.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Enable or disable dry-run mode.  In dry-run mode, requests other than `GET` and `HEAD` are
+    /// not sent.  Instead, the fully-built, signed request's method, URL, and body are logged at
+    /// `info` level (as a `tracing` event with the `tracing` feature, or with the `log` crate
+    /// otherwise), with the body redacted as for other logged bodies, and the request succeeds
+    /// with a `200 OK` response with an empty JSON object as its body.  Read-only requests are
+    /// sent as usual.  This is useful for checking what a tool would change before letting it do
+    /// so.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Send the given trace ID in the `X-Taskcluster-Trace-Id` header of every request, so that
+    /// the requests can be found in the services' logs.  This suits a client used for a single
+    /// job.  To use a different trace ID for a request, use
+    /// [`RequestOptions::trace_id`](crate::RequestOptions::trace_id) instead.
+    pub fn trace_id<S: Into<String>>(mut self, trace_id: S) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Limit the size of the response bodies read by the per-service methods, such as
+    /// `Queue::task`.  A response with a larger body fails with an error, rather than being
+    /// read into memory in full.  By default there is no limit.  This does not apply to
+    /// responses returned by [`Client::request`](crate::Client::request) and similar methods,
+    /// whose bodies are read by the caller, but it does apply to the body of any error response,
+    /// which the client reads into [`ApiError`](crate::ApiError); a larger error body is
+    /// omitted from the error.
+    pub fn max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Limit the number of requests in flight at once with the given
+    /// [`ConcurrencyLimit`](crate::ConcurrencyLimit), which may be shared with other clients.
+    /// Requests beyond the limit wait until others finish before they are sent.  By default
+    /// there is no limit.
+    pub fn concurrency_limit(mut self, limit: ConcurrencyLimit) -> Self {
+        self.concurrency_limit = Some(limit);
+        self
+    }
+
+    /// Handle all requests with the given [`MockTransport`](crate::mock::MockTransport), instead
+    /// of sending them over the network.  This is only available with the `mock` feature, and is
+    /// intended for testing.
+    #[cfg(feature = "mock")]
+    pub fn mock(mut self, mock: MockTransport) -> Self {
+        self.mock = Some(mock);
+        self
+    }
+
     /// Set the path_prefix; this will be included between the root URL and the path given to
     /// `request`, `make_url`, and `make_signed_url`.  This is typically used when building a
     /// client that will address a single service, such as `api/queue/v1/`.  The path prefix
     /// must not start with `/` and must end with a `/` character.  This is only for internal
     /// use in constructing service-specific clients that will always use the same path prefix.
+    /// The path prefix is ignored for a builder created with
+    /// [`from_base_url`](crate::ClientBuilder::from_base_url).
     pub(crate) fn path_prefix<S: Into<String>>(mut self, path_prefix: S) -> Self {
         let path_prefix = path_prefix.into();
         debug_assert!(path_prefix.ends_with('/'));
-        self.path_prefix = Some(path_prefix);
+        if !self.is_base_url {
+            self.path_prefix = Some(path_prefix);
+        }
         self
     }
 
     /// Set the authorized scopes for this client.  These will be passed along with request, and
     /// included in signed URLs, and will act as a limit on the scopes available for the operation
-    /// beyond those afforded by the credentials themselves.
+    /// beyond those afforded by the credentials themselves.  Since only signed requests carry
+    /// authorized scopes, building a client with authorized scopes but no
+    /// [`credentials`](crate::ClientBuilder::credentials) fails.
     pub fn authorized_scopes(
         mut self,
         authorized_scopes: impl IntoIterator<Item = impl AsRef<str>>,
@@ -79,6 +506,48 @@ impl ClientBuilder {
     pub fn build(self) -> Result<Client> {
         Client::new(self)
     }
+
+    /// Build the reqwest client configured by this builder, or return the one provided with
+    /// `http_client`.  The connect timeout is configured here, while the overall timeout is
+    /// applied per-request instead, so that it can be disabled or overridden for a single
+    /// request.
+    fn build_http_client(&self) -> Result<reqwest::Client> {
+        if let Some(ref client) = self.http_client {
+            return Ok(client.clone());
+        }
+        let mut builder = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .connect_timeout(self.connect_timeout);
+        for proxy in self.proxies.iter().cloned() {
+            builder = builder.proxy(proxy);
+        }
+        for cert in self.root_certificates.iter().cloned() {
+            builder = builder.add_root_certificate(cert);
+        }
+        if let Some(ref identity) = self.identity {
+            builder = builder.identity(identity.clone());
+        }
+        if self.danger_accept_invalid_certs {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        Ok(builder.build()?)
+    }
+
+    /// Build the reqwest client now, so that all clients built from clones of this builder share
+    /// it, and with it a connection pool.
+    pub(crate) fn share_http_client(mut self) -> Result<Self> {
+        self.http_client = Some(self.build_http_client()?);
+        Ok(self)
+    }
 }
 
 impl From<String> for ClientBuilder {
@@ -97,16 +566,28 @@ impl From<&str> for ClientBuilder {
 /// contains authentication credentials, and a service endpoint, which are
 /// required for all HTTP operations.
 pub struct Client {
-    /// The credentials associated with this client and used for requests.
-    /// If None, then unauthenticated requests are made.
-    credentials: Option<hawk::Credentials>,
+    /// The state used to sign requests, replaced when the credentials are refreshed
+    signing: RwLock<Arc<Signing>>,
 
-    /// The `ext` string for any requests made by this client, if any
-    ext: Option<String>,
+    /// The function to refresh expired credentials, if any
+    credentials_refresh: Option<CredentialsRefresh>,
+
+    /// The authorized scopes for this client, if any, kept for `clone_with_credentials` and for
+    /// refreshing credentials
+    authorized_scopes: Option<Vec<String>>,
 
     /// Retry information.
     retry: Retry,
 
+    /// If true, retry 429 responses
+    retry_on_429: bool,
+
+    /// Maximum total time to spend on a request, including retries
+    max_elapsed_time: Option<Duration>,
+
+    /// Timeout for each HTTP request
+    timeout: Duration,
+
     /// The base URL for requests to the selected service / api version
     base_url: reqwest::Url,
 
@@ -118,6 +599,40 @@ pub struct Client {
 
     /// Reqwest client
     client: reqwest::Client,
+
+    /// The `User-Agent` header for each request
+    user_agent: String,
+
+    /// Headers to include in each request to the deployment
+    default_headers: HeaderMap,
+
+    /// Maximum number of pages for `request_all`
+    max_pages: usize,
+
+    /// Token which, when cancelled, stops all requests
+    cancellation_token: Option<CancellationToken>,
+
+    /// Limit on requests in flight, possibly shared with other clients
+    concurrency_limit: Option<ConcurrencyLimit>,
+
+    /// If true, log non-read-only requests instead of sending them
+    dry_run: bool,
+
+    /// The `X-Taskcluster-Trace-Id` header for each request, if configured
+    trace_id: Option<HeaderValue>,
+
+    /// Function to call with metrics for each attempt at a request
+    on_response: Option<ResponseCallback>,
+
+    /// Function deciding whether to retry a response, replacing the default
+    retry_predicate: Option<RetryPredicate>,
+
+    /// Maximum size of a response body read by `request_json`
+    max_response_bytes: Option<usize>,
+
+    /// Mock transport, replacing the reqwest client
+    #[cfg(feature = "mock")]
+    mock: Option<MockTransport>,
 }
 
 impl Client {
@@ -125,22 +640,35 @@ impl Client {
     /// [`ClientBuilder::build`](crate::ClientBuilder::build))
     fn new(b: ClientBuilder) -> Result<Client> {
         // In general, try to pre-compute as much as possible here, so that later requests and
-        // URL-generation operations are as fast as possible.  Once created, a Client is immutable.
+        // URL-generation operations are as fast as possible.  Once created, a Client is immutable,
+        // except that its credentials may be refreshed.
+
+        // build a reqwest client, unless one was provided; this will also handle connection
+        // re-use.  Note that the host and port used for Hawk signatures are always those of the
+        // root URL, even if the request is sent via a proxy.
+        let client = b.build_http_client()?;
 
         // build a base_url containing both the root URL and any path_prefix.  This allows
         // service-specific clients to provide only the portion of the path specific to
-        // the API method being invoked.
-        let mut base_url = reqwest::Url::parse(b.root_url.as_ref())
-            .context(format!("while parsing {}", b.root_url))?;
+        // the API method being invoked.  The root URL may include a path, for deployments served
+        // under a path prefix, so it is normalized to end with exactly one `/` in order that
+        // joining relative paths to it preserves that path.
+        let root_url = format!("{}/", b.root_url.trim_end_matches('/'));
+        let mut base_url =
+            reqwest::Url::parse(&root_url).context(format!("while parsing {}", b.root_url))?;
 
-        let host = base_url
-            .host_str()
-            .ok_or_else(|| anyhow!("The root URL {} doesn't contain a host", b.root_url))?
-            .to_owned();
+        if base_url.scheme() != "http" && base_url.scheme() != "https" {
+            bail!("The root URL {} must be an http or https URL", b.root_url);
+        }
+        if base_url.query().is_some() || base_url.fragment().is_some() {
+            bail!(
+                "The root URL {} must not contain a query or fragment",
+                b.root_url
+            );
+        }
 
-        let port = base_url
-            .port_or_known_default()
-            .ok_or_else(|| anyhow!("Unkown port for protocol {}", base_url.scheme()))?;
+        let (host, port) = hawk_host_and_port(&base_url)
+            .context(format!("while parsing root URL {}", b.root_url))?;
 
         if let Some(path_prefix) = b.path_prefix {
             base_url = base_url.join(path_prefix.as_ref()).context(format!(
@@ -149,68 +677,160 @@ impl Client {
             ))?;
         }
 
+        let user_agent = match b.user_agent_suffix {
+            Some(suffix) => format!("{} {}", DEFAULT_USER_AGENT, suffix),
+            None => DEFAULT_USER_AGENT.to_owned(),
+        };
+
+        let trace_id = b
+            .trace_id
+            .map(|id| HeaderValue::from_str(&id).context(format!("invalid trace ID {:?}", id)))
+            .transpose()?;
+
         let retry = b.retry;
         let timeout = b.timeout;
 
-        // build a reqwest client with the timeout configuration; this will also handle
-        // connection re-use.
-        let client = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::none())
-            .timeout(timeout)
-            .build()?;
-
-        // figure out the `certificate` and `authorizedScopes` parts of the ext property
-        let mut certificate: Option<Value> = None;
-        if let Some(Credentials {
-            certificate: Some(ref cert_str),
-            ..
-        }) = b.credentials
-        {
-            certificate = Some(
-                serde_json::from_str(cert_str)
-                    .context("while parsing given certificate as JSON")?,
+        if b.authorized_scopes.is_some() && b.credentials.is_none() {
+            bail!(
+                "Authorized scopes require credentials, since unsigned requests cannot carry them"
             );
         }
-
-        let mut authorized_scopes: Option<Value> = None;
-        if let Some(scopes) = b.authorized_scopes {
-            authorized_scopes = Some(scopes.into());
-        }
-
-        let ext_json = match (certificate, authorized_scopes) {
-            (Some(c), None) => Some(json!({ "certificate": c })),
-            (None, Some(s)) => Some(json!({ "authorizedScopes": s })),
-            (Some(c), Some(s)) => Some(json!({ "certificate": c, "authorizedScopes": s })),
-            (None, None) => None,
-        };
-
-        let ext = if let Some(ext) = ext_json {
-            let ext_str = serde_json::to_string(&ext)?;
-            Some(base64::encode_config(ext_str, base64::URL_SAFE_NO_PAD))
-        } else {
-            None
-        };
-
-        // pre-generate the hawk::Credentials struct we will use to sign requests
-        let credentials = match b.credentials {
-            None => None,
-            Some(c) => Some(hawk::Credentials {
-                id: c.client_id.clone(),
-                key: hawk::Key::new(&c.access_token, hawk::SHA256).context(c.client_id)?,
-            }),
-        };
+        let signing = prepare_credentials(b.credentials, b.authorized_scopes.as_ref())?;
 
         Ok(Client {
-            credentials,
-            ext,
+            signing: RwLock::new(Arc::new(signing)),
+            credentials_refresh: b.credentials_refresh,
+            authorized_scopes: b.authorized_scopes,
             retry,
+            retry_on_429: b.retry_on_429,
+            max_elapsed_time: b.max_elapsed_time,
+            timeout,
             base_url,
             host,
             port,
             client,
+            user_agent,
+            default_headers: b.default_headers,
+            max_pages: b.max_pages,
+            cancellation_token: b.cancellation_token,
+            concurrency_limit: b.concurrency_limit,
+            dry_run: b.dry_run,
+            trace_id,
+            on_response: b.on_response,
+            retry_predicate: b.retry_predicate,
+            max_response_bytes: b.max_response_bytes,
+            #[cfg(feature = "mock")]
+            mock: b.mock,
         })
     }
 
+    /// Create a client for the given base URL, as for
+    /// [`ClientBuilder::from_base_url`](crate::ClientBuilder::from_base_url), with the given
+    /// credentials, if any, and retry configuration.
+    pub fn from_base_url(
+        base_url: &str,
+        credentials: Option<Credentials>,
+        retry: Retry,
+    ) -> Result<Client> {
+        let mut builder = ClientBuilder::from_base_url(base_url).retry(retry);
+        if let Some(credentials) = credentials {
+            builder = builder.credentials(credentials);
+        }
+        builder.build()
+    }
+
+    /// Create a copy of this client that uses the given credentials, or makes unauthenticated
+    /// requests if None, but is otherwise identical.  This is useful when credentials are
+    /// refreshed, such as a worker's temporary credentials.  The new client shares this client's
+    /// connection pool, and keeps any authorized scopes it was configured with, but not any
+    /// [`credentials_refresh`](crate::ClientBuilder::credentials_refresh) function, since that
+    /// supplies credentials in place of this client's.  This fails for None if the client has
+    /// authorized scopes, since unsigned requests cannot carry them.
+    pub fn clone_with_credentials(&self, credentials: Option<Credentials>) -> Result<Client> {
+        if self.authorized_scopes.is_some() && credentials.is_none() {
+            bail!(
+                "Authorized scopes require credentials, since unsigned requests cannot carry them"
+            );
+        }
+        let signing = prepare_credentials(credentials, self.authorized_scopes.as_ref())?;
+        Ok(self.clone_with_signing(Arc::new(signing), self.authorized_scopes.clone(), None))
+    }
+
+    /// Create a copy of this client whose requests carry the given authorized scopes, in place
+    /// of any it was configured with, but is otherwise identical.  This is useful for making a
+    /// series of requests restricted to a narrower set of scopes; for a single request, use
+    /// [`request_with_scopes`](crate::Client::request_with_scopes).  The new client shares this
+    /// client's credentials and connection pool, and refreshes its credentials with the same
+    /// [`credentials_refresh`](crate::ClientBuilder::credentials_refresh) function, if any.  This
+    /// fails if the client has no credentials, since unsigned requests cannot carry scopes.
+    pub fn with_authorized_scopes(
+        &self,
+        scopes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Client> {
+        let scopes: Vec<String> = collect_scopes(scopes);
+        let signing = self.signing();
+        if signing.credentials.is_none() {
+            bail!(
+                "Authorized scopes require credentials, since unsigned requests cannot carry them"
+            );
+        }
+        let signing = Signing {
+            credentials: signing.credentials.clone(),
+            certificate: signing.certificate.clone(),
+            ext: make_ext(signing.certificate.as_ref(), Some(scopes.clone()))?,
+        };
+        Ok(self.clone_with_signing(
+            Arc::new(signing),
+            Some(scopes),
+            self.credentials_refresh.clone(),
+        ))
+    }
+
+    /// Create an identical copy of this client, sharing its current credentials and connection
+    /// pool, such as for use in a background task.
+    pub(crate) fn duplicate(&self) -> Client {
+        self.clone_with_signing(
+            self.signing(),
+            self.authorized_scopes.clone(),
+            self.credentials_refresh.clone(),
+        )
+    }
+
+    /// Create a copy of this client with the given signing state, authorized scopes, and
+    /// credentials refresh function, but otherwise identical.
+    fn clone_with_signing(
+        &self,
+        signing: Arc<Signing>,
+        authorized_scopes: Option<Vec<String>>,
+        credentials_refresh: Option<CredentialsRefresh>,
+    ) -> Client {
+        Client {
+            signing: RwLock::new(signing),
+            credentials_refresh,
+            authorized_scopes,
+            retry: self.retry.clone(),
+            retry_on_429: self.retry_on_429,
+            max_elapsed_time: self.max_elapsed_time,
+            timeout: self.timeout,
+            base_url: self.base_url.clone(),
+            host: self.host.clone(),
+            port: self.port,
+            client: self.client.clone(),
+            user_agent: self.user_agent.clone(),
+            default_headers: self.default_headers.clone(),
+            max_pages: self.max_pages,
+            cancellation_token: self.cancellation_token.clone(),
+            concurrency_limit: self.concurrency_limit.clone(),
+            dry_run: self.dry_run,
+            trace_id: self.trace_id.clone(),
+            on_response: self.on_response.clone(),
+            retry_predicate: self.retry_predicate.clone(),
+            max_response_bytes: self.max_response_bytes,
+            #[cfg(feature = "mock")]
+            mock: self.mock.clone(),
+        }
+    }
+
     /// Make a request to a Taskcluster deployment.  While the per-service methods are generally
     /// more convenient, this method can be used to call a path on the deployment directly.
     ///
@@ -218,7 +838,8 @@ impl Client {
     /// with `/`.
     ///
     /// This will automatically retry on server-side errors and return an error for client errors.
-    /// Success and redirection responses are treated as OK.
+    /// Success and redirection responses are treated as OK.  Errors can be downcast to
+    /// [`ApiError`](crate::ApiError).
     pub async fn request(
         &self,
         method: &str,
@@ -226,98 +847,791 @@ impl Client {
         query: Option<Vec<(&str, &str)>>,
         body: Option<&Value>,
     ) -> Result<reqwest::Response, Error> {
-        let mut backoff = Backoff::new(&self.retry);
-
-        let req = self.build_request(method, path, query, body)?;
-        let url = req.url().as_str();
+        self.request_with_timeout(method, path, query, body, self.timeout)
+            .await
+    }
 
-        let mut retries = self.retry.retries;
-        loop {
-            let req = req
-                .try_clone()
-                .ok_or_else(|| anyhow!("Cannot clone the request {}", url))?;
+    /// Make a request as for [`request`](crate::Client::request), but with the given timeout in
+    /// place of the client's configured timeout.  The timeout applies to each attempt, so a
+    /// retried request may take longer than this in total.  A timeout of zero means no timeout.
+    pub async fn request_with_timeout(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+        timeout: Duration,
+    ) -> Result<reqwest::Response, Error> {
+        let options = RequestOptions::new().timeout(timeout);
+        let (resp, _) = self
+            .request_with_options(method, path, query, body, &options)
+            .await?;
+        Ok(resp)
+    }
 
-            let retry_for;
-            match self.client.execute(req).await {
-                // From the request docs for Client::execute:
-                // > This method fails if there was an error while sending request, redirect loop
-                // > was detected or redirect limit was exhausted.
-                // All cases where there's a successful HTTP response are Ok(..).
-                Err(e) => {
-                    retry_for = e;
-                }
+    /// Make a request as for [`request`](crate::Client::request), but giving up once the given
+    /// deadline passes, such as the deadline of a larger operation of which this request is a
+    /// part.  Each attempt's timeout is limited to the time remaining before the deadline, and
+    /// no retry is begun that would start after it.  If the deadline has already passed, the
+    /// request is not sent at all.
+    pub async fn request_with_deadline(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+        deadline: Instant,
+    ) -> Result<reqwest::Response, Error> {
+        let options = RequestOptions::new().deadline(deadline);
+        let (resp, _) = self
+            .request_with_options(method, path, query, body, &options)
+            .await?;
+        Ok(resp)
+    }
 
-                // Retry for server errors
-                Ok(resp) if resp.status().is_server_error() => {
-                    retry_for = resp.error_for_status().err().unwrap();
-                }
+    /// Make a request as for [`request`](crate::Client::request), also returning statistics
+    /// about the request, such as the number of attempts made.  A request that succeeds only
+    /// after retrying transient errors otherwise looks the same as one that succeeded
+    /// immediately.
+    pub async fn request_with_stats(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+    ) -> Result<(reqwest::Response, RequestStats), Error> {
+        self.request_with_options(method, path, query, body, &RequestOptions::new())
+            .await
+    }
 
-                // client errors do not get retried
-                Ok(resp) if resp.status().is_client_error() => {
-                    let err = resp.error_for_status_ref().err().unwrap();
-
-                    // try to add context based on the message from the JSON body, falling back
-                    // to just returning the reqwest::Error
-                    if let Ok(json) = resp.json::<Value>().await {
-                        if let Some(message) = json.get("message") {
-                            if let Some(s) = message.as_str() {
-                                return Err(Error::from(err).context(s.to_owned()));
-                            }
+    /// Make a request as for [`request`](crate::Client::request), with the given
+    /// [`RequestOptions`](crate::RequestOptions) overriding the client's configuration, such as
+    /// its timeout, authorized scopes, or trace ID, for this request only.  Statistics about the
+    /// request are returned along with the response, as for
+    /// [`request_with_stats`](crate::Client::request_with_stats).
+    pub async fn request_with_options(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+        options: &RequestOptions,
+    ) -> Result<(reqwest::Response, RequestStats), Error> {
+        let trace_id = match options.trace_id {
+            Some(ref trace_id) => Some(
+                HeaderValue::from_str(trace_id)
+                    .context(format!("invalid trace ID {:?}", trace_id))?,
+            ),
+            None => None,
+        };
+        let timeout = options.timeout.unwrap_or(self.timeout);
+        self.execute_signed_until(
+            |signing| {
+                let scoped_ext;
+                let ext = match options.authorized_scopes {
+                    Some(ref scopes) => {
+                        if signing.credentials.is_none() {
+                            bail!("Authorized scopes require credentials, since unsigned requests cannot carry them");
                         }
+                        scoped_ext = make_ext(signing.certificate.as_ref(), Some(scopes.clone()))?;
+                        scoped_ext.as_deref()
                     }
-                    return Err(err.into());
-                }
-
-                Ok(resp) => {
-                    return Ok(resp);
+                    None => signing.ext.as_deref(),
+                };
+                let mut req =
+                    self.build_request(signing, method, path, query.clone(), body, ext)?;
+                *req.timeout_mut() = request_timeout(timeout);
+                if let Some(ref trace_id) = trace_id {
+                    req.headers_mut().insert(TRACE_ID_HEADER, trace_id.clone());
                 }
-            };
+                Ok(req)
+            },
+            options.deadline,
+        )
+        .await
+    }
 
-            // if we got here, we are going to retry, or return the error if we are done
-            // retrying.
+    /// Make a request as for [`request`](crate::Client::request), but with a raw body of the
+    /// given content type, such as `application/octet-stream`, in place of a JSON body.  The
+    /// request is signed with a hash of the body using that content type.
+    pub async fn request_with_body(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> Result<reqwest::Response, Error> {
+        let body = Bytes::from(body);
+        let (resp, _) = self
+            .execute_signed(|signing| {
+                let body = Some((content_type, body.clone()));
+                let ext = signing.ext.as_deref();
+                self.build_raw_request(signing, method, path, query.clone(), body, ext)
+            })
+            .await?;
+        Ok(resp)
+    }
 
-            if retries == 0 {
-                return Err(retry_for.into());
-            }
-            retries -= 1;
+    /// The client's timeout for each HTTP request, if any.
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        request_timeout(self.timeout)
+    }
 
-            match backoff.next_backoff() {
-                Some(duration) => tokio::time::sleep(duration).await,
-                None => return Err(retry_for.into()),
-            }
+    /// The timeout for long-polling API methods: the client's configured timeout, but at least
+    /// long enough for the service to respond without any results.  A client with no timeout
+    /// has none for long-polling, either.
+    pub(crate) fn long_poll_timeout(&self) -> Duration {
+        match request_timeout(self.timeout) {
+            Some(timeout) => std::cmp::max(timeout, LONG_POLL_TIMEOUT),
+            None => self.timeout,
         }
     }
 
-    fn build_request(
+    /// Make a request as for [`request`](crate::Client::request), but with the given authorized
+    /// scopes in place of any configured for the client with
+    /// [`ClientBuilder::authorized_scopes`](crate::ClientBuilder::authorized_scopes).  This
+    /// restricts a single request to a narrower set of scopes, without building a new client.  A
+    /// client without credentials cannot make such a request.
+    pub async fn request_with_scopes(
         &self,
         method: &str,
         path: &str,
         query: Option<Vec<(&str, &str)>>,
         body: Option<&Value>,
-    ) -> Result<reqwest::Request, Error> {
-        if path.starts_with('/') {
-            bail!("Request path must not begin with `/`");
+        scopes: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<reqwest::Response, Error> {
+        let options = RequestOptions::new().authorized_scopes(scopes);
+        let (resp, _) = self
+            .request_with_options(method, path, query, body, &options)
+            .await?;
+        Ok(resp)
+    }
+
+    /// Begin an unsigned request to an arbitrary URL, such as the storage location of an
+    /// artifact.  Execute the resulting request with `execute_with_retries` to get the same retry
+    /// and error handling as [`request`](crate::Client::request).
+    pub(crate) fn unsigned_request(
+        &self,
+        method: reqwest::Method,
+        url: reqwest::Url,
+    ) -> reqwest::RequestBuilder {
+        let req = self
+            .client
+            .request(method, url)
+            .header(USER_AGENT, &self.user_agent);
+        match request_timeout(self.timeout) {
+            Some(timeout) => req.timeout(timeout),
+            None => req,
         }
+    }
 
-        let mut url = self.base_url.join(path)?;
+    /// The client's current signing state.
+    fn signing(&self) -> Arc<Signing> {
+        self.signing.read().unwrap().clone()
+    }
 
-        if let Some(q) = query {
-            url.query_pairs_mut().extend_pairs(q);
+    /// Build a request with the client's current signing state and execute it, retrying as
+    /// configured.  If the request fails with 401 Unauthorized because the client's temporary
+    /// credentials have expired, and the client has a
+    /// [`credentials_refresh`](crate::ClientBuilder::credentials_refresh) function, the
+    /// credentials are refreshed and the request is built and executed once more.
+    async fn execute_signed<F>(&self, build: F) -> Result<(reqwest::Response, RequestStats), Error>
+    where
+        F: Fn(&Signing) -> Result<reqwest::Request, Error>,
+    {
+        self.execute_signed_until(build, None).await
+    }
+
+    /// Build and execute a request as for `execute_signed`, with no retries begun after the
+    /// given deadline, if any.
+    async fn execute_signed_until<F>(
+        &self,
+        build: F,
+        deadline: Option<Instant>,
+    ) -> Result<(reqwest::Response, RequestStats), Error>
+    where
+        F: Fn(&Signing) -> Result<reqwest::Request, Error>,
+    {
+        let signing = self.signing();
+        match self.execute_with_stats(build(&signing)?, deadline).await {
+            Err(err)
+                if self.credentials_refresh.is_some()
+                    && signing.is_expired()
+                    && crate::err_status_code(&err) == Some(reqwest::StatusCode::UNAUTHORIZED) =>
+            {
+                let signing = self.refresh_credentials(&signing)?;
+                self.execute_with_stats(build(&signing)?, deadline).await
+            }
+            res => res,
+        }
+    }
+
+    /// Replace the given expired signing state with one for fresh credentials from the
+    /// client's `credentials_refresh` function.  If another request has already done so, its
+    /// result is used instead, so that concurrent requests go on with the same credentials.
+    fn refresh_credentials(&self, expired: &Arc<Signing>) -> Result<Arc<Signing>> {
+        let refresh = match self.credentials_refresh {
+            Some(ref refresh) => refresh,
+            None => bail!("Client has no credentials_refresh function"),
+        };
+
+        {
+            let signing = self.signing.read().unwrap();
+            if !Arc::ptr_eq(&signing, expired) {
+                return Ok(signing.clone());
+            }
+        }
+
+        // the function is called without holding the lock, so that it does not block other
+        // requests, and a panic in it does not poison the lock
+        let credentials = (refresh.0)().context("while refreshing expired credentials")?;
+        let fresh = Arc::new(prepare_credentials(
+            Some(credentials),
+            self.authorized_scopes.as_ref(),
+        )?);
+
+        let mut signing = self.signing.write().unwrap();
+        if Arc::ptr_eq(&signing, expired) {
+            *signing = fresh;
+        }
+        Ok(signing.clone())
+    }
+
+    /// Execute a request, retrying as configured.
+    pub(crate) async fn execute_with_retries(
+        &self,
+        req: reqwest::Request,
+    ) -> Result<reqwest::Response, Error> {
+        let (resp, _) = self.execute_with_stats(req, None).await?;
+        Ok(resp)
+    }
+
+    /// Execute a request, retrying as configured until any deadline, and return statistics about
+    /// the request.
+    #[cfg(not(feature = "tracing"))]
+    async fn execute_with_stats(
+        &self,
+        req: reqwest::Request,
+        deadline: Option<Instant>,
+    ) -> Result<(reqwest::Response, RequestStats), Error> {
+        let mut stats = RequestStats::default();
+        let resp = self.retry_loop(req, &mut stats, deadline).await?;
+        Ok((resp, stats))
+    }
+
+    /// Execute a request, retrying as configured until any deadline, within a tracing span for
+    /// the request, and return statistics about the request.
+    #[cfg(feature = "tracing")]
+    async fn execute_with_stats(
+        &self,
+        req: reqwest::Request,
+        deadline: Option<Instant>,
+    ) -> Result<(reqwest::Response, RequestStats), Error> {
+        use tracing::Instrument;
+
+        let span = tracing::info_span!(
+            "taskcluster_request",
+            service = self.service_name(),
+            method = req.method().as_str(),
+            path = req.url().path(),
+            attempts = tracing::field::Empty,
+            status = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        );
+        let start = Instant::now();
+        let mut stats = RequestStats::default();
+        let res = self
+            .retry_loop(req, &mut stats, deadline)
+            .instrument(span.clone())
+            .await;
+        span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+        span.record("attempts", stats.attempts);
+        let status = match res {
+            Ok(ref resp) => Some(resp.status()),
+            Err(ref err) => crate::err_status_code(err),
+        };
+        if let Some(status) = status {
+            span.record("status", status.as_u16());
+        }
+        Ok((res?, stats))
+    }
+
+    /// The name of the service this client calls, for use in tracing and metrics.  This is
+    /// derived from a path prefix of the form `api/<service>/<version>/`.
+    fn service_name(&self) -> &str {
+        let segments: Vec<&str> = self
+            .base_url
+            .path_segments()
+            .map(|s| s.filter(|s| !s.is_empty()).collect())
+            .unwrap_or_default();
+        if segments.len() >= 2 {
+            segments[segments.len() - 2]
+        } else {
+            ""
+        }
+    }
+
+    /// The duration given by a response's `Retry-After` header, if any, capped at the configured
+    /// [`max_delay`](crate::Retry::max_delay).
+    fn retry_after(&self, resp: &reqwest::Response) -> Option<Duration> {
+        parse_retry_after(resp.headers()).map(|delay| delay.min(self.retry.max_delay))
+    }
+
+    /// The retry loop for `execute_with_stats`, updating `stats` as it goes.
+    async fn retry_loop(
+        &self,
+        req: reqwest::Request,
+        stats: &mut RequestStats,
+        deadline: Option<Instant>,
+    ) -> Result<reqwest::Response, Error> {
+        let mut backoff = Backoff::new(&self.retry).with_max_elapsed_time(self.max_elapsed_time);
+        let mut next = Some(req);
+
+        loop {
+            // keep a copy of the request to retry with.  A request with a streaming body cannot be
+            // copied, since the stream is consumed by sending it, and so is sent only once.
+            let mut req = next.take().expect("request to retry with");
+            next = req.try_clone();
+
+            let retry_for;
+            let mut retry_after = None;
+            let permit = match self.concurrency_limit {
+                Some(ref limit) => self.unless_cancelled(limit.acquire()).await?,
+                None => None,
+            };
+            if let Some(deadline) = deadline {
+                // limit the attempt to the time remaining before the deadline
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining == Duration::from_secs(0) {
+                    bail!("Request deadline passed before the request was sent");
+                }
+                *req.timeout_mut() = Some(match req.timeout() {
+                    Some(&timeout) => std::cmp::min(timeout, remaining),
+                    None => remaining,
+                });
+            }
+            let metrics = self.on_response.as_ref().map(|callback| {
+                let metrics = RequestMetrics {
+                    service: self.service_name().to_owned(),
+                    method: req.method().to_string(),
+                    path: req.url().path().to_owned(),
+                    status: None,
+                    duration: Duration::default(),
+                };
+                (callback, metrics, Instant::now())
+            });
+            let logged = if log::log_enabled!(log::Level::Debug) {
+                Some((req.method().clone(), path_and_query(req.url())))
+            } else {
+                None
+            };
+            let res = self.unless_cancelled(self.execute(req)).await?;
+            drop(permit);
+            stats.attempts += 1;
+
+            if let Some((method, path)) = logged {
+                match res {
+                    Ok(ref resp) => log::debug!("response: {} {}: {}", method, path, resp.status()),
+                    Err(ref err) => log::debug!("response: {} {}: {}", method, path, err),
+                }
+            }
+
+            if let Some((callback, mut metrics, start)) = metrics {
+                metrics.status = res.as_ref().ok().map(|resp| resp.status());
+                metrics.duration = start.elapsed();
+                (callback.0)(&metrics);
+            }
+
+            match res {
+                // From the request docs for Client::execute:
+                // > This method fails if there was an error while sending request, redirect loop
+                // > was detected or redirect limit was exhausted.
+                // All cases where there's a successful HTTP response are Ok(..).  Only transient
+                // errors, such as timeouts and dropped connections, are retried.
+                Err(e) if !is_retryable(&e) => {
+                    return Err(ApiError::Transport(e).into());
+                }
+                Err(e) => {
+                    retry_for = ApiError::Transport(e);
+                }
+
+                // a custom retry predicate replaces the default decision of which responses to
+                // retry
+                Ok(resp) if self.retry_predicate.is_some() => {
+                    let predicate = self.retry_predicate.as_ref().unwrap();
+                    if !(predicate.0)(&resp) {
+                        if resp.status().is_client_error() || resp.status().is_server_error() {
+                            return Err(ApiError::from_response(resp, self.max_response_bytes)
+                                .await
+                                .into());
+                        }
+                        return Ok(resp);
+                    }
+                    retry_after = self.retry_after(&resp);
+                    retry_for = ApiError::from_response(resp, self.max_response_bytes).await;
+                }
+
+                // Retry for server errors, honoring any (capped) Retry-After header, such as on a
+                // 503 during maintenance
+                Ok(resp) if resp.status().is_server_error() => {
+                    retry_after = self.retry_after(&resp);
+                    retry_for = ApiError::from_response(resp, self.max_response_bytes).await;
+                }
+
+                // Retry for rate-limiting, if so configured, honoring any (capped) Retry-After
+                // header
+                Ok(resp)
+                    if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        && self.retry_on_429 =>
+                {
+                    retry_after = self.retry_after(&resp);
+                    retry_for = ApiError::from_response(resp, self.max_response_bytes).await;
+                }
+
+                // client errors do not get retried
+                Ok(resp) if resp.status().is_client_error() => {
+                    return Err(ApiError::from_response(resp, self.max_response_bytes)
+                        .await
+                        .into());
+                }
+
+                Ok(resp) => {
+                    return Ok(resp);
+                }
+            };
+
+            // if we got here, we are going to retry, or return the error if we are done
+            // retrying.  The backoff tracks the number of retries and the elapsed time.
+            if next.is_none() {
+                return Err(give_up(retry_for, stats.attempts).context(
+                    "Request was not retried, because its body is a stream that cannot be replayed",
+                ));
+            }
+            match backoff.next_backoff() {
+                Some(duration) => {
+                    let duration = retry_after.unwrap_or(duration);
+                    if !backoff.within_max_elapsed_time(duration) {
+                        return Err(give_up(retry_for, stats.attempts));
+                    }
+                    // a retry too far in the future to represent is past any deadline
+                    let past_deadline = deadline.map_or(false, |deadline| {
+                        Instant::now()
+                            .checked_add(duration)
+                            .map_or(true, |retry_at| retry_at >= deadline)
+                    });
+                    if past_deadline {
+                        return Err(give_up(retry_for, stats.attempts)
+                            .context("Request deadline passed before it could be retried"));
+                    }
+
+                    #[cfg(feature = "tracing")]
+                    tracing::info!(
+                        attempt = backoff.retries(),
+                        backoff_ms = duration.as_millis() as u64,
+                        error = %retry_for,
+                        "retrying request"
+                    );
+
+                    stats.total_wait += duration;
+                    self.unless_cancelled(tokio::time::sleep(duration)).await?;
+                }
+                None => return Err(give_up(retry_for, stats.attempts)),
+            }
+        }
+    }
+
+    /// Wait for the given future, unless the client's cancellation token is cancelled first.
+    pub(crate) async fn unless_cancelled<F: Future>(
+        &self,
+        future: F,
+    ) -> Result<F::Output, ApiError> {
+        match self.cancellation_token {
+            Some(ref token) => tokio::select! {
+                output = future => Ok(output),
+                _ = token.cancelled() => Err(ApiError::Cancelled),
+            },
+            None => Ok(future.await),
+        }
+    }
+
+    /// Execute a single request, without retries.
+    async fn execute(&self, req: reqwest::Request) -> reqwest::Result<reqwest::Response> {
+        if self.dry_run && !matches!(*req.method(), reqwest::Method::GET | reqwest::Method::HEAD) {
+            self.log_dry_run(&req);
+            return Ok(dry_run_response());
+        }
+        #[cfg(feature = "mock")]
+        {
+            if let Some(ref mock) = self.mock {
+                return Ok(mock.execute(req));
+            }
+        }
+        self.client.execute(req).await
+    }
+
+    /// Log a request that is not sent in dry-run mode.  The `Authorization` header is not logged,
+    /// as it could be replayed, and the body is redacted as for any logged body.
+    fn log_dry_run(&self, req: &reqwest::Request) {
+        let body = self.dry_run_body(req);
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            method = req.method().as_str(),
+            url = req.url().as_str(),
+            body = body.as_str(),
+            "dry run: not sending request"
+        );
+        #[cfg(not(feature = "tracing"))]
+        log::info!(
+            "dry run: not sending {} {} {}",
+            req.method(),
+            req.url(),
+            body
+        );
+    }
+
+    /// Format the body of a request that is not sent in dry-run mode for logging.
+    fn dry_run_body(&self, req: &reqwest::Request) -> String {
+        match req.body() {
+            Some(body) => match body.as_bytes() {
+                Some(bytes) => loggable_body(req.url(), bytes),
+                None => "<streaming body>".to_owned(),
+            },
+            None => String::new(),
+        }
+    }
+
+    /// Make a request as for [`request`](crate::Client::request), and decode the JSON response
+    /// body.  This is used by the per-service methods.  Unlike `request`, any non-2xx response
+    /// (including redirects) is treated as an error, with the Taskcluster error body, if any,
+    /// available in the resulting [`ApiError`](crate::ApiError).  A successful response with an
+    /// empty body, as some "action" endpoints return, decodes to `Value::Null`.
+    pub async fn request_json(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+    ) -> Result<Value, Error> {
+        self.request_json_with_timeout(method, path, query, body, self.timeout)
+            .await
+    }
+
+    /// Make a request as for [`request_json`](crate::Client::request_json), but with the given
+    /// timeout in place of the client's configured timeout.
+    pub async fn request_json_with_timeout(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+        timeout: Duration,
+    ) -> Result<Value, Error> {
+        let resp = self
+            .request_with_timeout(method, path, query, body, timeout)
+            .await?;
+        if !resp.status().is_success() {
+            return Err(ApiError::from_response(resp, self.max_response_bytes)
+                .await
+                .into());
+        }
+        let url = resp.url().clone();
+        let body = self.read_body(resp).await?;
+        log::trace!("response body: {}", loggable_body(&url, &body));
+        if body.iter().all(u8::is_ascii_whitespace) {
+            return Ok(Value::Null);
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Read the body of the given response, failing if it is larger than the client's
+    /// `max_response_bytes`, if any.
+    async fn read_body(&self, resp: reqwest::Response) -> Result<Bytes, Error> {
+        read_body(resp, self.max_response_bytes).await
+    }
+
+    /// Make a request as for [`request`](crate::Client::request), and return the response body
+    /// as a stream of chunks, without reading it all into memory.
+    ///
+    /// Retries apply only to getting the initial response: once the response status has been
+    /// received, a failure while reading the body is returned as an error from the stream, and
+    /// is not retried.
+    pub async fn request_stream(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+    ) -> Result<impl Stream<Item = Result<Bytes, Error>>, Error> {
+        let resp = self.request(method, path, query, body).await?;
+        Ok(resp.bytes_stream().map_err(Error::from))
+    }
+
+    /// Call the `ping` endpoint of the service, relative to the client's path prefix, and return
+    /// the parsed response.
+    pub async fn ping(&self) -> Result<PingResponse, Error> {
+        let res = self.request_json("GET", "ping", None, None).await?;
+        Ok(serde_json::from_value(res)?)
+    }
+
+    /// Check whether the service is up, returning false if its `ping` endpoint cannot be reached
+    /// or does not report that the service is alive.  The `ping` endpoint is tried only once, with
+    /// a timeout of at most five seconds, so that an unavailable service is detected promptly.
+    pub async fn is_alive(&self) -> bool {
+        let mut client = self.duplicate();
+        client.retry.retries = 0;
+        client.timeout = match request_timeout(self.timeout) {
+            Some(timeout) => std::cmp::min(timeout, IS_ALIVE_TIMEOUT),
+            None => IS_ALIVE_TIMEOUT,
+        };
+        matches!(client.ping().await, Ok(PingResponse { alive: true, .. }))
+    }
+
+    /// Stream all pages of results from a paginated API method, as described in [the
+    /// Taskcluster documentation](https://docs.taskcluster.net/docs/manual/design/apis#pagination).
+    /// Each item in the stream is the JSON response for one page.  The `continuationToken` from
+    /// each response is added to the query for the next request, along with the given query
+    /// parameters (such as `limit`), until a response without a `continuationToken` is received.
+    pub fn paginate<'a>(
+        &'a self,
+        method: &'a str,
+        path: &str,
+        query: Option<Vec<(&'a str, &'a str)>>,
+    ) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let path = path.to_owned();
+
+        // the state is None when there are no more pages, or otherwise the continuationToken for
+        // the next page (None for the first page)
+        stream::try_unfold(Some(None), move |state: Option<Option<String>>| {
+            let path = path.clone();
+            let query = query.clone();
+            async move {
+                let continuation_token = match state {
+                    Some(continuation_token) => continuation_token,
+                    None => return Ok(None),
+                };
+
+                let mut query = query.unwrap_or_default();
+                if let Some(ref token) = continuation_token {
+                    query.push(("continuationToken", token));
+                }
+
+                let page = self.request_json(method, &path, Some(query), None).await?;
+                let next = page
+                    .get("continuationToken")
+                    .and_then(|t| t.as_str())
+                    .map(|t| Some(t.to_owned()));
+                Ok(Some((page, next)))
+            }
+        })
+    }
+
+    /// Fetch all pages of results from a paginated API method, as for
+    /// [`paginate`](crate::Client::paginate), and collect the items from the array under
+    /// `items_key` in each page (such as `clients` or `tasks`) into a single vector.
+    ///
+    /// This fails if the results do not fit in the number of pages configured with
+    /// [`ClientBuilder::max_pages`](crate::ClientBuilder::max_pages), or if a page does not
+    /// contain an array under `items_key`.
+    pub async fn request_all<'a>(
+        &'a self,
+        method: &'a str,
+        path: &str,
+        query: Option<Vec<(&'a str, &'a str)>>,
+        items_key: &str,
+    ) -> Result<Vec<Value>, Error> {
+        let pages = self.paginate(method, path, query);
+        futures_util::pin_mut!(pages);
+
+        let mut items = vec![];
+        let mut num_pages = 0;
+        while let Some(mut page) = pages.try_next().await? {
+            num_pages += 1;
+            if num_pages >= self.max_pages && page.get("continuationToken").is_some() {
+                bail!(
+                    "Results from {} exceed the maximum of {} pages",
+                    path,
+                    self.max_pages
+                );
+            }
+
+            match page.get_mut(items_key).map(Value::take) {
+                Some(Value::Array(page_items)) => items.extend(page_items),
+                _ => bail!("Response from {} has no array {}", path, items_key),
+            }
         }
 
-        let meth = reqwest::Method::from_str(method)?;
+        Ok(items)
+    }
+
+    /// Build a request signed with the given signing state and `ext`.
+    fn build_request(
+        &self,
+        signing: &Signing,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+        ext: Option<&str>,
+    ) -> Result<reqwest::Request, Error> {
+        let body = match body {
+            Some(b) => Some(("application/json", Bytes::from(serde_json::to_vec(b)?))),
+            None => None,
+        };
+        self.build_raw_request(signing, method, path, query, body, ext)
+    }
+
+    /// Build a signed request as for `build_request`, with the given body, if any, and its
+    /// content type.
+    fn build_raw_request(
+        &self,
+        signing: &Signing,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<(&str, Bytes)>,
+        ext: Option<&str>,
+    ) -> Result<reqwest::Request, Error> {
+        let url = self.build_url(path, query)?;
+
+        // methods are case-sensitive, so normalize them to the uppercase form that the services
+        // expect and that is signed
+        let meth = reqwest::Method::from_str(&method.to_ascii_uppercase())?;
+
+        let req = self
+            .client
+            .request(meth, url)
+            .header(USER_AGENT, &self.user_agent)
+            .headers(self.default_headers.clone());
+
+        // apply the timeout per-request, both to allow overriding it and in case the reqwest
+        // client was supplied by the caller
+        let req = match request_timeout(self.timeout) {
+            Some(timeout) => req.timeout(timeout),
+            None => req,
+        };
 
-        let req = self.client.request(meth, url);
+        let req = match self.trace_id {
+            Some(ref trace_id) => req.header(TRACE_ID_HEADER, trace_id.clone()),
+            None => req,
+        };
 
         let req = match body {
-            Some(b) => req.json(&b),
+            Some((content_type, b)) => req.header(CONTENT_TYPE, content_type).body(b),
             None => req,
         };
 
         let req = req.build()?;
 
-        match self.credentials {
-            Some(ref creds) => self.sign_request(creds, req),
+        if log::log_enabled!(log::Level::Debug) {
+            log::debug!("request: {} {}", req.method(), path_and_query(req.url()));
+            if let Some(b) = req.body().and_then(|b| b.as_bytes()) {
+                log::trace!("request body: {}", loggable_body(req.url(), b));
+            }
+        }
+
+        match signing.credentials {
+            Some(ref creds) => self.sign_request(creds, req, ext),
             None => Ok(req),
         }
     }
@@ -326,25 +1640,38 @@ impl Client {
         &self,
         creds: &hawk::Credentials,
         req: reqwest::Request,
+        ext: Option<&str>,
     ) -> Result<reqwest::Request, Error> {
+        // the Hawk resource is the full path, including any query, just as for signed URLs
+        let path_with_query = path_and_query(req.url());
         let mut signed_req_builder = hawk::RequestBuilder::new(
             req.method().as_str(),
             &self.host,
             self.port,
-            req.url().path(),
+            &path_with_query,
         );
 
-        // hash the payload, if there is one
+        // hash the payload, if there is one, using the request's content type.  The payload hash
+        // is optional in Hawk, so a streaming body, which cannot be hashed without consuming it,
+        // is sent without one.
         let payload_hash;
-        if let Some(ref b) = req.body() {
-            let b = b
-                .as_bytes()
-                .ok_or_else(|| anyhow!("stream request bodies are not supported"))?;
-            payload_hash = hawk::PayloadHasher::hash("application/json", hawk::SHA256, b)?;
+        if let Some(b) = req.body().and_then(|b| b.as_bytes()) {
+            let content_type = req
+                .headers()
+                .get(CONTENT_TYPE)
+                .map(|ct| ct.to_str())
+                .transpose()
+                .context("invalid Content-Type header")?
+                .unwrap_or("");
+            payload_hash = hawk::PayloadHasher::hash(
+                hawk_content_type(content_type).as_str(),
+                hawk::SHA256,
+                b,
+            )?;
             signed_req_builder = signed_req_builder.hash(&payload_hash[..])
         }
 
-        signed_req_builder = signed_req_builder.ext(self.ext.as_ref().map(|s| s.as_ref()));
+        signed_req_builder = signed_req_builder.ext(ext);
 
         let header = signed_req_builder.request().make_header(&creds)?;
 
@@ -355,18 +1682,30 @@ impl Client {
         Ok(req)
     }
 
-    /// Make a URL for the given path, constructed as for [`request`](crate::Client::request).  The
-    /// path should not begin with a `/`.
-    pub fn make_url(&self, path: &str, query: Option<Vec<(&str, &str)>>) -> Result<String> {
+    /// Build the full URL for the given path and query, joined to the base URL (including any
+    /// path prefix).  This is shared by requests and URL generation, so that both always address
+    /// the same resource.
+    fn build_url(&self, path: &str, query: Option<Vec<(&str, &str)>>) -> Result<reqwest::Url> {
         if path.starts_with('/') {
             bail!("Request path must not begin with `/`");
         }
 
         let mut url = self.base_url.join(path)?;
 
-        if let Some(q) = query {
+        // sort the query parameters by key, so that the same request always has the same URL,
+        // regardless of the order in which the parameters were given.  The sort is stable, so
+        // repeated keys keep their relative order.
+        if let Some(mut q) = query {
+            q.sort_by_key(|(k, _)| *k);
             url.query_pairs_mut().extend_pairs(q);
         }
+        Ok(url)
+    }
+
+    /// Make a URL for the given path, constructed as for [`request`](crate::Client::request).  The
+    /// path should not begin with a `/`.
+    pub fn make_url(&self, path: &str, query: Option<Vec<(&str, &str)>>) -> Result<String> {
+        let url = self.build_url(path, query)?;
         Ok(url.as_ref().to_owned())
     }
 
@@ -380,19 +1719,48 @@ impl Client {
         query: Option<Vec<(&str, &str)>>,
         ttl: Duration,
     ) -> Result<String> {
-        if path.starts_with('/') {
-            bail!("Request path must not begin with `/`");
-        }
+        let url = self.build_signed_url("GET", path, query, ttl)?;
+        Ok(url.as_ref().to_owned())
+    }
+
+    /// Build a URL containing a Hawk "bewit" for the given method and path, constructed as for
+    /// [`request`](crate::Client::request).  The resulting URL can be fetched directly, without
+    /// an `Authorization` header, until the given duration has elapsed.  Note that Taskcluster
+    /// services only accept bewits for `GET` requests.
+    ///
+    /// This fails if the client has no credentials.
+    pub fn build_signed_url(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        ttl: Duration,
+    ) -> Result<reqwest::Url> {
+        let url = self.build_url(path, query)?;
+        self.sign_url(method, url, ttl)
+    }
 
-        let creds = if let Some(ref creds) = self.credentials {
+    /// Add a Hawk "bewit" to the given URL on the client's deployment, as for
+    /// [`build_signed_url`](crate::Client::build_signed_url).  This fails if the URL is not on
+    /// the same host and port as the client's root URL, since the bewit would not be valid there.
+    pub(crate) fn sign_url(
+        &self,
+        method: &str,
+        mut url: reqwest::Url,
+        ttl: Duration,
+    ) -> Result<reqwest::Url> {
+        let signing = self.signing();
+        let creds = if let Some(ref creds) = signing.credentials {
             creds
         } else {
             return Err(anyhow!("Cannot sign a URL without credentials"));
         };
 
-        let mut url = self.base_url.join(path)?;
-        if let Some(q) = query {
-            url.query_pairs_mut().extend_pairs(q);
+        if hawk_host_and_port(&url)? != (self.host.clone(), self.port) {
+            bail!(
+                "Cannot sign {}, which is not on this client's deployment",
+                url
+            );
         }
 
         // generate a full path containing the query
@@ -401,38 +1769,351 @@ impl Client {
             None => url.path().to_owned(),
         };
 
-        let req = hawk::RequestBuilder::new("GET", &self.host, self.port, &path_with_query)
-            .ext(self.ext.as_ref().map(|s| s.as_ref()))
+        let req = hawk::RequestBuilder::new(method, &self.host, self.port, &path_with_query)
+            .ext(signing.ext.as_ref().map(|s| s.as_ref()))
             .request();
 
         let bewit = req.make_bewit_with_ttl(creds, ttl)?;
 
         url.query_pairs_mut().append_pair("bewit", &bewit.to_str());
-        Ok(url.as_ref().to_owned())
+        Ok(url)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::err_status_code;
-    use anyhow::bail;
-    use httptest::{matchers::*, responders::*, Expectation, Server};
-    use serde_json::json;
-    use std::fmt;
-    use std::net::SocketAddr;
-    use std::time::Duration;
-    use tokio;
+    /// Compute the `Authorization` header value for a request, exactly as
+    /// [`request`](crate::Client::request) would sign it, without sending the request.  This is
+    /// useful for debugging, or for handing a signed request to another tool.  The header is
+    /// only valid for a request made soon afterward, with the same method, URL, and body.
+    ///
+    /// This fails if the client has no credentials.
+    pub fn sign_header(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+    ) -> Result<String> {
+        if self.signing().credentials.is_none() {
+            bail!("Cannot sign a request without credentials");
+        }
 
-    /// An httptest matcher that will check Hawk authentication with the given cedentials.
-    pub fn signed_with(creds: Credentials, addr: SocketAddr) -> SignedWith {
-        SignedWith(creds, addr)
+        let req = self.prepare(method, path, query, body)?;
+        let header = req
+            .headers()
+            .get("Authorization")
+            .ok_or_else(|| anyhow!("Request was not signed"))?;
+        Ok(header.to_str()?.to_owned())
     }
 
-    #[derive(Debug)]
-    pub struct SignedWith(Credentials, SocketAddr);
+    /// Build the request that [`request`](crate::Client::request) would send, including its
+    /// `Authorization` header if the client has credentials, without sending it.  The caller may
+    /// inspect or modify the request, and send it with its own `reqwest::Client`, though any
+    /// change to its method, URL, or body invalidates the signature.  Like the header from
+    /// [`sign_header`](crate::Client::sign_header), the signature is only valid for a request
+    /// sent soon afterward.  Requests sent this way are not retried.
+    pub fn prepare(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+        body: Option<&Value>,
+    ) -> Result<reqwest::Request> {
+        let signing = self.signing();
+        self.build_request(&signing, method, path, query, body, signing.ext.as_deref())
+    }
 
-    impl<B> Matcher<httptest::http::Request<B>> for SignedWith {
+    /// Get the absolute URL to which a redirect response points, resolving its `Location` header
+    /// relative to the URL of the request.  Since clients do not follow redirects, this is how a
+    /// caller finds, for example, the storage location of an artifact.  This returns None if the
+    /// response is not a redirect or has no valid `Location` header.
+    pub fn redirect_location(resp: &reqwest::Response) -> Option<reqwest::Url> {
+        if !resp.status().is_redirection() {
+            return None;
+        }
+        let location = resp.headers().get(LOCATION)?.to_str().ok()?;
+        resp.url().join(location).ok()
+    }
+}
+
+/// Determine the host and port that a Taskcluster service will use to validate a Hawk signature
+/// for requests to the given URL.  Services take these from the `Host` header, keeping the
+/// brackets around an IPv6 address, and from the port on which the request arrived.  An explicit
+/// port in the URL is always used, even if it is the default for the scheme.
+fn hawk_host_and_port(url: &reqwest::Url) -> Result<(String, u16)> {
+    // `host_str` gives a lower-cased domain name, or an IP address as it appears in the `Host`
+    // header, including brackets for IPv6
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("URL doesn't contain a host"))?
+        .to_owned();
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| anyhow!("Unknown port for protocol {}", url.scheme()))?;
+    Ok((host, port))
+}
+
+/// Normalize a `Content-Type` header value for use in a Hawk payload hash, which uses only the
+/// lower-cased media type, without any parameters such as `charset`.
+fn hawk_content_type(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_lowercase()
+}
+
+/// Build the error returned when a request is not retried any further.  The error from the last
+/// attempt, including the body of any error response, is kept as the source of the returned
+/// error, so it can still be downcast to [`ApiError`].
+fn give_up(err: ApiError, attempts: u32) -> Error {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+        attempts,
+        error = %err,
+        body = ?match err {
+            ApiError::Status { ref body, .. } => body.as_ref(),
+            _ => None,
+        },
+        "giving up on request"
+    );
+
+    if attempts > 1 {
+        Error::from(err).context(format!("Gave up after {} attempts", attempts))
+    } else {
+        err.into()
+    }
+}
+
+/// The path of a URL, with its query if any, as used in logs.
+fn path_and_query(url: &reqwest::Url) -> String {
+    match url.query() {
+        Some(q) => format!("{}?{}", url.path(), q),
+        None => url.path().to_owned(),
+    }
+}
+
+/// Format the body of a request to the given URL, or of its response, for logging, redacting
+/// secrets and truncating it to `MAX_LOGGED_BODY` characters.  Bodies of requests to the secrets
+/// service are not logged at all, however the client making them is configured, and the values of
+/// any `accessToken` properties, such as in the temporary credentials returned by `claimWork`,
+/// are redacted.
+fn loggable_body(url: &reqwest::Url, body: &[u8]) -> String {
+    if is_secrets_url(url) {
+        return "<redacted>".to_owned();
+    }
+    let text = match serde_json::from_slice::<Value>(body) {
+        Ok(mut value) => {
+            redact_access_tokens(&mut value);
+            value.to_string()
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    };
+    if text.chars().count() > MAX_LOGGED_BODY {
+        let text: String = text.chars().take(MAX_LOGGED_BODY).collect();
+        format!("{}... ({} bytes)", text, body.len())
+    } else {
+        text
+    }
+}
+
+/// Determine whether the given URL is of an endpoint of the secrets service, with a path
+/// containing `api/secrets/`.
+fn is_secrets_url(url: &reqwest::Url) -> bool {
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(Iterator::collect)
+        .unwrap_or_default();
+    segments
+        .windows(2)
+        .any(|window| window == ["api", "secrets"])
+}
+
+/// Replace the values of any `accessToken` properties in the given JSON value, at any depth.
+fn redact_access_tokens(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if key == "accessToken" {
+                    *value = Value::String("<redacted>".to_owned());
+                } else {
+                    redact_access_tokens(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_access_tokens),
+        _ => {}
+    }
+}
+
+/// Return the synthetic response standing in for the server's to a request that is not sent in
+/// dry-run mode.
+fn dry_run_response() -> reqwest::Response {
+    http::Response::builder()
+        .status(200)
+        .header(CONTENT_TYPE, "application/json")
+        .body("{}")
+        .unwrap()
+        .into()
+}
+
+/// What a client needs to sign requests, derived from its credentials.  This is replaced as a
+/// whole when the credentials are refreshed, so that a request never mixes old and new parts.
+struct Signing {
+    /// The credentials used for requests.  If None, then unauthenticated requests are made.
+    /// These are shared with any clients derived with different authorized scopes.
+    credentials: Option<Arc<hawk::Credentials>>,
+
+    /// The certificate for temporary credentials, if any
+    certificate: Option<Value>,
+
+    /// The `ext` string for requests, if any
+    ext: Option<String>,
+}
+
+impl Signing {
+    /// Return true if these are temporary credentials whose certificate has expired.
+    fn is_expired(&self) -> bool {
+        self.certificate.as_ref().map_or(false, certificate_expired)
+    }
+}
+
+/// The per-request timeout for the given configured timeout, where zero means no timeout.
+fn request_timeout(timeout: Duration) -> Option<Duration> {
+    if timeout == Duration::from_secs(0) {
+        None
+    } else {
+        Some(timeout)
+    }
+}
+
+/// A function supplying fresh credentials, for
+/// [`ClientBuilder::credentials_refresh`](crate::ClientBuilder::credentials_refresh)
+#[derive(Clone)]
+struct CredentialsRefresh(Arc<dyn Fn() -> Result<Credentials> + Send + Sync>);
+
+impl std::fmt::Debug for CredentialsRefresh {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("CredentialsRefresh")
+    }
+}
+
+/// A function receiving metrics for each attempt at a request, for
+/// [`ClientBuilder::on_response`](crate::ClientBuilder::on_response)
+#[derive(Clone)]
+struct ResponseCallback(Arc<dyn Fn(&RequestMetrics) + Send + Sync>);
+
+impl std::fmt::Debug for ResponseCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ResponseCallback")
+    }
+}
+
+/// A function deciding whether to retry a response, for
+/// [`ClientBuilder::retry_predicate`](crate::ClientBuilder::retry_predicate)
+#[derive(Clone)]
+struct RetryPredicate(Arc<dyn Fn(&reqwest::Response) -> bool + Send + Sync>);
+
+impl std::fmt::Debug for RetryPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("RetryPredicate")
+    }
+}
+
+/// Validate the given credentials and prepare what is needed to sign requests with them: the
+/// `hawk::Credentials`, the parsed certificate, and the `ext` property combining the certificate
+/// and any authorized scopes.
+fn prepare_credentials(
+    credentials: Option<Credentials>,
+    authorized_scopes: Option<&Vec<String>>,
+) -> Result<Signing> {
+    let credentials = match credentials {
+        Some(credentials) => credentials,
+        None => {
+            return Ok(Signing {
+                credentials: None,
+                certificate: None,
+                ext: make_ext(None, authorized_scopes.cloned())?,
+            })
+        }
+    };
+    credentials.validate()?;
+
+    // figure out the `certificate` and `authorizedScopes` parts of the ext property
+    let certificate: Option<Value> = match credentials.certificate {
+        Some(ref cert_str) => Some(
+            serde_json::from_str(cert_str).context("while parsing given certificate as JSON")?,
+        ),
+        None => None,
+    };
+    let ext = make_ext(certificate.as_ref(), authorized_scopes.cloned())?;
+
+    // pre-generate the hawk::Credentials struct we will use to sign requests
+    let hawk_credentials = hawk::Credentials {
+        id: credentials.client_id.clone(),
+        key: hawk::Key::new(&credentials.access_token, hawk::SHA256)
+            .context(credentials.client_id)?,
+    };
+
+    Ok(Signing {
+        credentials: Some(Arc::new(hawk_credentials)),
+        certificate,
+        ext,
+    })
+}
+
+/// Build the base64-encoded Hawk `ext` string for the given certificate and authorized scopes,
+/// if either is present.
+fn make_ext(
+    certificate: Option<&Value>,
+    authorized_scopes: Option<Vec<String>>,
+) -> Result<Option<String>> {
+    let ext_json = match (certificate, authorized_scopes) {
+        (Some(c), None) => json!({ "certificate": c }),
+        (None, Some(s)) => json!({ "authorizedScopes": s }),
+        (Some(c), Some(s)) => json!({ "certificate": c, "authorizedScopes": s }),
+        (None, None) => return Ok(None),
+    };
+
+    let ext_str = serde_json::to_string(&ext_json)?;
+    Ok(Some(base64::encode_config(
+        ext_str,
+        base64::URL_SAFE_NO_PAD,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Record;
+    use crate::{err_status_code, StatusCode};
+    use anyhow::bail;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::json;
+    use std::fmt;
+    use std::net::SocketAddr;
+    use std::time::Duration;
+    use tokio;
+
+    /// An httptest matcher that will check Hawk authentication with the given cedentials.
+    pub fn signed_with(creds: Credentials, addr: SocketAddr) -> SignedWith {
+        let host = match addr {
+            SocketAddr::V4(addr) => addr.ip().to_string(),
+            SocketAddr::V6(addr) => format!("[{}]", addr.ip()),
+        };
+        SignedWith(creds, host, addr.port())
+    }
+
+    /// An httptest matcher that will check Hawk authentication with the given cedentials, for a
+    /// request to the given host and port (which may differ from the server's address, if the
+    /// server is acting as a proxy).
+    pub fn signed_with_host(creds: Credentials, host: &str, port: u16) -> SignedWith {
+        SignedWith(creds, host.to_owned(), port)
+    }
+
+    #[derive(Debug)]
+    pub struct SignedWith(Credentials, String, u16);
+
+    impl<B> Matcher<httptest::http::Request<B>> for SignedWith {
         fn matches(
             &mut self,
             input: &httptest::http::Request<B>,
@@ -449,12 +2130,15 @@ mod tests {
             }
             let auth_header: hawk::Header = auth_header[5..].parse().unwrap();
 
-            let host = format!("{}", self.1.ip());
             let hawk_req = hawk::RequestBuilder::new(
                 input.method().as_str(),
-                &host,
-                self.1.port(),
-                input.uri().path(),
+                &self.1,
+                self.2,
+                input
+                    .uri()
+                    .path_and_query()
+                    .map(|pq| pq.as_str())
+                    .unwrap_or("/"),
             )
             .request();
 
@@ -475,8 +2159,46 @@ mod tests {
         }
     }
 
+    /// An httptest matcher that will check that the Hawk `ext` in the Authorization header
+    /// decodes to the given JSON value.
+    pub fn hawk_ext(ext: Value) -> HawkExt {
+        HawkExt(ext)
+    }
+
+    #[derive(Debug)]
+    pub struct HawkExt(Value);
+
+    impl<B> Matcher<httptest::http::Request<B>> for HawkExt {
+        fn matches(
+            &mut self,
+            input: &httptest::http::Request<B>,
+            _ctx: &mut ExecutionContext,
+        ) -> bool {
+            let auth_header = input
+                .headers()
+                .get(httptest::http::header::AUTHORIZATION)
+                .unwrap();
+            let auth_header = auth_header.to_str().unwrap();
+            let auth_header: hawk::Header = auth_header[5..].parse().unwrap();
+            let ext = match auth_header.ext {
+                Some(ext) => ext,
+                None => {
+                    println!("Authorization header has no ext");
+                    return false;
+                }
+            };
+            let ext = base64::decode_config(ext, base64::URL_SAFE_NO_PAD).unwrap();
+            serde_json::from_slice::<Value>(&ext).unwrap() == self.0
+        }
+
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            <Self as fmt::Debug>::fmt(self, f)
+        }
+    }
+
     fn get_authorized_scopes(client: &Client) -> Result<Vec<String>> {
-        let ext = if let Some(ref ext) = client.ext {
+        let signing = client.signing();
+        let ext = if let Some(ref ext) = signing.ext {
             ext
         } else {
             bail!("client has no ext")
@@ -490,229 +2212,3273 @@ mod tests {
             authorized_scopes: Vec<String>,
         }
 
-        let ext = serde_json::from_slice::<Certificate>(&ext)?;
-        Ok(ext.authorized_scopes)
-    }
+        let ext = serde_json::from_slice::<Certificate>(&ext)?;
+        Ok(ext.authorized_scopes)
+    }
+
+    fn get_ext(client: &Client) -> Result<Value> {
+        let signing = client.signing();
+        let ext = if let Some(ref ext) = signing.ext {
+            ext
+        } else {
+            bail!("client has no ext")
+        };
+
+        let ext = base64::decode_config(ext, base64::URL_SAFE_NO_PAD)?;
+        Ok(serde_json::from_slice(&ext)?)
+    }
+
+    #[test]
+    fn test_no_ext() {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new("a-client", "a-token"))
+            .build()
+            .unwrap();
+        assert!(client.signing().ext.is_none());
+    }
+
+    #[test]
+    fn test_certificate_ext() {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new_with_certificate(
+                "a-client",
+                "a-token",
+                r#"{"version": 1, "seed": "abc"}"#,
+            ))
+            .build()
+            .unwrap();
+        assert_eq!(
+            get_ext(&client).unwrap(),
+            json!({"certificate": {"version": 1, "seed": "abc"}})
+        );
+    }
+
+    #[test]
+    fn test_certificate_and_authorized_scopes_ext() {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new_with_certificate(
+                "a-client",
+                "a-token",
+                r#"{"version": 1}"#,
+            ))
+            .authorized_scopes(vec!["a-scope"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            get_ext(&client).unwrap(),
+            json!({"certificate": {"version": 1}, "authorizedScopes": ["a-scope"]})
+        );
+    }
+
+    #[test]
+    fn test_empty_client_id() {
+        assert!(ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new("", "a-token"))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_certificate() {
+        assert!(ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new_with_certificate(
+                "a-client", "a-token", "not json"
+            ))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_authorized_scopes_vec() {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new("a-client", "a-token"))
+            .authorized_scopes(vec!["a-scope"])
+            .build()
+            .unwrap();
+        assert_eq!(get_authorized_scopes(&client).unwrap(), vec!["a-scope"]);
+    }
+
+    #[test]
+    fn test_authorized_scopes_iter() {
+        let nums = vec![1, 2, 3];
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new("a-client", "a-token"))
+            .authorized_scopes(nums.iter().map(|n| format!("scope:{}", n)))
+            .build()
+            .unwrap();
+        assert_eq!(
+            get_authorized_scopes(&client).unwrap(),
+            vec!["scope:1", "scope:2", "scope:3"]
+        );
+    }
+
+    #[test]
+    fn test_authorized_scopes_without_credentials() {
+        let err = ClientBuilder::new("https://tc-tests.example.com")
+            .authorized_scopes(vec!["a-scope"])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Authorized scopes require credentials, since unsigned requests cannot carry them"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simple_request() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let resp = client.request("GET", "ping", None, None).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping")).respond_with(
+                // note that the tests do not wait for this to actually time out,
+                // so this is a very long delay to avoid any test intermittency
+                delay_and_then(Duration::from_secs(30), status_code(200)),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .timeout(Duration::from_millis(5))
+            .retry(Retry {
+                retries: 0,
+                ..Default::default()
+            })
+            .build()?;
+        let err = client.request("GET", "ping", None, None).await.unwrap_err();
+        match err.downcast::<ApiError>().unwrap() {
+            ApiError::Transport(reqerr) => assert!(reqerr.is_timeout()),
+            e => panic!("unexpected error {:?}", e),
+        }
+        Ok(())
+    }
+
+    async fn slow_request(timeout: Duration) -> Result<reqwest::Response, Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .respond_with(delay_and_then(Duration::from_millis(500), status_code(200))),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .timeout(timeout)
+            .retry(Retry {
+                retries: 0,
+                ..Default::default()
+            })
+            .build()?;
+        client.request("GET", "ping", None, None).await
+    }
+
+    #[tokio::test]
+    async fn test_zero_timeout() -> Result<(), Error> {
+        // a small timeout fails the slow request..
+        let err = slow_request(Duration::from_millis(50)).await.unwrap_err();
+        match err.downcast::<ApiError>().unwrap() {
+            ApiError::Transport(reqerr) => assert!(reqerr.is_timeout()),
+            e => panic!("unexpected error {:?}", e),
+        }
+
+        // ..but a zero timeout means no timeout at all
+        let resp = slow_request(Duration::from_secs(0)).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[test]
+    fn test_long_poll_timeout_for_client_timeout() -> Result<(), Error> {
+        let timeout = |t| -> Result<Duration, Error> {
+            let client = ClientBuilder::new("https://tc-tests.example.com")
+                .timeout(t)
+                .build()?;
+            Ok(client.long_poll_timeout())
+        };
+        assert_eq!(timeout(Duration::from_secs(30))?, LONG_POLL_TIMEOUT);
+        assert_eq!(timeout(Duration::from_secs(90))?, Duration::from_secs(90));
+        assert_eq!(timeout(Duration::from_secs(0))?, Duration::from_secs(0));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(3) // 1 successful call, then a call with 1 retry
+                .respond_with(delay_and_then(Duration::from_millis(200), status_code(200))),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .timeout(Duration::from_millis(5))
+            .retry(Retry {
+                retries: 1,
+                ..Default::default()
+            })
+            .build()?;
+
+        // a longer timeout overrides the client's timeout
+        let resp = client
+            .request_with_timeout("GET", "ping", None, None, Duration::from_secs(10))
+            .await?;
+        assert!(resp.status().is_success());
+
+        // ..while the client's timeout still applies to other requests; the retry gets the full
+        // timeout, too, and fails in the same way
+        let err = client.request("GET", "ping", None, None).await.unwrap_err();
+        match err.downcast::<ApiError>().unwrap() {
+            ApiError::Transport(reqerr) => assert!(reqerr.is_timeout()),
+            e => panic!("unexpected error {:?}", e),
+        }
+        Ok(())
+    }
+
+    fn deadline_client(server: &Server) -> Result<Client, Error> {
+        let root_url = format!("http://{}", server.addr());
+        ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 10,
+                delay_factor: Duration::from_millis(50),
+                randomization_factor: 0.0,
+                ..Default::default()
+            })
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_request_with_deadline_stops_retrying() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(1..)
+                .respond_with(status_code(500)),
+        );
+        let client = deadline_client(&server)?;
+
+        // attempts at about 0ms, 50ms, and 150ms, after which the next retry, at 350ms, would
+        // start after the deadline; without the deadline there would be 11 attempts over 50s
+        let start = Instant::now();
+        let err = client
+            .request_with_deadline(
+                "GET",
+                "ping",
+                None,
+                None,
+                start + Duration::from_millis(300),
+            )
+            .await
+            .unwrap_err();
+        assert!(start.elapsed() < Duration::from_millis(300));
+        assert_eq!(
+            err.to_string(),
+            "Request deadline passed before it could be retried"
+        );
+        assert_eq!(
+            crate::err_status_code(&err),
+            Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_deadline_huge_retry_after() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(1)
+                .respond_with(status_code(503).insert_header("retry-after", u64::MAX.to_string())),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                max_delay: Duration::from_secs(u64::MAX),
+                ..Default::default()
+            })
+            .build()?;
+
+        // the retry would be too far in the future to represent as an Instant
+        let err = client
+            .request_with_deadline(
+                "GET",
+                "ping",
+                None,
+                None,
+                Instant::now() + Duration::from_secs(60),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Request deadline passed before it could be retried"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_deadline_limits_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(1)
+                .respond_with(delay_and_then(Duration::from_secs(2), status_code(200))),
+        );
+        let client = deadline_client(&server)?;
+
+        // the attempt times out at the deadline, well before the client's timeout
+        let start = Instant::now();
+        let err = client
+            .request_with_deadline(
+                "GET",
+                "ping",
+                None,
+                None,
+                start + Duration::from_millis(100),
+            )
+            .await
+            .unwrap_err();
+        assert!(start.elapsed() < Duration::from_millis(1000));
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::Transport(reqerr)) => assert!(reqerr.is_timeout()),
+            e => panic!("unexpected error {:?}", e),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_deadline_passed() -> Result<(), Error> {
+        // the server expects no requests
+        let server = Server::run();
+        let client = deadline_client(&server)?;
+
+        let err = client
+            .request_with_deadline("GET", "ping", None, None, Instant::now())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Request deadline passed before the request was sent"
+        );
+        Ok(())
+    }
+
+    // this relies on Linux dropping connections to a listener whose backlog is full
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_connect_timeout() -> Result<(), Error> {
+        // a listener with a zero-length backlog that never accepts holds one connection, so a
+        // second connection to it hangs
+        let socket = tokio::net::TcpSocket::new_v4()?;
+        socket.bind("127.0.0.1:0".parse()?)?;
+        let listener = socket.listen(0)?;
+        let addr = listener.local_addr()?;
+        let _held = std::net::TcpStream::connect(addr)?;
+
+        let client = ClientBuilder::new(format!("http://{}", addr))
+            .path_prefix("api/queue/v1/")
+            .connect_timeout(Duration::from_millis(5))
+            .retry(Retry {
+                retries: 0,
+                ..Default::default()
+            })
+            .build()?;
+        let err = tokio::time::timeout(
+            Duration::from_secs(10),
+            client.request("GET", "ping", None, None),
+        )
+        .await?
+        .unwrap_err();
+        match err.downcast::<ApiError>().unwrap() {
+            ApiError::Transport(reqerr) => assert!(reqerr.is_connect()),
+            e => panic!("unexpected error {:?}", e),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_connect_timeout_slow_response() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .respond_with(delay_and_then(Duration::from_millis(200), status_code(200))),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        // a slow response is governed by the overall timeout, not the connect timeout
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .connect_timeout(Duration::from_millis(50))
+            .build()?;
+        let resp = client.request("GET", "ping", None, None).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shared_http_client() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(1)
+                .respond_with(status_code(200)),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/auth/v1/ping"))
+                .times(1)
+                .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let http_client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+        let queue = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .http_client(http_client.clone())
+            .build()?;
+        let auth = ClientBuilder::new(&root_url)
+            .path_prefix("api/auth/v1/")
+            .http_client(http_client)
+            .build()?;
+        assert!(queue
+            .request("GET", "ping", None, None)
+            .await?
+            .status()
+            .is_success());
+        assert!(auth
+            .request("GET", "ping", None, None)
+            .await?
+            .status()
+            .is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shared_http_client_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .respond_with(delay_and_then(Duration::from_secs(30), status_code(200))),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .http_client(reqwest::Client::new())
+            .timeout(Duration::from_millis(5))
+            .retry(Retry {
+                retries: 0,
+                ..Default::default()
+            })
+            .build()?;
+        let err = client.request("GET", "ping", None, None).await.unwrap_err();
+        match err.downcast::<ApiError>().unwrap() {
+            ApiError::Transport(reqerr) => assert!(reqerr.is_timeout()),
+            e => panic!("unexpected error {:?}", e),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_simple_request_with_perm_creds() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .build()?;
+        let resp = client.request("GET", "ping", None, None).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_clone_with_credentials() -> Result<(), Error> {
+        let old_creds = Credentials::new("old-client", "old-token");
+        let new_creds = Credentials::new("new-client", "new-token");
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/old"),
+                signed_with(old_creds.clone(), server.addr()),
+            ])
+            .respond_with(status_code(200)),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/new"),
+                signed_with(new_creds.clone(), server.addr()),
+                request::headers(contains(("x-test", "yes"))),
+            ])
+            .respond_with(status_code(200)),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/none"),
+                request::headers(not(contains(key("authorization")))),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-test", HeaderValue::from_static("yes"));
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .default_headers(headers)
+            .credentials(old_creds)
+            .build()?;
+        let new_client = client.clone_with_credentials(Some(new_creds))?;
+        let unauthenticated_client = client.clone_with_credentials(None)?;
+
+        // the original client is unchanged, while the new clients share its configuration
+        client.request("GET", "old", None, None).await?;
+        new_client.request("GET", "new", None, None).await?;
+        unauthenticated_client
+            .request("GET", "none", None, None)
+            .await?;
+        Ok(())
+    }
+
+    /// Temporary credentials whose certificate expired long ago
+    fn expired_credentials() -> Credentials {
+        let cert = json!({"version": 1, "start": 0, "expiry": 1000, "scopes": []});
+        Credentials::new_with_certificate("temp-client", "old-token", cert.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_credentials_refresh() -> Result<(), Error> {
+        let expired_creds = expired_credentials();
+        let fresh_creds = Credentials::new("temp-client", "fresh-token");
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/test"),
+                signed_with(expired_creds.clone(), server.addr()),
+            ])
+            .times(1)
+            .respond_with(status_code(401)),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/test"),
+                signed_with(fresh_creds.clone(), server.addr()),
+            ])
+            .times(2)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let refreshes = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = refreshes.clone();
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(expired_creds)
+            .credentials_refresh(move || {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Ok(fresh_creds.clone())
+            })
+            .build()?;
+
+        // the first request is retried with fresh credentials, which are then used for the
+        // second request without another refresh
+        client.request("GET", "test", None, None).await?;
+        client.request("GET", "test", None, None).await?;
+        assert_eq!(refreshes.load(std::sync::atomic::Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_credentials_refresh_not_expired() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(401)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(Credentials::new("a-client", "a-token"))
+            .credentials_refresh(|| bail!("credentials should not be refreshed"))
+            .build()?;
+
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        assert_eq!(err_status_code(&err), Some(StatusCode::UNAUTHORIZED));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_credentials_refresh_panics() -> Result<(), Error> {
+        use futures_util::future::FutureExt;
+
+        let expired_creds = expired_credentials();
+        let fresh_creds = Credentials::new("temp-client", "fresh-token");
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/test"),
+                signed_with(expired_creds.clone(), server.addr()),
+            ])
+            .times(2)
+            .respond_with(status_code(401)),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/test"),
+                signed_with(fresh_creds.clone(), server.addr()),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let refreshes = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = refreshes.clone();
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(expired_creds)
+            .credentials_refresh(move || {
+                if counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    panic!("refresh failed");
+                }
+                Ok(fresh_creds.clone())
+            })
+            .build()?;
+
+        // the panic propagates to the first request, but leaves the client usable
+        let res = std::panic::AssertUnwindSafe(client.request("GET", "test", None, None))
+            .catch_unwind()
+            .await;
+        assert!(res.is_err());
+        client.request("GET", "test", None, None).await?;
+        assert_eq!(refreshes.load(std::sync::atomic::Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_credentials_refresh_fails() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(401)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(expired_credentials())
+            .credentials_refresh(|| bail!("no credentials available"))
+            .build()?;
+
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        assert_eq!(err.to_string(), "while refreshing expired credentials");
+        assert_eq!(err.root_cause().to_string(), "no credentials available");
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_with_credentials_authorized_scopes() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .authorized_scopes(vec!["scope:1"])
+            .credentials(Credentials::new("old-client", "old-token"))
+            .build()?;
+        let new_client =
+            client.clone_with_credentials(Some(Credentials::new("new-client", "new-token")))?;
+        assert_eq!(get_authorized_scopes(&new_client)?, vec!["scope:1"]);
+
+        // a scoped client cannot become an unsigned one
+        let err = client.clone_with_credentials(None).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Authorized scopes require credentials, since unsigned requests cannot carry them"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_simple_request_with_temp_creds() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken").create_named_temp_creds(
+            "tempClientId",
+            Duration::from_secs(3600),
+            vec!["some:scope"],
+        )?;
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .build()?;
+        let resp = client.request("GET", "ping", None, None).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_authorized_scopes() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+                hawk_ext(json!({"authorizedScopes": ["scope:a", "scope:b"]})),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .authorized_scopes(vec!["scope:a", "scope:b"])
+            .build()?;
+        let resp = client.request("GET", "ping", None, None).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_scopes() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+                hawk_ext(json!({"authorizedScopes": ["scope:a"]})),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .authorized_scopes(vec!["scope:a", "scope:b"])
+            .build()?;
+        let scopes = vec!["scope:a".to_owned()];
+        let resp = client
+            .request_with_scopes("GET", "ping", None, None, &scopes)
+            .await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_with_authorized_scopes() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+                hawk_ext(json!({"authorizedScopes": ["scope:a"]})),
+            ])
+            .times(2)
+            .respond_with(
+                status_code(200)
+                    .append_header("Content-Type", "application/json")
+                    .body(r#"{"alive": true, "uptime": 1.0}"#),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let queue = crate::Queue::new(ClientBuilder::new(&root_url).credentials(creds))?;
+        let scopes = vec!["scope:a".to_owned()];
+        let scoped = queue.with_authorized_scopes(&scopes)?;
+        scoped.ping().await?;
+        scoped.ping().await?;
+
+        // the original client is unchanged
+        assert!(get_authorized_scopes(&queue.0).is_err());
+        assert_eq!(get_authorized_scopes(&scoped.0)?, scopes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_authorized_scopes_no_credentials() -> Result<(), Error> {
+        let queue = crate::Queue::new(ClientBuilder::new("https://tc-tests.example.com"))?;
+        let err = queue.with_authorized_scopes(&["scope:a"]).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Authorized scopes require credentials, since unsigned requests cannot carry them"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_temp_creds_and_authorized_scopes() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken").create_named_temp_creds(
+            "tempClientId",
+            Duration::from_secs(3600),
+            vec!["scope:a", "scope:b"],
+        )?;
+        let certificate: Value = serde_json::from_str(creds.certificate.as_ref().unwrap())?;
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+                hawk_ext(json!({
+                    "certificate": certificate.clone(),
+                    "authorizedScopes": ["scope:a", "scope:b"],
+                })),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+                hawk_ext(json!({
+                    "certificate": certificate,
+                    "authorizedScopes": ["scope:a"],
+                })),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .authorized_scopes(vec!["scope:a", "scope:b"])
+            .build()?;
+
+        // the certificate is combined with both the client's authorized scopes and those given
+        // for a single request
+        client.request("GET", "ping", None, None).await?;
+        let scopes = vec!["scope:a".to_owned()];
+        client
+            .request_with_scopes("GET", "ping", None, None, &scopes)
+            .await?;
+        Ok(())
+    }
+
+    /// An httptest matcher that will check that the payload hash in the Authorization header
+    /// matches the request's JSON body, or is absent if there is no body.
+    pub fn payload_hashed() -> PayloadHashed {
+        PayloadHashed
+    }
+
+    #[derive(Debug)]
+    pub struct PayloadHashed;
+
+    impl<B: AsRef<[u8]>> Matcher<httptest::http::Request<B>> for PayloadHashed {
+        fn matches(
+            &mut self,
+            input: &httptest::http::Request<B>,
+            _ctx: &mut ExecutionContext,
+        ) -> bool {
+            let auth_header = input
+                .headers()
+                .get(httptest::http::header::AUTHORIZATION)
+                .unwrap();
+            let auth_header: hawk::Header = auth_header.to_str().unwrap()[5..].parse().unwrap();
+            let body = input.body().as_ref();
+            let expected = if body.is_empty() {
+                None
+            } else {
+                Some(hawk::PayloadHasher::hash("application/json", hawk::SHA256, body).unwrap())
+            };
+            auth_header.hash == expected
+        }
+
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            <Self as fmt::Debug>::fmt(self, f)
+        }
+    }
+
+    macro_rules! method_signing_tests {
+        ($($name:ident: $method:expr, $sent_method:expr, $body:expr,)*) => {
+        $(
+            #[tokio::test]
+            async fn $name() -> Result<(), Error> {
+                let creds = Credentials::new("clientId", "accessToken");
+                let body: Option<Value> = $body;
+                let server = Server::run();
+                server.expect(
+                    Expectation::matching(all_of![
+                        request::method_path($sent_method, "/api/secrets/v1/secret/x"),
+                        signed_with(creds.clone(), server.addr()),
+                        payload_hashed(),
+                    ])
+                    .times(1)
+                    .respond_with(status_code(200)),
+                );
+                let root_url = format!("http://{}", server.addr());
+                let client = ClientBuilder::new(&root_url)
+                    .path_prefix("api/secrets/v1/")
+                    .credentials(creds)
+                    .build()?;
+                client
+                    .request($method, "secret/x", None, body.as_ref())
+                    .await?;
+                Ok(())
+            }
+        )*
+        }
+    }
+
+    method_signing_tests! {
+        delete_no_body: "DELETE", "DELETE", None,
+        delete_body: "DELETE", "DELETE", Some(json!({"reason": "gone"})),
+        put_no_body: "PUT", "PUT", None,
+        put_body: "PUT", "PUT", Some(json!({"secret": {"a": 1}, "expires": "2030-01-01T00:00:00Z"})),
+        patch_no_body: "PATCH", "PATCH", None,
+        patch_body: "PATCH", "PATCH", Some(json!({"a": 1})),
+        lowercase_delete: "delete", "DELETE", None,
+        lowercase_put_body: "put", "PUT", Some(json!({"a": 1})),
+    }
+
+    /// Sign the given request, returning the payload hash from the Authorization header
+    fn signed_payload_hash(req: reqwest::Request) -> Result<Option<Vec<u8>>, Error> {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .credentials(Credentials::new("clientId", "accessToken"))
+            .build()?;
+        let signing = client.signing();
+        let creds = signing.credentials.as_ref().unwrap();
+        let req = client.sign_request(creds, req, None)?;
+        let auth_header = req.headers().get("Authorization").unwrap().to_str()?;
+        let auth_header: hawk::Header = auth_header[5..].parse()?;
+        Ok(auth_header.hash)
+    }
+
+    #[test]
+    fn test_sign_request_content_type() -> Result<(), Error> {
+        let req = reqwest::Client::new()
+            .put("https://tc-tests.example.com/api/queue/v1/thing")
+            .header("Content-Type", "Text/Plain; charset=utf-8")
+            .body("hello, world")
+            .build()?;
+        let expected = hawk::PayloadHasher::hash("text/plain", hawk::SHA256, b"hello, world")?;
+        assert_eq!(signed_payload_hash(req)?, Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_request_no_content_type() -> Result<(), Error> {
+        let req = reqwest::Client::new()
+            .put("https://tc-tests.example.com/api/queue/v1/thing")
+            .body("hello, world")
+            .build()?;
+        let expected = hawk::PayloadHasher::hash("", hawk::SHA256, b"hello, world")?;
+        assert_eq!(signed_payload_hash(req)?, Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_request_stream_body() -> Result<(), Error> {
+        let stream = futures_util::stream::once(async {
+            Ok::<_, std::io::Error>(Bytes::from_static(b"hello, world"))
+        });
+        let req = reqwest::Client::new()
+            .put("https://tc-tests.example.com/api/queue/v1/thing")
+            .header("Content-Type", "text/plain")
+            .body(reqwest::Body::wrap_stream(stream))
+            .build()?;
+        assert_eq!(signed_payload_hash(req)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_raw_request() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .path_prefix("api/object/v1/")
+            .credentials(Credentials::new("clientId", "accessToken"))
+            .build()?;
+        let req = client.build_raw_request(
+            &client.signing(),
+            "PUT",
+            "thing",
+            None,
+            Some((
+                "application/octet-stream",
+                Bytes::from_static(b"\x00\x01\x02"),
+            )),
+            None,
+        )?;
+        assert_eq!(
+            req.headers().get("Content-Type").unwrap(),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            req.body().and_then(|b| b.as_bytes()),
+            Some(&b"\x00\x01\x02"[..])
+        );
+
+        let auth_header = req.headers().get("Authorization").unwrap().to_str()?;
+        let auth_header: hawk::Header = auth_header[5..].parse()?;
+        let expected =
+            hawk::PayloadHasher::hash("application/octet-stream", hawk::SHA256, b"\x00\x01\x02")?;
+        assert_eq!(auth_header.hash, Some(expected));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_body() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("PUT", "/api/object/v1/thing"),
+                request::headers(contains(("content-type", "application/octet-stream"))),
+                request::body("raw bytes"),
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/object/v1/")
+            .credentials(creds)
+            .build()?;
+
+        let resp = client
+            .request_with_body(
+                "PUT",
+                "thing",
+                None,
+                "application/octet-stream",
+                b"raw bytes".to_vec(),
+            )
+            .await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hawk_content_type() {
+        assert_eq!(hawk_content_type("application/json"), "application/json");
+        assert_eq!(
+            hawk_content_type("Application/JSON; charset=utf-8"),
+            "application/json"
+        );
+        assert_eq!(hawk_content_type(""), "");
+    }
+
+    async fn redirect_to(location: &'static str) -> Result<reqwest::Response, Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/a/b"))
+                .times(1)
+                .respond_with(status_code(303).insert_header("location", location)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        client.request("GET", "a/b", None, None).await
+    }
+
+    #[tokio::test]
+    async fn test_redirect_location_absolute() -> Result<(), Error> {
+        let resp = redirect_to("https://storage.example.com/data?sig=abc").await?;
+        assert_eq!(
+            Client::redirect_location(&resp).map(String::from),
+            Some("https://storage.example.com/data?sig=abc".to_owned())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redirect_location_relative() -> Result<(), Error> {
+        let resp = redirect_to("../c/d").await?;
+        let location = Client::redirect_location(&resp).unwrap();
+        assert_eq!(location.path(), "/api/queue/v1/c/d");
+        assert_eq!(location.host_str(), resp.url().host_str());
+        assert_eq!(location.port(), resp.url().port());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redirect_location_not_redirect() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/a/b"))
+                .times(1)
+                .respond_with(status_code(200).insert_header("location", "/elsewhere")),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let resp = client.request("GET", "a/b", None, None).await?;
+        assert_eq!(Client::redirect_location(&resp), None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pool_settings() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(2)
+                .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .pool_max_idle_per_host(1)
+            .pool_idle_timeout(Duration::from_secs(300))
+            .build()?;
+
+        // the second request can reuse the connection left idle by the first
+        client.request("GET", "ping", None, None).await?;
+        client.request("GET", "ping", None, None).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_post() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(any())
+                .times(0)
+                .respond_with(status_code(500)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(Credentials::new("clientId", "accessToken"))
+            .dry_run(true)
+            .build()?;
+
+        let res = client
+            .request_json("POST", "task/abc/cancel", None, Some(&json!({"x": 1})))
+            .await?;
+        assert_eq!(res, json!({}));
+        Ok(())
+    }
+
+    #[test]
+    fn test_dry_run_body_redacted() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .path_prefix("api/auth/v1/")
+            .build()?;
+        let req = client
+            .client
+            .post("https://tc-tests.example.com/api/auth/v1/clients/c")
+            .body(json!({"accessToken": "sekrit", "x": 1}).to_string())
+            .build()?;
+        assert_eq!(
+            client.dry_run_body(&req),
+            json!({"accessToken": "<redacted>", "x": 1}).to_string()
+        );
+
+        // a request to the secrets service is redacted, whatever the client's path prefix
+        let client = ClientBuilder::new("https://tc-tests.example.com").build()?;
+        let req = client
+            .client
+            .put("https://tc-tests.example.com/api/secrets/v1/secret/s")
+            .body(json!({"secret": {"password": "hunter2"}}).to_string())
+            .build()?;
+        assert_eq!(client.dry_run_body(&req), "<redacted>");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_get() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(1)
+                .respond_with(status_code(200).body("{\"alive\": true}")),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .dry_run(true)
+            .build()?;
+
+        let res = client.request_json("GET", "ping", None, None).await?;
+        assert_eq!(res, json!({"alive": true}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_via_proxy() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+
+        // the server acts as an HTTP proxy, so it receives requests for the root URL's host,
+        // which must be signed for that host rather than for the proxy
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                request::headers(contains(("host", "tc-tests.example.com"))),
+                signed_with_host(creds.clone(), "tc-tests.example.com", 80),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let proxy_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new("http://tc-tests.example.com")
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .proxy(reqwest::Proxy::http(&proxy_url)?)
+            .build()?;
+        let resp = client.request("GET", "ping", None, None).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_user_agent() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                request::headers(contains((
+                    "user-agent",
+                    concat!("taskcluster-client-rust/", env!("CARGO_PKG_VERSION")),
+                ))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        client.request("GET", "ping", None, None).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_trace_id() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                request::headers(contains(("x-taskcluster-trace-id", "my-job-123"))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .trace_id("my-job-123")
+            .build()?;
+        client.request("GET", "ping", None, None).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_options_trace_id() -> Result<(), Error> {
+        let trace_ids = Record::header(TRACE_ID_HEADER);
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                trace_ids.clone(),
+            ])
+            .times(2)
+            .respond_with(cycle![status_code(500), status_code(200)]),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .trace_id("overridden")
+            .retry(retry_fast())
+            .build()?;
+        let options = RequestOptions::new().trace_id("abc");
+        let (_, stats) = client
+            .request_with_options("GET", "ping", None, None, &options)
+            .await?;
+        assert_eq!(stats.attempts, 2);
+
+        // the retry carries the same trace ID as the first try
+        assert_eq!(
+            trace_ids.values(),
+            vec![Some("abc".to_owned()), Some("abc".to_owned())]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_options_combined() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                signed_with(creds.clone(), server.addr()),
+                hawk_ext(json!({"authorizedScopes": ["scope:a"]})),
+                request::headers(contains(("x-taskcluster-trace-id", "abc"))),
+            ])
+            .times(1)
+            .respond_with(delay_and_then(Duration::from_millis(500), status_code(200))),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .retry(Retry {
+                retries: 0,
+                ..Default::default()
+            })
+            .build()?;
+
+        // the scopes and trace ID apply along with the timeout, which the slow response exceeds
+        let options = RequestOptions::new()
+            .authorized_scopes(vec!["scope:a"])
+            .trace_id("abc")
+            .timeout(Duration::from_millis(100));
+        let err = client
+            .request_with_options("GET", "ping", None, None, &options)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::Transport(e)) if e.is_timeout()
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_options_invalid_trace_id() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com").build()?;
+        let options = RequestOptions::new().trace_id("bad\nid");
+        assert!(client
+            .request_with_options("GET", "ping", None, None, &options)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_trace_id() {
+        assert!(ClientBuilder::new("https://tc-test.example.com")
+            .trace_id("bad\nid")
+            .build()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_from_base_url() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/legacy/queue/v1/task/abc"),
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let base_url = format!("http://{}/legacy/queue/v1/", server.addr());
+        let client = Client::from_base_url(&base_url, Some(creds), retry_fast())?;
+        client.request("GET", "task/abc", None, None).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_taskcluster_services() -> Result<(), Error> {
+        let server = Server::run();
+        for path in &[
+            "/api/auth/v1/ping",
+            "/api/index/v1/ping",
+            "/api/purge-cache/v1/ping",
+            "/api/queue/v1/ping",
+            "/api/worker-manager/v1/ping",
+        ] {
+            server.expect(
+                Expectation::matching(all_of![
+                    request::method_path("GET", *path),
+                    request::headers(contains(key("authorization"))),
+                ])
+                .times(1)
+                .respond_with(
+                    status_code(200)
+                        .insert_header("Content-Type", "application/json")
+                        .body(r#"{"alive": true, "uptime": 1.0}"#),
+                ),
+            );
+        }
+        let root_url = format!("http://{}", server.addr());
+        let tc = crate::Taskcluster::new(
+            ClientBuilder::new(&root_url).credentials(Credentials::new("clientId", "accessToken")),
+        )?;
+
+        assert!(tc.auth().health().await?.alive);
+        assert!(tc.index().health().await?.alive);
+        assert!(tc.purge_cache().health().await?.alive);
+        assert!(tc.queue().health().await?.alive);
+        assert!(tc.worker_manager().health().await?.alive);
+        Ok(())
+    }
+
+    #[test]
+    fn test_taskcluster_invalid_root_url() {
+        assert!(crate::Taskcluster::new("ftp://tc.example.com").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_from_base_url_service_client() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/v1/task/abc/status"))
+                .times(1)
+                .respond_with(
+                    status_code(200)
+                        .append_header("Content-Type", "application/json")
+                        .body(r#"{"status": {}}"#),
+                ),
+        );
+        // the service client's `api/queue/v1/` prefix is not added
+        let queue = crate::Queue::new(ClientBuilder::from_base_url(format!(
+            "http://{}/v1",
+            server.addr()
+        )))?;
+        assert_eq!(queue.status("abc").await?, json!({"status": {}}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_suffix() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                request::headers(contains((
+                    "user-agent",
+                    concat!(
+                        "taskcluster-client-rust/",
+                        env!("CARGO_PKG_VERSION"),
+                        " my-app/1.2.3"
+                    ),
+                ))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .user_agent_suffix("my-app/1.2.3")
+            .build()?;
+        client.request("GET", "ping", None, None).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_default_headers() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/ping"),
+                request::headers(contains(("x-forwarded-auth", "gateway-token"))),
+                // the default Authorization header is replaced by the signature
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Forwarded-Auth",
+            HeaderValue::from_static("gateway-token"),
+        );
+        headers.insert("Authorization", HeaderValue::from_static("Bearer nope"));
+
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .default_headers(headers)
+            .build()?;
+        client.request("GET", "ping", None, None).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ping() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(2)
+                .respond_with(
+                    status_code(200)
+                        .append_header("Content-Type", "application/json")
+                        .body(r#"{"alive": true, "uptime": 12.5}"#),
+                ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        assert_eq!(
+            client.ping().await?,
+            PingResponse {
+                alive: true,
+                uptime: 12.5
+            }
+        );
+        assert!(client.is_alive().await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_is_alive_server_error() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            // the ping is not retried
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
+                .times(1)
+                .respond_with(status_code(500)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        assert!(!client.is_alive().await);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_long_poll_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "POST",
+                "/api/queue/v1/claim-work/proj%2Fqueue",
+            ))
+            // a single attempt, even though the response is slower than the client's timeout
+            .times(1)
+            .respond_with(delay_and_then(
+                Duration::from_millis(500),
+                status_code(200)
+                    .append_header("Content-Type", "application/json")
+                    .body(r#"{"tasks": []}"#),
+            )),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let queue = crate::Queue::new(
+            ClientBuilder::new(root_url)
+                .timeout(Duration::from_millis(100))
+                .retry(retry_fast()),
+        )?;
+
+        let res = queue
+            .claimWork(
+                "proj/queue",
+                &json!({"tasks": 1, "workerGroup": "g", "workerId": "w"}),
+            )
+            .await?;
+        assert_eq!(res, json!({"tasks": []}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_query() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/test"),
+                request::query(url_decoded(contains(("taskcluster", "test")))),
+                request::query(url_decoded(contains(("client", "rust")))),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let resp = client
+            .request(
+                "GET",
+                "test",
+                Some(vec![("taskcluster", "test"), ("client", "rust")]),
+                None,
+            )
+            .await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_body() -> Result<(), Error> {
+        let body = json!({"hello": "world"});
+
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/queue/v1/test"),
+                request::body(json_decoded(eq(body.clone()))),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let resp = client.request("POST", "test", None, Some(&body)).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[test]
+    fn make_url_simple() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let url = client.make_url("ping", None)?;
+        assert_eq!(url, "https://tc-test.example.com/api/queue/v1/ping");
+        Ok(())
+    }
+
+    #[test]
+    fn make_url_root_url_shapes() -> Result<(), Error> {
+        for (root_url, expected) in &[
+            (
+                "https://tc.example.com",
+                "https://tc.example.com/api/queue/v1/ping",
+            ),
+            (
+                "https://tc.example.com/",
+                "https://tc.example.com/api/queue/v1/ping",
+            ),
+            (
+                "https://tc.example.com/prefix",
+                "https://tc.example.com/prefix/api/queue/v1/ping",
+            ),
+            (
+                "https://tc.example.com/prefix/",
+                "https://tc.example.com/prefix/api/queue/v1/ping",
+            ),
+            (
+                "https://tc.example.com/a/b",
+                "https://tc.example.com/a/b/api/queue/v1/ping",
+            ),
+            (
+                "http://localhost:8080",
+                "http://localhost:8080/api/queue/v1/ping",
+            ),
+        ] {
+            let client = ClientBuilder::new(*root_url)
+                .path_prefix("api/queue/v1/")
+                .build()?;
+            assert_eq!(&client.make_url("ping", None)?, expected, "{}", root_url);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_root_urls() {
+        for root_url in &[
+            "tc.example.com",
+            "ftp://tc.example.com",
+            "https://tc.example.com?x=y",
+            "https://tc.example.com#frag",
+        ] {
+            assert!(
+                ClientBuilder::new(*root_url).build().is_err(),
+                "{}",
+                root_url
+            );
+        }
+    }
+
+    #[test]
+    fn make_url_escapable_characters() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let url = client.make_url("escape%2Fthis!", None)?;
+        assert_eq!(
+            url,
+            "https://tc-test.example.com/api/queue/v1/escape%2Fthis!"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn make_url_query() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let url = client.make_url("a/b/c", Some(vec![("abc", "def"), ("x!z", "1/3")]))?;
+        assert_eq!(
+            url,
+            "https://tc-test.example.com/api/queue/v1/a/b/c?abc=def&x%21z=1%2F3"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn make_url_query_order() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let url1 = client.make_url("a", Some(vec![("z", "1"), ("a", "2"), ("m", "3")]))?;
+        let url2 = client.make_url("a", Some(vec![("m", "3"), ("z", "1"), ("a", "2")]))?;
+        assert_eq!(url1, url2);
+        assert_eq!(
+            url1,
+            "https://tc-test.example.com/api/queue/v1/a?a=2&m=3&z=1"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn make_url_query_repeated_key() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let url = client.make_url("a", Some(vec![("b", "2"), ("a", "1"), ("b", "1")]))?;
+        assert_eq!(
+            url,
+            "https://tc-test.example.com/api/queue/v1/a?a=1&b=2&b=1"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_query_order_signed() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/test"),
+                request::query("a=2&z=1"),
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .times(2)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .build()?;
+
+        client
+            .request("GET", "test", Some(vec![("z", "1"), ("a", "2")]), None)
+            .await?;
+        client
+            .request("GET", "test", Some(vec![("a", "2"), ("z", "1")]), None)
+            .await?;
+        Ok(())
+    }
+
+    #[test]
+    fn make_signed_url_simple() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .build()?;
+        let url = client.make_signed_url("a/b", None, Duration::from_secs(10))?;
+        assert!(url.starts_with("https://tc-test.example.com/api/queue/v1/a/b?bewit="));
+        Ok(())
+    }
+
+    #[test]
+    fn make_signed_url_query() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .build()?;
+        let url = client.make_signed_url(
+            "a/b/c",
+            Some(vec![("abc", "def"), ("xyz", "1/3")]),
+            Duration::from_secs(10),
+        )?;
+        assert!(url.starts_with(
+            "https://tc-test.example.com/api/queue/v1/a/b/c?abc=def&xyz=1%2F3&bewit="
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn build_signed_url_no_credentials() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        assert!(client
+            .build_signed_url("GET", "a/b", None, Duration::from_secs(10))
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_header_round_trip() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/queue/v1/a/b"),
+                request::query("x=1"),
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .build()?;
+
+        let body = json!({"a": 1});
+        let header = client.sign_header("POST", "a/b", Some(vec![("x", "1")]), Some(&body))?;
+        assert!(header.starts_with("Hawk "));
+
+        // send the request without the client, using only the computed header
+        let url = client.make_url("a/b", Some(vec![("x", "1")]))?;
+        reqwest::Client::new()
+            .post(&url)
+            .header("Authorization", header)
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prepare() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/queue/v1/a/b"),
+                request::query("x=1"),
+                request::body(json_decoded(eq(json!({"a": 1})))),
+                signed_with(creds.clone(), server.addr()),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(creds)
+            .build()?;
+
+        let req = client.prepare(
+            "POST",
+            "a/b",
+            Some(vec![("x", "1")]),
+            Some(&json!({"a": 1})),
+        )?;
+        assert_eq!(req.method(), reqwest::Method::POST);
+        assert_eq!(
+            req.url().as_str(),
+            format!("{}/api/queue/v1/a/b?x=1", root_url)
+        );
+        assert!(req
+            .headers()
+            .get("Authorization")
+            .unwrap()
+            .to_str()?
+            .starts_with("Hawk "));
+
+        // the prepared request can be sent with any reqwest client
+        reqwest::Client::new()
+            .execute(req)
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepare_no_credentials() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-tests.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let req = client.prepare("GET", "a/b", None, None)?;
+        assert_eq!(
+            req.url().as_str(),
+            "https://tc-tests.example.com/api/queue/v1/a/b"
+        );
+        assert!(req.headers().get("Authorization").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn sign_header_no_credentials() -> Result<(), Error> {
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .build()?;
+        let err = client.sign_header("GET", "a/b", None, None).unwrap_err();
+        assert_eq!(err.to_string(), "Cannot sign a request without credentials");
+        Ok(())
+    }
+
+    #[test]
+    fn hawk_host_and_port_default_port() -> Result<(), Error> {
+        let url = reqwest::Url::parse("https://TC.Example.com/")?;
+        assert_eq!(
+            hawk_host_and_port(&url)?,
+            ("tc.example.com".to_owned(), 443)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hawk_host_and_port_explicit_port() -> Result<(), Error> {
+        let url = reqwest::Url::parse("https://tc.example.com:8443/")?;
+        assert_eq!(
+            hawk_host_and_port(&url)?,
+            ("tc.example.com".to_owned(), 8443)
+        );
+        let url = reqwest::Url::parse("http://127.0.0.1:8080/")?;
+        assert_eq!(hawk_host_and_port(&url)?, ("127.0.0.1".to_owned(), 8080));
+        Ok(())
+    }
+
+    #[test]
+    fn hawk_host_and_port_ipv6() -> Result<(), Error> {
+        let url = reqwest::Url::parse("http://[::1]:8080/")?;
+        assert_eq!(hawk_host_and_port(&url)?, ("[::1]".to_owned(), 8080));
+        let url = reqwest::Url::parse("http://[0:0::1]/")?;
+        assert_eq!(hawk_host_and_port(&url)?, ("[::1]".to_owned(), 80));
+        Ok(())
+    }
+
+    #[test]
+    fn sign_header_ipv6() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let client = ClientBuilder::new("http://[::1]:8080/")
+            .path_prefix("api/queue/v1/")
+            .credentials(creds.clone())
+            .build()?;
+        let header = client.sign_header("GET", "a/b", Some(vec![("x", "1")]), None)?;
+        let header: hawk::Header = header[5..].parse()?;
+
+        let hawk_req =
+            hawk::RequestBuilder::new("GET", "[::1]", 8080, "/api/queue/v1/a/b?x=1").request();
+        let key = hawk::Key::new(&creds.access_token, hawk::SHA256)?;
+        assert!(hawk_req.validate_header(&header, &key, Duration::from_secs(60)));
+        Ok(())
+    }
+
+    #[test]
+    fn build_signed_url_round_trip() -> Result<(), Error> {
+        let creds = Credentials::new("clientId", "accessToken");
+        let client = ClientBuilder::new("https://tc-test.example.com")
+            .path_prefix("api/queue/v1/")
+            .credentials(creds.clone())
+            .build()?;
+        let url = client.build_signed_url(
+            "GET",
+            "a/b/c",
+            Some(vec![("abc", "def")]),
+            Duration::from_secs(10),
+        )?;
+        assert_eq!(url.path(), "/api/queue/v1/a/b/c");
+
+        // split the bewit (appended last) back out of the query, leaving the query that was
+        // signed
+        let mut parts = url.query().unwrap().rsplitn(2, "&bewit=");
+        let bewit: hawk::Bewit = parts.next().unwrap().parse()?;
+        let path_with_query = format!("{}?{}", url.path(), parts.next().unwrap());
+
+        let hawk_req =
+            hawk::RequestBuilder::new("GET", "tc-test.example.com", 443, &path_with_query)
+                .request();
+        let key = hawk::Key::new(&creds.access_token, hawk::SHA256)?;
+        assert!(hawk_req.validate_bewit(&bewit, &key));
+        Ok(())
+    }
+
+    fn retry_fast() -> Retry {
+        Retry {
+            retries: 6,
+            max_delay: Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_on_response() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(3)
+                .respond_with(cycle![status_code(500), status_code(500), status_code(200)]),
+        );
+        let metrics = Arc::new(std::sync::Mutex::new(vec![]));
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .on_response({
+                let metrics = metrics.clone();
+                move |m: &RequestMetrics| metrics.lock().unwrap().push(m.clone())
+            })
+            .build()?;
+
+        client.request("GET", "test", None, None).await?;
+
+        let metrics = metrics.lock().unwrap();
+        let statuses: Vec<_> = metrics.iter().map(|m| m.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                Some(StatusCode::INTERNAL_SERVER_ERROR),
+                Some(StatusCode::INTERNAL_SERVER_ERROR),
+                Some(StatusCode::OK),
+            ]
+        );
+        for m in metrics.iter() {
+            assert_eq!(m.service, "queue");
+            assert_eq!(m.method, "GET");
+            assert_eq!(m.path, "/api/queue/v1/test");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_predicate() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(3)
+                .respond_with(cycle![status_code(404), status_code(404), status_code(200)]),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .retry_predicate(|resp| resp.status() == StatusCode::NOT_FOUND)
+            .build()?;
+
+        let (resp, stats) = client.request_with_stats("GET", "test", None, None).await?;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(stats.attempts, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_predicate_no_retry() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(500)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .retry_predicate(|_| false)
+            .build()?;
+
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        assert_eq!(
+            err_status_code(&err),
+            Some(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        Ok(())
+    }
+
+    /// Send a PUT request with a streaming body, which cannot be cloned, to the given server
+    async fn put_stream(server: &Server) -> Result<reqwest::Response, Error> {
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/object/v1/")
+            .retry(retry_fast())
+            .build()?;
+        let stream = futures_util::stream::iter(vec![
+            Ok::<_, std::io::Error>(Bytes::from_static(b"hello, ")),
+            Ok(Bytes::from_static(b"world")),
+        ]);
+        let req = client
+            .unsigned_request(reqwest::Method::PUT, client.build_url("thing", None)?)
+            .body(reqwest::Body::wrap_stream(stream))
+            .build()?;
+        assert!(req.try_clone().is_none());
+        client.execute_with_retries(req).await
+    }
+
+    #[tokio::test]
+    async fn test_stream_body() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("PUT", "/api/object/v1/thing"),
+                request::body("hello, world"),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let resp = put_stream(&server).await?;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_body_not_retried() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("PUT", "/api/object/v1/thing"))
+                .times(1)
+                .respond_with(status_code(500)),
+        );
+        let err = put_stream(&server).await.unwrap_err();
+        assert_eq!(
+            err_status_code(&err),
+            Some(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        assert!(err.to_string().contains("was not retried"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_500_one_retry() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(2) // 1 try, 1 retry
+                .respond_with(status_code(500)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 1,
+                ..retry_fast()
+            })
+            .build()?;
+
+        let result = client.request("GET", "test", None, None).await;
+        assert_eq!(
+            err_status_code(&result.err().unwrap()),
+            Some(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_500_retry() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(7) // 1 try, 6 retries
+                .respond_with(status_code(500)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .build()?;
+
+        let result = client.request("GET", "test", None, None).await;
+        assert!(result.is_err());
+        let api_err: ApiError = result.err().unwrap().downcast()?;
+        assert_eq!(api_err.status().unwrap(), 500);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_during_backoff() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(500)),
+        );
+        let token = CancellationToken::new();
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            // without cancellation, the first retry would not be made for 30s
+            .retry(Retry {
+                retries: 1,
+                delay_factor: Duration::from_secs(30),
+                max_delay: Duration::from_secs(30),
+                randomization_factor: 0.0,
+                ..Default::default()
+            })
+            .cancellation_token(token.clone())
+            .build()?;
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            token.cancel();
+        });
+
+        let start = std::time::Instant::now();
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::Cancelled)
+        ));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_before_request() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(0)
+                .respond_with(status_code(200)),
+        );
+        let token = CancellationToken::new();
+        token.cancel();
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .cancellation_token(token)
+            .build()?;
+
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::Cancelled)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_drop_during_backoff() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(500)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 1,
+                delay_factor: Duration::from_secs(30),
+                max_delay: Duration::from_secs(30),
+                ..Default::default()
+            })
+            .build()?;
+
+        // dropping the future, here by a timeout, abandons the request without any further
+        // attempts
+        let res = tokio::time::timeout(
+            Duration::from_millis(100),
+            client.request("GET", "test", None, None),
+        )
+        .await;
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_stats() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(3)
+                .respond_with(cycle![status_code(500), status_code(500), status_code(200),]),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .build()?;
+
+        let (resp, stats) = client.request_with_stats("GET", "test", None, None).await?;
+        assert!(resp.status().is_success());
+        assert_eq!(stats.attempts, 3);
+        assert!(stats.total_wait > Duration::from_millis(0));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_with_stats_no_retries() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+
+        let (_, stats) = client.request_with_stats("GET", "test", None, None).await?;
+        assert_eq!(
+            stats,
+            RequestStats {
+                attempts: 1,
+                total_wait: Duration::from_millis(0),
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_500_max_elapsed_time() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                // each try takes at least 50ms, so at most 5 begin within 200ms
+                .times(1..=5)
+                .respond_with(delay_and_then(Duration::from_millis(50), status_code(500))),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 1000,
+                ..retry_fast()
+            })
+            .max_elapsed_time(Duration::from_millis(200))
+            .build()?;
+
+        let result = client.request("GET", "test", None, None).await;
+        assert_eq!(
+            err_status_code(&result.err().unwrap()),
+            Some(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_500_no_retries() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(500)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 0,
+                // long enough that any retry would be noticed
+                delay_factor: Duration::from_secs(10),
+                ..Default::default()
+            })
+            .build()?;
+
+        let start = std::time::Instant::now();
+        let err = client
+            .request_with_stats("GET", "test", None, None)
+            .await
+            .unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            err_status_code(&err),
+            Some(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        // a single attempt is not described as giving up after retries
+        assert!(!err.to_string().starts_with("Gave up"));
+        Ok(())
+    }
+
+    async fn test_retry_after(status: u16, retry_after: &str) -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(2)
+                .respond_with(cycle![
+                    status_code(status).insert_header("Retry-After", retry_after),
+                    status_code(200),
+                ]),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            // with the default delays, a test that did not honor Retry-After would take
+            // several seconds
+            .retry(Retry {
+                retries: 1,
+                delay_factor: Duration::from_secs(30),
+                ..Default::default()
+            })
+            .build()?;
+
+        let resp = tokio::time::timeout(
+            Duration::from_secs(10),
+            client.request("GET", "test", None, None),
+        )
+        .await??;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_429_retry_after_seconds() -> Result<(), Error> {
+        test_retry_after(429, "0").await
+    }
+
+    #[tokio::test]
+    async fn test_429_retry_after_date() -> Result<(), Error> {
+        test_retry_after(429, "Wed, 21 Oct 2015 07:28:00 GMT").await
+    }
+
+    /// Test that a huge Retry-After on a response with the given status is capped at the
+    /// configured max_delay.
+    async fn test_retry_after_capped(status: u16) -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(2)
+                .respond_with(cycle![
+                    status_code(status).insert_header("Retry-After", "18446744073709551615"),
+                    status_code(200),
+                ]),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 1,
+                max_delay: Duration::from_millis(10),
+                ..Default::default()
+            })
+            .max_elapsed_time(Duration::from_secs(60))
+            .build()?;
+
+        let resp = tokio::time::timeout(
+            Duration::from_secs(10),
+            client.request("GET", "test", None, None),
+        )
+        .await??;
+        assert!(resp.status().is_success());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_429_retry_after_capped() -> Result<(), Error> {
+        test_retry_after_capped(429).await
+    }
+
+    #[tokio::test]
+    async fn test_503_retry_after_capped() -> Result<(), Error> {
+        test_retry_after_capped(503).await
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(
+                    status_code(401)
+                        .insert_header("WWW-Authenticate", "hawk")
+                        .insert_header("Date", "Tue, 01 Jan 2030 00:00:00 GMT")
+                        .insert_header("Content-Type", "application/json")
+                        .body(
+                            json!({
+                                "code": "AuthenticationFailed",
+                                "message": "Internal error\n\nStale timestamp\n\n---",
+                            })
+                            .to_string(),
+                        ),
+                ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(Credentials::new("clientId", "accessToken"))
+            .build()?;
+
+        let err = client
+            .request_json("GET", "test", None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err_status_code(&err), Some(StatusCode::UNAUTHORIZED));
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::ClockSkew { server_time, .. }) => {
+                assert_eq!(
+                    *server_time,
+                    "2030-01-01T00:00:00Z".parse::<chrono::DateTime<chrono::Utc>>()?
+                )
+            }
+            e => panic!("unexpected error {:?}", e),
+        }
+        let api_err = err.downcast_ref::<ApiError>().unwrap();
+        assert_eq!(api_err.code(), Some("AuthenticationFailed"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_401_not_clock_skew() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(
+                    status_code(401)
+                        .insert_header("WWW-Authenticate", "hawk")
+                        .insert_header("Date", "Tue, 01 Jan 2030 00:00:00 GMT")
+                        .insert_header("Content-Type", "application/json")
+                        .body(
+                            json!({"code": "AuthenticationFailed", "message": "Bad mac"})
+                                .to_string(),
+                        ),
+                ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .credentials(Credentials::new("clientId", "accessToken"))
+            .build()?;
+
+        let err = client
+            .request_json("GET", "test", None, None)
+            .await
+            .unwrap_err();
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::Status { code: 401, .. }) => {}
+            e => panic!("unexpected error {:?}", e),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_503_retry_after_seconds() -> Result<(), Error> {
+        test_retry_after(503, "0").await
+    }
+
+    #[tokio::test]
+    async fn test_503_retry_after_date() -> Result<(), Error> {
+        test_retry_after(503, "Wed, 21 Oct 2015 07:28:00 GMT").await
+    }
+
+    #[tokio::test]
+    async fn test_503_retries_exhausted() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(3)
+                .respond_with(
+                    status_code(503)
+                        .insert_header("content-type", "application/json")
+                        .body(r#"{"code": "ServiceUnavailable", "message": "overloaded"}"#),
+                ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 2,
+                ..retry_fast()
+            })
+            .build()?;
+
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "Gave up after 3 attempts: HTTP status 503: overloaded"
+        );
+        let api_err = err.downcast_ref::<ApiError>().unwrap();
+        assert_eq!(api_err.code(), Some("ServiceUnavailable"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_503_text_body() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(503).body("<h1>Service Unavailable</h1>\n")),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 0,
+                ..retry_fast()
+            })
+            .build()?;
+
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ApiError>().unwrap().body(),
+            Some(&json!("<h1>Service Unavailable</h1>"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_429_no_retry() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(429)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .retry_on_429(false)
+            .build()?;
+
+        let result = client.request("GET", "test", None, None).await;
+        assert_eq!(
+            err_status_code(&result.err().unwrap()),
+            Some(StatusCode::TOO_MANY_REQUESTS)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_400_no_retry() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(400)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .build()?;
+
+        let result = client.request("GET", "test", None, None).await;
+        assert!(result.is_err());
+        assert_eq!(
+            err_status_code(&result.err().unwrap()),
+            Some(reqwest::StatusCode::BAD_REQUEST)
+        );
+        Ok(())
+    }
+
+    async fn request_json_status(status: u16) -> Result<Error, Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(
+                    status_code(status)
+                        .append_header("Content-Type", "application/json")
+                        .body("{\"code\": \"SomeError\", \"message\": \"uhoh\"}"),
+                ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .retry(retry_fast())
+            .build()?;
+
+        Ok(client
+            .request_json("GET", "test", None, None)
+            .await
+            .unwrap_err())
+    }
+
+    #[tokio::test]
+    async fn test_request_json_400() -> Result<(), Error> {
+        let err = request_json_status(400).await?;
+        assert_eq!(err_status_code(&err), Some(StatusCode::BAD_REQUEST));
+        assert_eq!(format!("{}", err), "HTTP status 400: uhoh");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_json_404() -> Result<(), Error> {
+        let err = request_json_status(404).await?;
+        assert_eq!(err_status_code(&err), Some(StatusCode::NOT_FOUND));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_json_409() -> Result<(), Error> {
+        let err = request_json_status(409).await?;
+        assert_eq!(err_status_code(&err), Some(StatusCode::CONFLICT));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_json_303() -> Result<(), Error> {
+        let err = request_json_status(303).await?;
+        assert_eq!(err_status_code(&err), Some(StatusCode::SEE_OTHER));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_json_success() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test")).respond_with(
+                status_code(200)
+                    .append_header("Content-Type", "application/json")
+                    .body("{\"ok\": true}"),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+
+        let res = client.request_json("GET", "test", None, None).await?;
+        assert_eq!(res, json!({"ok": true}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_json_empty_body() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "POST",
+                "/api/queue/v1/task/abc/cancel",
+            ))
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let queue = crate::Queue::new(ClientBuilder::new(root_url))?;
+
+        let res = queue.cancelTask("abc").await?;
+        assert_eq!(res, Value::Null);
+        Ok(())
+    }
+
+    /// A logger capturing all log messages, as logging is global to the process.  Tests using it
+    /// must pick out their own messages, such as by a unique path.
+    struct CaptureLogger(std::sync::Mutex<Vec<String>>);
+
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    lazy_static::lazy_static! {
+        static ref CAPTURE_LOGGER: CaptureLogger = CaptureLogger(std::sync::Mutex::new(vec![]));
+    }
+
+    /// Start capturing log messages, returning a function to get those captured so far.
+    fn capture_logs() -> impl Fn() -> Vec<String> {
+        static INIT: std::sync::Once = std::sync::Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&*CAPTURE_LOGGER).unwrap();
+            log::set_max_level(log::LevelFilter::Trace);
+        });
+        || CAPTURE_LOGGER.0.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn test_logging_redacts_secrets() -> Result<(), Error> {
+        let logs = capture_logs();
+        let server = Server::run();
+        let secret =
+            json!({"secret": {"password": "hunter2"}, "expires": "2030-01-01T00:00:00.000Z"});
+        server.expect(
+            Expectation::matching(request::method_path(
+                "PUT",
+                "/api/secrets/v1/secret/logging-test",
+            ))
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        server.expect(
+            Expectation::matching(request::method_path(
+                "GET",
+                "/api/secrets/v1/secret/logging-test",
+            ))
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .insert_header("Content-Type", "application/json")
+                    .body(secret.to_string()),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let secrets = crate::Secrets::new(
+            ClientBuilder::new(root_url).credentials(Credentials::new("clientId", "accessToken")),
+        )?;
+
+        secrets.set("logging-test", &secret).await?;
+        assert_eq!(secrets.get("logging-test").await?, secret);
+
+        let logs = logs();
+        let logged = |msg: &str| logs.iter().any(|line| line == msg);
+        assert!(logged("request: PUT /api/secrets/v1/secret/logging-test"));
+        assert!(logged("request body: <redacted>"));
+        assert!(logged(
+            "response: PUT /api/secrets/v1/secret/logging-test: 200 OK"
+        ));
+        assert!(logged("request: GET /api/secrets/v1/secret/logging-test"));
+        assert!(logged(
+            "response: GET /api/secrets/v1/secret/logging-test: 200 OK"
+        ));
+        assert!(logged("response body: <redacted>"));
+        for line in logs.iter() {
+            assert!(!line.contains("hunter2"), "secret logged in {:?}", line);
+            assert!(!line.contains("Hawk"), "authorization logged in {:?}", line);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_logging_redacts_access_tokens() -> Result<(), Error> {
+        let logs = capture_logs();
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", "/api/queue/v1/logging-test"))
+                .times(1)
+                .respond_with(
+                    status_code(200)
+                        .insert_header("Content-Type", "application/json")
+                        .body(
+                            json!({"credentials": {"clientId": "c", "accessToken": "response-token"}})
+                                .to_string(),
+                        ),
+                ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+
+        let body = json!({"accessToken": "request-token", "name": "logging-test"});
+        client
+            .request_json("POST", "logging-test", None, Some(&body))
+            .await?;
 
-    #[test]
-    fn test_authorized_scopes_vec() {
-        let client = ClientBuilder::new("https://tc-tests.example.com")
-            .authorized_scopes(vec!["a-scope"])
-            .build()
-            .unwrap();
-        assert_eq!(get_authorized_scopes(&client).unwrap(), vec!["a-scope"]);
+        let logs = logs();
+        let logged = |msg: &str| logs.iter().any(|line| line == msg);
+        assert!(logged(
+            r#"request body: {"accessToken":"<redacted>","name":"logging-test"}"#
+        ));
+        assert!(logged(
+            r#"response body: {"credentials":{"accessToken":"<redacted>","clientId":"c"}}"#
+        ));
+        for line in logs.iter() {
+            assert!(
+                !line.contains("-token"),
+                "access token logged in {:?}",
+                line
+            );
+        }
+        Ok(())
     }
 
     #[test]
-    fn test_authorized_scopes_iter() {
-        let nums = vec![1, 2, 3];
-        let client = ClientBuilder::new("https://tc-tests.example.com")
-            .authorized_scopes(nums.iter().map(|n| format!("scope:{}", n)))
-            .build()
-            .unwrap();
+    fn test_loggable_body_truncated() -> Result<(), Error> {
+        let url = reqwest::Url::parse("https://tc-tests.example.com/api/queue/v1/ping")?;
+        let body = "x".repeat(MAX_LOGGED_BODY + 10);
+        let logged = loggable_body(&url, body.as_bytes());
         assert_eq!(
-            get_authorized_scopes(&client).unwrap(),
-            vec!["scope:1", "scope:2", "scope:3"]
+            logged,
+            format!("{}... ({} bytes)", "x".repeat(MAX_LOGGED_BODY), body.len())
         );
+        assert_eq!(loggable_body(&url, b"short"), "short");
+        Ok(())
     }
 
-    #[tokio::test]
-    async fn test_simple_request() -> Result<(), Error> {
+    #[test]
+    fn test_is_secrets_url() -> Result<(), Error> {
+        let is_secrets = |url: &str| Ok::<_, Error>(is_secrets_url(&reqwest::Url::parse(url)?));
+        assert!(is_secrets(
+            "https://tc.example.com/api/secrets/v1/secret/s"
+        )?);
+        assert!(is_secrets("https://example.com/tc/api/secrets/v1/secrets")?);
+        assert!(!is_secrets("https://tc.example.com/api/queue/v1/task/abc")?);
+        assert!(!is_secrets(
+            "https://tc.example.com/api/queue/v1/task/abc/artifacts/api%2Fsecrets%2Fx"
+        )?);
+        Ok(())
+    }
+
+    async fn request_json_limited(body: &str, max: usize) -> Result<Value, Error> {
         let server = Server::run();
         server.expect(
-            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping"))
-                .respond_with(status_code(200)),
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(
+                    status_code(200)
+                        .append_header("Content-Type", "application/json")
+                        .body(body.to_owned()),
+                ),
         );
         let root_url = format!("http://{}", server.addr());
-
-        let client = ClientBuilder::new(&root_url)
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
+            .max_response_bytes(max)
             .build()?;
-        let resp = client.request("GET", "ping", None, None).await?;
-        assert!(resp.status().is_success());
-        Ok(())
+        client.request_json("GET", "test", None, None).await
     }
 
     #[tokio::test]
-    async fn test_timeout() -> Result<(), Error> {
-        let server = Server::run();
-        server.expect(
-            Expectation::matching(request::method_path("GET", "/api/queue/v1/ping")).respond_with(
-                // note that the tests do not wait for this to actually time out,
-                // so this is a very long delay to avoid any test intermittency
-                delay_and_then(Duration::from_secs(30), status_code(200)),
-            ),
+    async fn test_max_response_bytes_exceeded() -> Result<(), Error> {
+        let body = json!({"data": "x".repeat(100)}).to_string();
+        let err = request_json_limited(&body, 64).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Response body exceeds the maximum of 64 bytes"
         );
-        let root_url = format!("http://{}", server.addr());
+        Ok(())
+    }
 
-        let client = ClientBuilder::new(&root_url)
+    #[tokio::test]
+    async fn test_max_response_bytes_error_body() -> Result<(), Error> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // a server whose error response streams a body without end, and without a Content-Length
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(async move {
+            let (mut conn, _) = listener.accept().await.unwrap();
+            let _ = conn.read(&mut [0u8; 4096]).await;
+            let head = "HTTP/1.1 500 Internal Server Error\r\ntransfer-encoding: chunked\r\n\r\n";
+            if conn.write_all(head.as_bytes()).await.is_err() {
+                return;
+            }
+            let chunk = format!("{:x}\r\n{}\r\n", 1024, "x".repeat(1024));
+            while conn.write_all(chunk.as_bytes()).await.is_ok() {}
+        });
+
+        let client = ClientBuilder::new(format!("http://{}", addr))
             .path_prefix("api/queue/v1/")
-            .timeout(Duration::from_millis(5))
             .retry(Retry {
                 retries: 0,
                 ..Default::default()
             })
+            .max_response_bytes(64)
             .build()?;
-        let err = client.request("GET", "ping", None, None).await.unwrap_err();
-        let reqerr = err.downcast::<reqwest::Error>().unwrap();
-        assert!(reqerr.is_timeout());
+        let err = tokio::time::timeout(
+            Duration::from_secs(10),
+            client.request("GET", "test", None, None),
+        )
+        .await?
+        .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiError>(),
+            Some(ApiError::Status {
+                code: 500,
+                body: None
+            })
+        ));
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_simple_request_with_perm_creds() -> Result<(), Error> {
-        let creds = Credentials::new("clientId", "accessToken");
+    async fn test_max_response_bytes_within_limit() -> Result<(), Error> {
+        let res = request_json_limited(r#"{"ok": true}"#, 12).await?;
+        assert_eq!(res, json!({"ok": true}));
+        Ok(())
+    }
 
+    #[tokio::test]
+    async fn test_request_json_invalid_body() -> Result<(), Error> {
         let server = Server::run();
         server.expect(
-            Expectation::matching(all_of![
-                request::method_path("GET", "/api/queue/v1/ping"),
-                signed_with(creds.clone(), server.addr()),
-            ])
-            .respond_with(status_code(200)),
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .respond_with(status_code(200).body("not json")),
         );
         let root_url = format!("http://{}", server.addr());
-
-        let client = ClientBuilder::new(&root_url)
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
-            .credentials(creds)
             .build()?;
-        let resp = client.request("GET", "ping", None, None).await?;
-        assert!(resp.status().is_success());
+
+        let err = client
+            .request_json("GET", "test", None, None)
+            .await
+            .unwrap_err();
+        assert!(err.downcast_ref::<serde_json::Error>().is_some());
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_query() -> Result<(), Error> {
+    async fn test_paginate() -> Result<(), Error> {
         let server = Server::run();
+        let page = |token: Option<&str>| {
+            let body = match token {
+                Some(t) => json!({"items": [t], "continuationToken": t}),
+                None => json!({"items": ["last"]}),
+            };
+            status_code(200)
+                .append_header("Content-Type", "application/json")
+                .body(body.to_string())
+        };
         server.expect(
             Expectation::matching(all_of![
-                request::method_path("GET", "/api/queue/v1/test"),
-                request::query(url_decoded(contains(("taskcluster", "test")))),
-                request::query(url_decoded(contains(("client", "rust")))),
+                request::method_path("GET", "/api/queue/v1/things"),
+                request::query(url_decoded(contains(("limit", "1")))),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
             ])
-            .respond_with(status_code(200)),
+            .times(1)
+            .respond_with(page(Some("a"))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/things"),
+                request::query(url_decoded(contains(("limit", "1")))),
+                request::query(url_decoded(contains(("continuationToken", "a")))),
+            ])
+            .times(1)
+            .respond_with(page(Some("b"))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/things"),
+                request::query(url_decoded(contains(("limit", "1")))),
+                request::query(url_decoded(contains(("continuationToken", "b")))),
+            ])
+            .times(1)
+            .respond_with(page(None)),
         );
         let root_url = format!("http://{}", server.addr());
-
-        let client = ClientBuilder::new(&root_url)
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
             .build()?;
-        let resp = client
-            .request(
-                "GET",
-                "test",
-                Some(vec![("taskcluster", "test"), ("client", "rust")]),
-                None,
-            )
+
+        let pages: Vec<Value> = client
+            .paginate("GET", "things", Some(vec![("limit", "1")]))
+            .try_collect()
             .await?;
-        assert!(resp.status().is_success());
+        let items: Vec<&Value> = pages.iter().map(|p| &p["items"][0]).collect();
+        assert_eq!(items, vec!["a", "b", "last"]);
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_body() -> Result<(), Error> {
-        let body = json!({"hello": "world"});
-
+    async fn test_request_stream() -> Result<(), Error> {
+        let body: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
         let server = Server::run();
         server.expect(
-            Expectation::matching(all_of![
-                request::method_path("POST", "/api/queue/v1/test"),
-                request::body(json_decoded(eq(body.clone()))),
-            ])
-            .respond_with(status_code(200)),
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/log"))
+                .times(1)
+                .respond_with(status_code(200).body(body.clone())),
         );
         let root_url = format!("http://{}", server.addr());
-
-        let client = ClientBuilder::new(&root_url)
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
             .build()?;
-        let resp = client.request("POST", "test", None, Some(&body)).await?;
-        assert!(resp.status().is_success());
+
+        let chunks: Vec<Bytes> = client
+            .request_stream("GET", "log", None, None)
+            .await?
+            .try_collect()
+            .await?;
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), body);
         Ok(())
     }
 
-    #[test]
-    fn make_url_simple() -> Result<(), Error> {
-        let client = ClientBuilder::new("https://tc-test.example.com")
+    #[tokio::test]
+    async fn test_request_stream_error() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/log"))
+                .times(1)
+                .respond_with(status_code(404)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
             .build()?;
-        let url = client.make_url("ping", None)?;
-        assert_eq!(url, "https://tc-test.example.com/api/queue/v1/ping");
+
+        let err = client
+            .request_stream("GET", "log", None, None)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err_status_code(&err), Some(StatusCode::NOT_FOUND));
         Ok(())
     }
 
-    #[test]
-    fn make_url_escapable_characters() -> Result<(), Error> {
-        let client = ClientBuilder::new("https://tc-test.example.com")
-            .path_prefix("api/queue/v1/")
-            .build()?;
-        let url = client.make_url("escape%2Fthis!", None)?;
-        assert_eq!(
-            url,
-            "https://tc-test.example.com/api/queue/v1/escape%2Fthis!"
+    /// Set up a server returning three pages of things
+    fn three_page_server() -> Server {
+        let server = Server::run();
+        let page = |items: Value, token: Option<&str>| {
+            let mut body = json!({ "things": items });
+            if let Some(t) = token {
+                body["continuationToken"] = json!(t);
+            }
+            status_code(200)
+                .append_header("Content-Type", "application/json")
+                .body(body.to_string())
+        };
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/things"),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .times(..=1)
+            .respond_with(page(json!([1, 2]), Some("a"))),
         );
-        Ok(())
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/things"),
+                request::query(url_decoded(contains(("continuationToken", "a")))),
+            ])
+            .times(..=1)
+            .respond_with(page(json!([3, 4]), Some("b"))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/things"),
+                request::query(url_decoded(contains(("continuationToken", "b")))),
+            ])
+            .times(..=1)
+            .respond_with(page(json!([5]), None)),
+        );
+        server
     }
 
-    #[test]
-    fn make_url_query() -> Result<(), Error> {
-        let client = ClientBuilder::new("https://tc-test.example.com")
+    #[tokio::test]
+    async fn test_request_all() -> Result<(), Error> {
+        let server = three_page_server();
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
             .build()?;
-        let url = client.make_url("a/b/c", Some(vec![("abc", "def"), ("x!z", "1/3")]))?;
+
+        let items = client.request_all("GET", "things", None, "things").await?;
         assert_eq!(
-            url,
-            "https://tc-test.example.com/api/queue/v1/a/b/c?abc=def&x%21z=1%2F3"
+            items,
+            vec![json!(1), json!(2), json!(3), json!(4), json!(5)]
         );
         Ok(())
     }
 
-    #[test]
-    fn make_signed_url_simple() -> Result<(), Error> {
-        let creds = Credentials::new("clientId", "accessToken");
-        let client = ClientBuilder::new("https://tc-test.example.com")
+    #[tokio::test]
+    async fn test_request_all_max_pages() -> Result<(), Error> {
+        let server = three_page_server();
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
-            .credentials(creds)
+            .max_pages(2)
             .build()?;
-        let url = client.make_signed_url("a/b", None, Duration::from_secs(10))?;
-        assert!(url.starts_with("https://tc-test.example.com/api/queue/v1/a/b?bewit="));
+
+        assert!(client
+            .request_all("GET", "things", None, "things")
+            .await
+            .is_err());
         Ok(())
     }
 
-    #[test]
-    fn make_signed_url_query() -> Result<(), Error> {
-        let creds = Credentials::new("clientId", "accessToken");
-        let client = ClientBuilder::new("https://tc-test.example.com")
+    #[tokio::test]
+    async fn test_request_all_missing_items() -> Result<(), Error> {
+        let server = three_page_server();
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
-            .credentials(creds)
             .build()?;
-        let url = client.make_signed_url(
-            "a/b/c",
-            Some(vec![("abc", "def"), ("xyz", "1/3")]),
-            Duration::from_secs(10),
-        )?;
-        assert!(url.starts_with(
-            "https://tc-test.example.com/api/queue/v1/a/b/c?abc=def&xyz=1%2F3&bewit="
-        ));
+
+        assert!(client
+            .request_all("GET", "things", None, "clients")
+            .await
+            .is_err());
         Ok(())
     }
 
-    fn retry_fast() -> Retry {
-        Retry {
-            retries: 6,
-            max_delay: Duration::from_millis(1),
-            ..Default::default()
-        }
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn test_gzip_response() -> Result<(), Error> {
+        // `{"ok": true}`, gzipped
+        let gzipped: Vec<u8> = vec![
+            31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 171, 86, 202, 207, 86, 178, 82, 40, 41, 42, 77, 173,
+            5, 0, 198, 25, 171, 23, 12, 0, 0, 0,
+        ];
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/queue/v1/test"),
+                request::headers(contains(("accept-encoding", matches("gzip")))),
+            ])
+            .respond_with(
+                status_code(200)
+                    .append_header("Content-Type", "application/json")
+                    .append_header("Content-Encoding", "gzip")
+                    .body(gzipped),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+
+        let res = client.request_json("GET", "test", None, None).await?;
+        assert_eq!(res, json!({"ok": true}));
+        Ok(())
     }
 
     #[tokio::test]
-    async fn test_500_retry() -> Result<(), Error> {
+    async fn test_404_error_body() -> Result<(), Error> {
         let server = Server::run();
         server.expect(
             Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
-                .times(7) // 1 try, 6 retries
-                .respond_with(status_code(500)),
+                .times(1)
+                .respond_with(
+                    status_code(404)
+                        .append_header("Content-Type", "application/json")
+                        .body(
+                            json!({
+                                "code": "ResourceNotFound",
+                                "message": "Task not found",
+                                "requestInfo": {"method": "task"},
+                            })
+                            .to_string(),
+                        ),
+                ),
         );
         let root_url = format!("http://{}", server.addr());
         let client = ClientBuilder::new(root_url)
@@ -720,33 +5486,76 @@ mod tests {
             .retry(retry_fast())
             .build()?;
 
-        let result = client.request("GET", "test", None, None).await;
-        assert!(result.is_err());
-        let reqw_err: reqwest::Error = result.err().unwrap().downcast()?;
-        assert_eq!(reqw_err.status().unwrap(), 500);
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::Status {
+                code: 404,
+                body: Some(body),
+            }) => assert_eq!(body.get("code"), Some(&json!("ResourceNotFound"))),
+            e => panic!("unexpected error {:?}", e),
+        }
+        assert_eq!(format!("{}", err), "HTTP status 404: Task not found");
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_400_no_retry() -> Result<(), Error> {
+    async fn test_409_error_body() -> Result<(), Error> {
         let server = Server::run();
         server.expect(
-            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+            Expectation::matching(request::method_path("PUT", "/api/queue/v1/task/abc"))
                 .times(1)
-                .respond_with(status_code(400)),
+                .respond_with(
+                    status_code(409)
+                        .append_header("Content-Type", "application/json")
+                        .body(
+                            json!({
+                                "code": "RequestConflict",
+                                "message": "taskId already used",
+                                "requestInfo": {"method": "createTask", "params": {"taskId": "abc"}},
+                            })
+                            .to_string(),
+                        ),
+                ),
         );
         let root_url = format!("http://{}", server.addr());
         let client = ClientBuilder::new(root_url)
             .path_prefix("api/queue/v1/")
-            .retry(retry_fast())
             .build()?;
 
-        let result = client.request("GET", "test", None, None).await;
-        assert!(result.is_err());
+        let err = client
+            .request("PUT", "task/abc", None, Some(&json!({})))
+            .await
+            .unwrap_err();
+        let err = err.downcast_ref::<ApiError>().unwrap();
+        assert_eq!(err.code(), Some("RequestConflict"));
         assert_eq!(
-            err_status_code(&result.err().unwrap()),
-            Some(reqwest::StatusCode::BAD_REQUEST)
+            err.request_info().and_then(|ri| ri.get("method")),
+            Some(&json!("createTask"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_non_json_error_body() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/test"))
+                .times(1)
+                .respond_with(status_code(404).body("<html>Not Found</html>")),
         );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(root_url)
+            .path_prefix("api/queue/v1/")
+            .build()?;
+
+        let err = client.request("GET", "test", None, None).await.unwrap_err();
+        match err.downcast_ref::<ApiError>() {
+            Some(ApiError::Status {
+                code: 404,
+                body: None,
+            }) => {}
+            e => panic!("unexpected error {:?}", e),
+        }
         Ok(())
     }
 
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// A limit on the number of requests in flight at once, which can be shared by any number of
+/// clients, including clients for different services.  Clone the limit to share it: clones
+/// count against the same limit.
+///
+/// A request holds one of the limit's permits while it is sent and its response headers are
+/// received, but not while it waits to be retried, nor while the response body is read.
+/// Requests beyond the limit wait for a permit before they are sent.
+///
+/// Give a limit to a client with
+/// [`ClientBuilder::concurrency_limit`](crate::ClientBuilder::concurrency_limit).
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimit(Option<Arc<Semaphore>>);
+
+impl ConcurrencyLimit {
+    /// Create a new limit, allowing at most `max_concurrent` requests in flight at once.  A
+    /// limit of zero means no limit, rather than one that no request could ever pass.
+    pub fn new(max_concurrent: usize) -> Self {
+        if max_concurrent == 0 {
+            Self(None)
+        } else {
+            Self(Some(Arc::new(Semaphore::new(max_concurrent))))
+        }
+    }
+
+    /// Wait for a permit to send a request, which is released when dropped.  Without a limit,
+    /// there is no permit to wait for.
+    pub(crate) async fn acquire(&self) -> Option<SemaphorePermit<'_>> {
+        match self.0 {
+            // the semaphore is never closed, so this cannot fail
+            Some(ref semaphore) => Some(semaphore.acquire().await.unwrap()),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::Record;
+    use crate::ClientBuilder;
+    use anyhow::Error;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrency_limit() -> Result<(), Error> {
+        const DELAY: Duration = Duration::from_millis(200);
+
+        let arrivals = Record::arrivals();
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/ping"),
+                arrivals.clone(),
+            ])
+            .times(5)
+            .respond_with(delay_and_then(DELAY, status_code(200))),
+        );
+        let root_url = format!("http://{}", server.addr());
+
+        // two clients share the limit
+        let limit = ConcurrencyLimit::new(2);
+        let client1 = ClientBuilder::new(&root_url)
+            .concurrency_limit(limit.clone())
+            .build()?;
+        let client2 = ClientBuilder::new(&root_url)
+            .concurrency_limit(limit)
+            .build()?;
+
+        let results = futures_util::future::join_all(vec![
+            client1.request("GET", "ping", None, None),
+            client2.request("GET", "ping", None, None),
+            client1.request("GET", "ping", None, None),
+            client2.request("GET", "ping", None, None),
+            client1.request("GET", "ping", None, None),
+        ])
+        .await;
+        for res in results {
+            res?;
+        }
+
+        // with at most two requests in flight, each request arrives no sooner than one response
+        // delay after the request two before it
+        let arrivals = arrivals.values();
+        assert_eq!(arrivals.len(), 5);
+        for (earlier, later) in arrivals.iter().zip(arrivals.iter().skip(2)) {
+            assert!(
+                later.duration_since(*earlier) >= DELAY - Duration::from_millis(50),
+                "more than two requests were in flight at once"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit_zero() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/ping"))
+                .times(2)
+                .respond_with(status_code(200)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let client = ClientBuilder::new(&root_url)
+            .concurrency_limit(ConcurrencyLimit::new(0))
+            .build()?;
+
+        let results = tokio::time::timeout(
+            Duration::from_secs(10),
+            futures_util::future::join_all(vec![
+                client.request("GET", "ping", None, None),
+                client.request("GET", "ping", None, None),
+            ]),
+        )
+        .await?;
+        for res in results {
+            res?;
+        }
+        Ok(())
+    }
+}
@@ -1,16 +1,23 @@
 use crate::util::collect_scopes;
+use crate::CredentialsError;
 use anyhow::{anyhow, Context, Error};
 use crypto::hmac::Hmac;
 use crypto::mac::Mac;
 use crypto::sha2::Sha256;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::env;
+use std::io;
 use std::iter::{IntoIterator, Iterator};
+use std::path::PathBuf;
 use std::time::{Duration, SystemTime};
 
 /// Credentials represents the set of credentials required to access protected
 /// Taskcluster HTTP APIs.
-#[derive(Debug, PartialEq, Clone)]
+///
+/// This deserializes from the JSON format used in API responses, with properties `clientId`,
+/// `accessToken`, and optionally `certificate`.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Credentials {
     /// Client ID
     pub client_id: String,
@@ -36,6 +43,104 @@ pub(crate) struct Certificate {
     pub issuer: Option<String>,
 }
 
+/// The `config` section of the Taskcluster CLI's configuration file
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct CliConfig {
+    root_url: Option<String>,
+    client_id: Option<String>,
+    access_token: Option<String>,
+    certificate: Option<String>,
+    authorized_scopes: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct CliConfigFile {
+    #[serde(default)]
+    config: CliConfig,
+}
+
+/// The location of the Taskcluster CLI's configuration file, as determined by the CLI
+fn cli_config_file() -> Option<PathBuf> {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => env::var_os("HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(|dir| PathBuf::from(dir).join(".config")),
+    }
+    .map(|dir| dir.join("taskcluster.yml"))
+}
+
+/// Parse the contents of the CLI configuration file, given the value of `TASKCLUSTER_ROOT_URL`,
+/// if set.
+fn parse_cli_config(
+    data: &str,
+    env_root_url: Option<String>,
+) -> Result<Option<(String, Credentials)>, Error> {
+    let file: Option<CliConfigFile> = serde_yaml::from_str(data)?;
+    let config = file.unwrap_or_default().config;
+
+    // treat empty strings, which the CLI uses as defaults, as missing
+    let non_empty = |s: Option<String>| s.filter(|s| !s.is_empty());
+    let env_root_url = non_empty(env_root_url);
+    let file_root_url = non_empty(config.root_url);
+
+    let root_url = match (env_root_url, file_root_url) {
+        // credentials in the file are for a different deployment
+        (Some(env_root_url), Some(file_root_url))
+            if env_root_url.trim_end_matches('/') != file_root_url.trim_end_matches('/') =>
+        {
+            return Ok(None)
+        }
+        (Some(root_url), _) | (None, Some(root_url)) => root_url,
+        (None, None) => return Ok(None),
+    };
+
+    match (non_empty(config.client_id), non_empty(config.access_token)) {
+        // the CLI only restricts its requests to non-empty authorized scopes; these credentials
+        // cannot carry them, and ignoring them would grant more than the configuration allows
+        (Some(_), Some(_)) if config.authorized_scopes.map_or(false, |s| !s.is_empty()) => {
+            Err(anyhow!(
+                "authorizedScopes in the Taskcluster CLI configuration are not supported; \
+                 use ClientBuilder::authorized_scopes instead"
+            ))
+        }
+        (Some(client_id), Some(access_token)) => Ok(Some((
+            root_url,
+            Credentials {
+                client_id,
+                access_token,
+                certificate: non_empty(config.certificate),
+            },
+        ))),
+        _ => Ok(None),
+    }
+}
+
+/// Return true if the given temporary-credentials certificate has an `expiry` in the past.  A
+/// certificate without a valid `expiry` is not considered expired, leaving the auth service to
+/// reject it.
+pub(crate) fn certificate_expired(certificate: &serde_json::Value) -> bool {
+    let expiry = match certificate.get("expiry").and_then(|e| e.as_i64()) {
+        Some(expiry) => expiry,
+        None => return false,
+    };
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64;
+    expiry <= now
+}
+
+/// Read an environment variable, returning None if it is not set.
+fn env_var(name: &'static str) -> Result<Option<String>, CredentialsError> {
+    match env::var(name) {
+        Ok(value) => Ok(Some(value)),
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(env::VarError::NotUnicode(_)) => Err(CredentialsError::InvalidEnvVar(name)),
+    }
+}
+
 fn gen_temp_access_token(perm_access_token: &str, seed: &str) -> String {
     let mut hash = Hmac::new(Sha256::new(), perm_access_token.as_bytes());
     hash.input(seed.as_bytes());
@@ -49,23 +154,11 @@ impl Credentials {
     /// * `TASKCLUSTER_ACCESS_TOKEN`
     /// * `TASKCLUSTER_CERTIFICATE` (optional)
     pub fn from_env() -> Result<Credentials, Error> {
-        let client_id = env::var("TASKCLUSTER_CLIENT_ID").context("TASKCLUSTER_CLIENT_ID")?;
-        let access_token =
-            env::var("TASKCLUSTER_ACCESS_TOKEN").context("TASKCLUSTER_ACCESS_TOKEN")?;
-
-        let certificate = match env::var("TASKCLUSTER_CERTIFICATE") {
-            Err(err) => match err {
-                env::VarError::NotPresent => None,
-                _ => {
-                    return Err(anyhow!(
-                        "Cannot read environment variable 'TASKCLUSTER_CERTIFICATE': {}",
-                        err
-                    ))
-                }
-            },
-            Ok(cert) if cert.is_empty() => None,
-            Ok(cert) => Some(cert),
-        };
+        let client_id = env_var("TASKCLUSTER_CLIENT_ID")?
+            .ok_or(CredentialsError::MissingEnvVar("TASKCLUSTER_CLIENT_ID"))?;
+        let access_token = env_var("TASKCLUSTER_ACCESS_TOKEN")?
+            .ok_or(CredentialsError::MissingEnvVar("TASKCLUSTER_ACCESS_TOKEN"))?;
+        let certificate = env_var("TASKCLUSTER_CERTIFICATE")?.filter(|cert| !cert.is_empty());
 
         Ok(Credentials {
             client_id,
@@ -74,6 +167,29 @@ impl Credentials {
         })
     }
 
+    /// Read the root URL and credentials from the configuration file used by the Taskcluster
+    /// CLI, `$XDG_CONFIG_HOME/taskcluster.yml` or `~/.config/taskcluster.yml`.  If
+    /// `TASKCLUSTER_ROOT_URL` is set, it takes precedence over the root URL in the file, and the
+    /// file's credentials are only used if they are for the same deployment.
+    ///
+    /// This returns `Ok(None)` if the file does not exist or does not contain credentials for
+    /// the selected deployment, so callers can fall back to other sources of credentials.  If the
+    /// file configures `authorizedScopes` for those credentials, this fails, rather than
+    /// returning credentials that are not restricted to them.
+    pub fn from_cli_config() -> Result<Option<(String, Credentials)>, Error> {
+        let path = match cli_config_file() {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let data = match std::fs::read_to_string(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err).context(format!("while reading {}", path.display())),
+        };
+        parse_cli_config(&data, env::var("TASKCLUSTER_ROOT_URL").ok())
+            .context(format!("while parsing {}", path.display()))
+    }
+
     /// Create a new Credentials object with clientId and accessToken
     ///
     /// Examples:
@@ -115,6 +231,44 @@ impl Credentials {
         }
     }
 
+    /// Check that these credentials are well-formed: the client ID and access token must be
+    /// non-empty, the access token must be usable as a Hawk key, and the certificate, if any,
+    /// must be a JSON object.  This does not check the credentials with the auth service.
+    ///
+    /// This is called when building a client, so malformed credentials are detected before any
+    /// requests are made.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.client_id.is_empty() {
+            return Err(CredentialsError::EmptyClientId.into());
+        }
+        if self.access_token.is_empty() {
+            return Err(CredentialsError::EmptyAccessToken(self.client_id.clone()).into());
+        }
+        hawk::Key::new(&self.access_token, hawk::SHA256)
+            .context(CredentialsError::InvalidAccessToken(self.client_id.clone()))?;
+        if let Some(ref certificate) = self.certificate {
+            let certificate: serde_json::Value = serde_json::from_str(certificate).context(
+                CredentialsError::InvalidCertificateJson(self.client_id.clone()),
+            )?;
+            if !certificate.is_object() {
+                return Err(CredentialsError::MalformedCertificate(self.client_id.clone()).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Return true if these are temporary credentials whose certificate has expired, in which
+    /// case the auth service will reject any request made with them.  Permanent credentials
+    /// never expire.
+    pub fn is_expired(&self) -> bool {
+        match self.certificate {
+            Some(ref certificate) => serde_json::from_str(certificate)
+                .map(|certificate| certificate_expired(&certificate))
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+
     /// Generate temporary credentials from permanent credentials, valid for the given duration,
     /// starting immediately.  The temporary credentials' scopes must be a subset of the permanent
     /// credentials' scopes. The duration may not be more than 31 days. Any authorized scopes of
@@ -129,11 +283,11 @@ impl Credentials {
     /// See https://docs.taskcluster.net/docs/manual/design/apis/hawk/temporary-credentials
     pub fn create_named_temp_creds(
         &self,
-        temp_client_id: &str,
+        temp_client_id: impl AsRef<str>,
         duration: Duration,
         scopes: impl IntoIterator<Item = impl AsRef<str>>,
     ) -> Result<Credentials, Error> {
-        self.create_temp_creds_inner(Some(temp_client_id), duration, scopes)
+        self.create_temp_creds_inner(Some(temp_client_id.as_ref()), duration, scopes)
     }
 
     /// Similar to `create_named_temp_creds`, but creating unnamed credentials.  This approach is
@@ -275,6 +429,209 @@ mod tests {
         assert_eq!(creds.certificate, None);
     }
 
+    #[test]
+    fn test_new_string_forms() {
+        assert_eq!(
+            Credentials::new("a-client", "a-token"),
+            Credentials::new(String::from("a-client"), String::from("a-token"))
+        );
+        assert_eq!(
+            Credentials::new_with_certificate("a-client", "a-token", "{}"),
+            Credentials::new_with_certificate(
+                String::from("a-client"),
+                "a-token",
+                String::from("{}")
+            )
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Credentials::new("a-client", "a-token").validate().is_ok());
+        assert!(
+            Credentials::new_with_certificate("a-client", "a-token", "{}")
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_empty_client_id() {
+        assert!(Credentials::new("", "a-token").validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_empty_access_token() {
+        assert!(Credentials::new("a-client", "").validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_bad_certificate() {
+        assert!(
+            Credentials::new_with_certificate("a-client", "a-token", "not json")
+                .validate()
+                .is_err()
+        );
+        assert!(
+            Credentials::new_with_certificate("a-client", "a-token", "[]")
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_error_kind() {
+        let err = Credentials::new("a-client", "").validate().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<CredentialsError>(),
+            Some(&CredentialsError::EmptyAccessToken("a-client".into()))
+        );
+
+        // a JSON parsing error is kept as the cause
+        let err = Credentials::new_with_certificate("a-client", "a-token", "not json")
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<CredentialsError>(),
+            Some(&CredentialsError::InvalidCertificateJson("a-client".into()))
+        );
+        assert!(err.root_cause().is::<serde_json::Error>());
+
+        let err = Credentials::new_with_certificate("a-client", "a-token", "[]")
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<CredentialsError>(),
+            Some(&CredentialsError::MalformedCertificate("a-client".into()))
+        );
+    }
+
+    const CLI_CONFIG: &str = r#"
+config:
+    rootUrl: https://tc.example.com
+    clientId: a-client
+    accessToken: a-token
+    certificate: ""
+other-command:
+    someOption: 1
+"#;
+
+    #[test]
+    fn test_parse_cli_config() {
+        assert_eq!(
+            parse_cli_config(CLI_CONFIG, None).unwrap(),
+            Some((
+                "https://tc.example.com".to_owned(),
+                Credentials::new("a-client", "a-token")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_config_certificate() {
+        let config = r#"
+config:
+    rootUrl: https://tc.example.com
+    clientId: a-client
+    accessToken: a-token
+    certificate: "{}"
+"#;
+        assert_eq!(
+            parse_cli_config(config, None).unwrap(),
+            Some((
+                "https://tc.example.com".to_owned(),
+                Credentials::new_with_certificate("a-client", "a-token", "{}")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_config_same_root_url() {
+        assert_eq!(
+            parse_cli_config(CLI_CONFIG, Some("https://tc.example.com/".to_owned())).unwrap(),
+            Some((
+                "https://tc.example.com/".to_owned(),
+                Credentials::new("a-client", "a-token")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_config_other_root_url() {
+        assert_eq!(
+            parse_cli_config(CLI_CONFIG, Some("https://other.example.com".to_owned())).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_config_root_url_from_env() {
+        let config = r#"
+config:
+    clientId: a-client
+    accessToken: a-token
+"#;
+        assert_eq!(
+            parse_cli_config(config, Some("https://tc.example.com".to_owned())).unwrap(),
+            Some((
+                "https://tc.example.com".to_owned(),
+                Credentials::new("a-client", "a-token")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_config_no_credentials() {
+        let config = r#"
+config:
+    rootUrl: https://tc.example.com
+    clientId: ""
+    accessToken: ""
+"#;
+        assert_eq!(parse_cli_config(config, None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_cli_config_authorized_scopes() {
+        let config = r#"
+config:
+    rootUrl: https://tc.example.com
+    clientId: a-client
+    accessToken: a-token
+    authorizedScopes: ["queue:create-task:*"]
+"#;
+        let err = parse_cli_config(config, None).unwrap_err();
+        assert!(err.to_string().contains("authorizedScopes"));
+    }
+
+    #[test]
+    fn test_parse_cli_config_empty_authorized_scopes() {
+        let config = r#"
+config:
+    rootUrl: https://tc.example.com
+    clientId: a-client
+    accessToken: a-token
+    authorizedScopes: []
+"#;
+        assert_eq!(
+            parse_cli_config(config, None).unwrap(),
+            Some((
+                "https://tc.example.com".to_owned(),
+                Credentials::new("a-client", "a-token")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_cli_config_empty() {
+        assert_eq!(parse_cli_config("", None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_cli_config_invalid() {
+        assert!(parse_cli_config("config: [", None).is_err());
+    }
+
     #[test]
     fn test_from_env() {
         let _guard = clear_env();
@@ -291,7 +648,11 @@ mod tests {
         let _guard = clear_env();
         env::set_var("TASKCLUSTER_CLIENT_ID", "a-client");
         // (no access token)
-        assert!(Credentials::from_env().is_err());
+        let err = Credentials::from_env().unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<CredentialsError>(),
+            Some(&CredentialsError::MissingEnvVar("TASKCLUSTER_ACCESS_TOKEN"))
+        );
     }
 
     #[test]
@@ -306,6 +667,16 @@ mod tests {
         assert_eq!(creds.certificate, Some("cert".into()));
     }
 
+    #[test]
+    fn test_from_env_empty_cert() {
+        let _guard = clear_env();
+        env::set_var("TASKCLUSTER_CLIENT_ID", "a-client");
+        env::set_var("TASKCLUSTER_ACCESS_TOKEN", "a-token");
+        env::set_var("TASKCLUSTER_CERTIFICATE", "");
+        let creds = Credentials::from_env().unwrap();
+        assert_eq!(creds.certificate, None);
+    }
+
     #[test]
     fn test_unnamed_temp_creds() {
         let creds = Credentials::new("a-client", "a-token");
@@ -366,6 +737,24 @@ mod tests {
         assert_eq!(cert.get("issuer").unwrap(), &json!("a-client"));
     }
 
+    #[test]
+    fn test_named_temp_creds_string_forms() {
+        let creds = Credentials::new("a-client", "a-token");
+        let client_id = String::from("new-cred");
+        let scopes = vec![String::from("scope1"), String::from("scope2")];
+        let temp = creds
+            .create_named_temp_creds(&client_id, time::Duration::from_secs(3600), &scopes)
+            .unwrap();
+        assert_eq!(temp.client_id, "new-cred");
+        let temp = creds
+            .create_named_temp_creds(client_id, time::Duration::from_secs(3600), scopes)
+            .unwrap();
+        assert_eq!(temp.client_id, "new-cred");
+
+        let cert = serde_json::from_str::<Value>(temp.certificate.unwrap().as_ref()).unwrap();
+        assert_eq!(cert.get("scopes").unwrap(), &json!(["scope1", "scope2"]));
+    }
+
     #[test]
     fn test_named_temp_creds_on_temp_creds() {
         let creds = Credentials::new_with_certificate("a-client", "a-token", "{}");
@@ -373,4 +762,33 @@ mod tests {
             .create_named_temp_creds("new-cred", time::Duration::from_secs(3600), vec!["scope1"])
             .is_err());
     }
+
+    #[test]
+    fn test_is_expired() {
+        let cert = json!({"version": 1, "start": 0, "expiry": 1000, "scopes": []});
+        let creds = Credentials::new_with_certificate("a-client", "a-token", cert.to_string());
+        assert!(creds.is_expired());
+    }
+
+    #[test]
+    fn test_is_expired_fresh_temp_creds() {
+        let creds = Credentials::new("a-client", "a-token");
+        let temp = creds
+            .create_temp_creds(time::Duration::from_secs(3600), vec!["scope1"])
+            .unwrap();
+        assert!(!temp.is_expired());
+    }
+
+    #[test]
+    fn test_is_expired_permanent() {
+        assert!(!Credentials::new("a-client", "a-token").is_expired());
+    }
+
+    #[test]
+    fn test_is_expired_invalid_certificate() {
+        let creds = Credentials::new_with_certificate("a-client", "a-token", "not json");
+        assert!(!creds.is_expired());
+        let creds = Credentials::new_with_certificate("a-client", "a-token", "{}");
+        assert!(!creds.is_expired());
+    }
 }
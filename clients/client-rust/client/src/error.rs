@@ -0,0 +1,399 @@
+use crate::util::{parse_server_time, read_body};
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::fmt;
+
+/// The maximum number of characters of a non-JSON error body to include in an error message
+const MAX_DISPLAY_TEXT: usize = 200;
+
+/// The text in the message of an `AuthenticationFailed` error rejecting a request whose Hawk
+/// timestamp is too far from the server's clock
+const STALE_TIMESTAMP: &str = "Stale timestamp";
+
+/// An error from a call to a Taskcluster API.
+///
+/// Client methods return `anyhow::Error`, which can be downcast to this type to determine what
+/// went wrong:
+///
+/// ```
+/// # use taskcluster::ApiError;
+/// fn is_not_found(err: &anyhow::Error) -> bool {
+///     matches!(
+///         err.downcast_ref::<ApiError>(),
+///         Some(ApiError::Status { code: 404, .. })
+///     )
+/// }
+/// ```
+#[derive(Debug)]
+pub enum ApiError {
+    /// The service responded with an error status (4xx or 5xx).  If the response had a JSON body,
+    /// that is included here; for Taskcluster services, this is an object with properties
+    /// `code`, `message`, and `requestInfo`.  Any other non-empty body, such as an error page
+    /// from a load balancer, is included as a JSON string.
+    Status { code: u16, body: Option<Value> },
+
+    /// An error occurred in sending the request or receiving the response, such as a connection
+    /// failure or timeout.
+    Transport(reqwest::Error),
+
+    /// The request was cancelled with the client's
+    /// [`cancellation_token`](crate::ClientBuilder::cancellation_token) before it completed.
+    Cancelled,
+
+    /// The service rejected the request with a 401 `AuthenticationFailed` error because its Hawk
+    /// timestamp was too far from the server's clock.  The server's time is taken from the `ts`
+    /// attribute of the response's `WWW-Authenticate` header, if present, and otherwise from its
+    /// `Date` header.  The response body is kept, as for `Status`.  This usually means that the
+    /// local clock is wrong, and every signed request will fail until it is corrected.
+    ClockSkew {
+        server_time: DateTime<Utc>,
+        local_time: DateTime<Utc>,
+        body: Option<Value>,
+    },
+}
+
+impl ApiError {
+    /// Build an `ApiError::Status` from an error response, consuming the response body.  A body
+    /// larger than `max_body_bytes`, if given, is not kept.
+    pub(crate) async fn from_response(
+        resp: reqwest::Response,
+        max_body_bytes: Option<usize>,
+    ) -> ApiError {
+        let code = resp.status().as_u16();
+        let server_time = parse_server_time(resp.headers());
+        let body = match read_body(resp, max_body_bytes).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).ok().or_else(|| {
+                let text = String::from_utf8_lossy(&bytes).trim().to_owned();
+                if text.is_empty() {
+                    None
+                } else {
+                    Some(Value::String(text))
+                }
+            }),
+            Err(_) => None,
+        };
+        let err = ApiError::Status { code, body };
+        if !err.is_stale_timestamp() {
+            return err;
+        }
+        match (server_time, err) {
+            (Some(server_time), ApiError::Status { body, .. }) => ApiError::ClockSkew {
+                server_time,
+                local_time: Utc::now(),
+                body,
+            },
+            (_, err) => err,
+        }
+    }
+
+    /// Determine whether this error rejects a request because its Hawk timestamp was too far
+    /// from the server's clock.
+    fn is_stale_timestamp(&self) -> bool {
+        self.status() == Some(StatusCode::UNAUTHORIZED)
+            && self.code() == Some("AuthenticationFailed")
+            && self
+                .message()
+                .map_or(false, |message| message.contains(STALE_TIMESTAMP))
+    }
+
+    /// Get the HTTP status code for this error, if it was caused by an HTTP response.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            ApiError::Status { code, .. } => StatusCode::from_u16(*code).ok(),
+            ApiError::Transport(err) => err.status(),
+            ApiError::Cancelled => None,
+            ApiError::ClockSkew { .. } => Some(StatusCode::UNAUTHORIZED),
+        }
+    }
+
+    /// Get the `code` property of the error body, if any.  This is a string identifying the
+    /// type of error, such as `ResourceNotFound` or `RequestConflict`.
+    pub fn code(&self) -> Option<&str> {
+        self.body_property("code").and_then(|c| c.as_str())
+    }
+
+    /// Get the `message` property of the error body, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.body_property("message").and_then(|m| m.as_str())
+    }
+
+    /// Get the `requestInfo` property of the error body, if any.  This contains details of the
+    /// failed request, such as its `method`, `params`, and `payload`.
+    pub fn request_info(&self) -> Option<&Value> {
+        self.body_property("requestInfo")
+    }
+
+    /// Get the body of the error response, if any.
+    pub fn body(&self) -> Option<&Value> {
+        match self {
+            ApiError::Status {
+                body: Some(body), ..
+            }
+            | ApiError::ClockSkew {
+                body: Some(body), ..
+            } => Some(body),
+            _ => None,
+        }
+    }
+
+    fn body_property(&self, property: &str) -> Option<&Value> {
+        self.body().and_then(|body| body.get(property))
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Status { code, .. } => {
+                write!(f, "HTTP status {}", code)?;
+                if let Some(message) = self.message() {
+                    write!(f, ": {}", message)?;
+                } else if let Some(Value::String(text)) = self.body() {
+                    // limit the length of non-JSON bodies, which may be entire HTML pages
+                    let text: String = text.chars().take(MAX_DISPLAY_TEXT).collect();
+                    write!(f, ": {}", text)?;
+                }
+                Ok(())
+            }
+            ApiError::Transport(err) => write!(f, "{}", err),
+            ApiError::Cancelled => write!(f, "Request cancelled"),
+            ApiError::ClockSkew {
+                server_time,
+                local_time,
+                ..
+            } => {
+                let skew = *local_time - *server_time;
+                write!(
+                    f,
+                    "Request rejected due to clock skew: the local clock is {}s {} the server's \
+                     ({} vs. {}); check the system clock",
+                    skew.num_seconds().abs(),
+                    if skew > chrono::Duration::zero() {
+                        "ahead of"
+                    } else {
+                        "behind"
+                    },
+                    local_time.to_rfc3339(),
+                    server_time.to_rfc3339(),
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ApiError::Status { .. } | ApiError::Cancelled | ApiError::ClockSkew { .. } => None,
+            ApiError::Transport(err) => Some(err),
+        }
+    }
+}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(err: reqwest::Error) -> Self {
+        ApiError::Transport(err)
+    }
+}
+
+/// An error in the configuration of credentials, such as a missing environment variable or a
+/// malformed certificate.
+///
+/// Methods such as [`Credentials::from_env`](crate::Credentials::from_env) and
+/// [`Credentials::validate`](crate::Credentials::validate) return `anyhow::Error`, which can be
+/// downcast to this type.  Where the error was caused by another, such as a JSON parsing error,
+/// that error is available from the `anyhow::Error`'s chain of causes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CredentialsError {
+    /// The named environment variable is not set
+    MissingEnvVar(&'static str),
+
+    /// The named environment variable is set, but is not valid unicode
+    InvalidEnvVar(&'static str),
+
+    /// The client ID is empty
+    EmptyClientId,
+
+    /// The access token for the given client ID is empty
+    EmptyAccessToken(String),
+
+    /// The access token for the given client ID cannot be used to sign requests
+    InvalidAccessToken(String),
+
+    /// The certificate for the given client ID is not valid JSON
+    InvalidCertificateJson(String),
+
+    /// The certificate for the given client ID is valid JSON, but not a JSON object
+    MalformedCertificate(String),
+}
+
+impl fmt::Display for CredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialsError::MissingEnvVar(name) => {
+                write!(f, "Environment variable {} is not set", name)
+            }
+            CredentialsError::InvalidEnvVar(name) => {
+                write!(f, "Environment variable {} is not valid unicode", name)
+            }
+            CredentialsError::EmptyClientId => write!(f, "Credentials have an empty client ID"),
+            CredentialsError::EmptyAccessToken(client_id) => {
+                write!(
+                    f,
+                    "Credentials for {} have an empty access token",
+                    client_id
+                )
+            }
+            CredentialsError::InvalidAccessToken(client_id) => write!(
+                f,
+                "Credentials for {} have an invalid access token",
+                client_id
+            ),
+            CredentialsError::InvalidCertificateJson(client_id) => {
+                write!(f, "Certificate for {} is not valid JSON", client_id)
+            }
+            CredentialsError::MalformedCertificate(client_id) => {
+                write!(f, "Certificate for {} is not a JSON object", client_id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CredentialsError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn display_with_message() {
+        let err = ApiError::Status {
+            code: 404,
+            body: Some(json!({"code": "ResourceNotFound", "message": "no such thing"})),
+        };
+        assert_eq!(format!("{}", err), "HTTP status 404: no such thing");
+    }
+
+    #[test]
+    fn display_without_body() {
+        let err = ApiError::Status {
+            code: 500,
+            body: None,
+        };
+        assert_eq!(format!("{}", err), "HTTP status 500");
+    }
+
+    #[test]
+    fn display_with_text_body() {
+        let err = ApiError::Status {
+            code: 503,
+            body: Some(json!("Service Unavailable")),
+        };
+        assert_eq!(format!("{}", err), "HTTP status 503: Service Unavailable");
+    }
+
+    #[test]
+    fn display_with_long_text_body() {
+        let err = ApiError::Status {
+            code: 502,
+            body: Some(json!("x".repeat(1000))),
+        };
+        assert_eq!(
+            format!("{}", err),
+            format!("HTTP status 502: {}", "x".repeat(MAX_DISPLAY_TEXT))
+        );
+    }
+
+    #[test]
+    fn error_body_properties() {
+        let err = ApiError::Status {
+            code: 409,
+            body: Some(json!({
+                "code": "RequestConflict",
+                "message": "already exists",
+                "requestInfo": {"method": "createTask", "params": {"taskId": "abc"}},
+            })),
+        };
+        assert_eq!(err.code(), Some("RequestConflict"));
+        assert_eq!(err.message(), Some("already exists"));
+        assert_eq!(
+            err.request_info(),
+            Some(&json!({"method": "createTask", "params": {"taskId": "abc"}}))
+        );
+    }
+
+    #[test]
+    fn error_body_missing() {
+        let err = ApiError::Status {
+            code: 502,
+            body: None,
+        };
+        assert_eq!(err.code(), None);
+        assert_eq!(err.message(), None);
+        assert_eq!(err.request_info(), None);
+    }
+
+    #[test]
+    fn status() {
+        let err = ApiError::Status {
+            code: 409,
+            body: None,
+        };
+        assert_eq!(err.status(), Some(StatusCode::CONFLICT));
+    }
+
+    #[test]
+    fn display_clock_skew() {
+        let server_time: DateTime<Utc> = "2030-01-01T00:00:00Z".parse().unwrap();
+        let err = ApiError::ClockSkew {
+            server_time,
+            local_time: server_time + chrono::Duration::seconds(1200),
+            body: Some(json!({"code": "AuthenticationFailed", "message": "Stale timestamp"})),
+        };
+        assert_eq!(
+            format!("{}", err),
+            "Request rejected due to clock skew: the local clock is 1200s ahead of the server's \
+             (2030-01-01T00:20:00+00:00 vs. 2030-01-01T00:00:00+00:00); check the system clock"
+        );
+        assert_eq!(err.status(), Some(StatusCode::UNAUTHORIZED));
+        assert_eq!(err.code(), Some("AuthenticationFailed"));
+        assert_eq!(err.message(), Some("Stale timestamp"));
+    }
+
+    macro_rules! credentials_error_display_tests {
+        ($($name:ident: $err:expr, $display:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(format!("{}", $err), $display);
+            }
+        )*
+        }
+    }
+
+    credentials_error_display_tests! {
+        display_missing_env_var:
+            CredentialsError::MissingEnvVar("TASKCLUSTER_ACCESS_TOKEN"),
+            "Environment variable TASKCLUSTER_ACCESS_TOKEN is not set",
+        display_invalid_env_var:
+            CredentialsError::InvalidEnvVar("TASKCLUSTER_CERTIFICATE"),
+            "Environment variable TASKCLUSTER_CERTIFICATE is not valid unicode",
+        display_empty_client_id:
+            CredentialsError::EmptyClientId,
+            "Credentials have an empty client ID",
+        display_empty_access_token:
+            CredentialsError::EmptyAccessToken("a-client".into()),
+            "Credentials for a-client have an empty access token",
+        display_invalid_access_token:
+            CredentialsError::InvalidAccessToken("a-client".into()),
+            "Credentials for a-client have an invalid access token",
+        display_invalid_certificate_json:
+            CredentialsError::InvalidCertificateJson("a-client".into()),
+            "Certificate for a-client is not valid JSON",
+        display_malformed_certificate:
+            CredentialsError::MalformedCertificate("a-client".into()),
+            "Certificate for a-client is not a JSON object",
+    }
+}
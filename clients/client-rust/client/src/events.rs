@@ -0,0 +1,494 @@
+//! Listening for task events, such as a task being completed, without polling.
+//!
+//! Events are received over a WebSocket connection to the deployment's web-server, as GraphQL
+//! subscriptions to the queue's Pulse exchanges.  This is only available with the `events`
+//! feature.
+//!
+//! ```no_run
+//! # use anyhow::Result;
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! use futures_util::stream::TryStreamExt;
+//! use taskcluster::events::{task_events, TaskEventFilter, TaskEventKind};
+//!
+//! let filter = TaskEventFilter::TaskGroup("YLd4fvLHR3GqaK4qsF1fxw".to_owned());
+//! let events = task_events(
+//!     "https://tc.example.com",
+//!     &filter,
+//!     &[TaskEventKind::Completed, TaskEventKind::Failed],
+//! )
+//! .await?;
+//! futures_util::pin_mut!(events);
+//! while let Some(event) = events.try_next().await? {
+//!     println!("{} is {}", event.task_id, event.state);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, bail, Context, Error};
+use futures_util::sink::SinkExt;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+
+/// The WebSocket subprotocol spoken by the web-server's subscription endpoint
+const SUBPROTOCOL: &str = "graphql-ws";
+
+/// The prefix of the names of the queue's exchanges
+const QUEUE_EXCHANGE_PREFIX: &str = "exchange/taskcluster-queue/v1/";
+
+/// The subscription for task events, in terms of arbitrary Pulse messages
+const SUBSCRIPTION_QUERY: &str = "subscription TaskEvents($subscriptions: [PulseSubscription]!) {
+  pulseMessages(subscriptions: $subscriptions) {
+    payload
+    exchange
+    routingKey
+  }
+}";
+
+/// The id of the single operation started on each connection
+const OPERATION_ID: &str = "1";
+
+/// A kind of task event, corresponding to one of the queue's exchanges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskEventKind {
+    /// The task was defined
+    Defined,
+    /// The task became pending
+    Pending,
+    /// A run of the task started running
+    Running,
+    /// The task completed successfully
+    Completed,
+    /// The task failed
+    Failed,
+    /// The task was resolved as an exception, such as by being cancelled
+    Exception,
+}
+
+impl TaskEventKind {
+    /// The name of the queue's exchange for this kind of event, without its prefix
+    fn exchange(self) -> &'static str {
+        match self {
+            TaskEventKind::Defined => "task-defined",
+            TaskEventKind::Pending => "task-pending",
+            TaskEventKind::Running => "task-running",
+            TaskEventKind::Completed => "task-completed",
+            TaskEventKind::Failed => "task-failed",
+            TaskEventKind::Exception => "task-exception",
+        }
+    }
+
+    /// Determine the kind of event from the full name of the exchange it was published to
+    fn from_exchange(exchange: &str) -> Option<Self> {
+        let name = exchange.strip_prefix(QUEUE_EXCHANGE_PREFIX)?;
+        [
+            TaskEventKind::Defined,
+            TaskEventKind::Pending,
+            TaskEventKind::Running,
+            TaskEventKind::Completed,
+            TaskEventKind::Failed,
+            TaskEventKind::Exception,
+        ]
+        .iter()
+        .copied()
+        .find(|kind| kind.exchange() == name)
+    }
+}
+
+/// The tasks to receive events for
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskEventFilter {
+    /// All tasks in the given task group
+    TaskGroup(String),
+    /// All tasks with the given route in their `routes`, without the `route.` prefix
+    Route(String),
+}
+
+impl TaskEventFilter {
+    /// The routing key pattern matching events for this filter
+    fn pattern(&self) -> String {
+        match self {
+            // primary.<taskId>.<runId>.<workerGroup>.<workerId>.<provisionerId>.<workerType>.
+            // <schedulerId>.<taskGroupId>.<reserved>
+            TaskEventFilter::TaskGroup(task_group_id) => {
+                format!("primary.*.*.*.*.*.*.*.{}.#", task_group_id)
+            }
+            TaskEventFilter::Route(route) => format!("route.{}", route),
+        }
+    }
+}
+
+/// An event for a task, such as it being completed
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskEvent {
+    /// The kind of event
+    pub kind: TaskEventKind,
+
+    /// The task's taskId
+    pub task_id: String,
+
+    /// The task's taskGroupId
+    pub task_group_id: String,
+
+    /// The task's state after the event, such as `completed`
+    pub state: String,
+
+    /// The run the event concerns, for events other than `Defined`
+    pub run_id: Option<u32>,
+
+    /// The task's full status after the event, as returned by `queue.status`
+    pub status: Value,
+}
+
+/// A Pulse message as delivered by the subscription
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PulseMessage {
+    payload: Value,
+    exchange: String,
+}
+
+/// The payload of a message on one of the queue's task exchanges
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskMessage {
+    status: Value,
+    run_id: Option<u32>,
+}
+
+impl TaskEvent {
+    /// Interpret a Pulse message from one of the queue's task exchanges
+    fn from_message(message: PulseMessage) -> Result<Self, Error> {
+        let kind = TaskEventKind::from_exchange(&message.exchange)
+            .ok_or_else(|| anyhow!("Unexpected message from exchange {}", message.exchange))?;
+        let payload: TaskMessage = serde_json::from_value(message.payload)
+            .context(format!("while parsing message from {}", message.exchange))?;
+        let field = |name: &str| -> Result<String, Error> {
+            payload.status[name]
+                .as_str()
+                .map(str::to_owned)
+                .ok_or_else(|| anyhow!("Task status in message has no {}", name))
+        };
+        Ok(TaskEvent {
+            kind,
+            task_id: field("taskId")?,
+            task_group_id: field("taskGroupId")?,
+            state: field("state")?,
+            run_id: payload.run_id,
+            status: payload.status,
+        })
+    }
+}
+
+/// The URL of the given deployment's subscription endpoint
+fn subscription_url(root_url: &str) -> Result<reqwest::Url, Error> {
+    let mut url = reqwest::Url::parse(&format!("{}/", root_url.trim_end_matches('/')))
+        .context(format!("while parsing {}", root_url))?
+        .join("subscription")?;
+    let scheme = match url.scheme() {
+        "http" => "ws",
+        "https" => "wss",
+        _ => bail!("The root URL {} must be an http or https URL", root_url),
+    };
+    url.set_scheme(scheme)
+        .map_err(|_| anyhow!("Cannot make a WebSocket URL from {}", root_url))?;
+    Ok(url)
+}
+
+/// A message from the server, in the `graphql-ws` protocol
+#[derive(Debug, Deserialize)]
+struct ServerMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    payload: Value,
+}
+
+/// Parse a WebSocket message as a `graphql-ws` message, or None for messages that are not part
+/// of that protocol, such as pings.
+fn parse_message(message: Message) -> Result<Option<ServerMessage>, Error> {
+    match message {
+        Message::Text(text) => Ok(Some(serde_json::from_str(&text).context(text)?)),
+        Message::Close(_) => bail!("Subscription connection closed by server"),
+        _ => Ok(None),
+    }
+}
+
+/// The error described by the payload of an `error` or `connection_error` message, or of a
+/// `data` message with errors
+fn payload_error(payload: &Value) -> Error {
+    let messages: Vec<&str> = match payload {
+        Value::Array(errors) => errors
+            .iter()
+            .filter_map(|e| e["message"].as_str())
+            .collect(),
+        p => p["message"].as_str().into_iter().collect(),
+    };
+    if messages.is_empty() {
+        anyhow!("Subscription failed: {}", payload)
+    } else {
+        anyhow!("Subscription failed: {}", messages.join("; "))
+    }
+}
+
+/// Subscribe to events of the given kinds for the tasks matching the given filter, on the
+/// deployment with the given root URL.  The returned stream yields each event as it happens,
+/// until the connection is closed or fails.  Events that happened before the subscription
+/// began are not included.
+pub async fn task_events(
+    root_url: &str,
+    filter: &TaskEventFilter,
+    kinds: &[TaskEventKind],
+) -> Result<impl Stream<Item = Result<TaskEvent, Error>>, Error> {
+    let mut request = subscription_url(root_url)?.as_str().into_client_request()?;
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        HeaderValue::from_static(SUBPROTOCOL),
+    );
+    let (mut ws, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .context(format!("while connecting to {}", root_url))?;
+
+    ws.send(Message::Text(
+        json!({"type": "connection_init", "payload": {}}).to_string(),
+    ))
+    .await?;
+    loop {
+        let message = match ws.try_next().await? {
+            Some(message) => parse_message(message)?,
+            None => bail!("Subscription connection closed by server"),
+        };
+        match message {
+            Some(m) if m.kind == "connection_ack" => break,
+            Some(m) if m.kind == "connection_error" => return Err(payload_error(&m.payload)),
+            _ => {}
+        }
+    }
+
+    let pattern = filter.pattern();
+    let subscriptions: Vec<Value> = kinds
+        .iter()
+        .map(|kind| {
+            json!({
+                "exchange": format!("{}{}", QUEUE_EXCHANGE_PREFIX, kind.exchange()),
+                "pattern": pattern,
+            })
+        })
+        .collect();
+    let start = json!({
+        "id": OPERATION_ID,
+        "type": "start",
+        "payload": {
+            "query": SUBSCRIPTION_QUERY,
+            "variables": {"subscriptions": subscriptions},
+        },
+    });
+    ws.send(Message::Text(start.to_string())).await?;
+
+    Ok(stream::try_unfold(ws, |mut ws| async move {
+        while let Some(message) = ws.try_next().await? {
+            let message = match parse_message(message)? {
+                Some(message) => message,
+                None => continue,
+            };
+            match message.kind.as_str() {
+                "data" => {
+                    if message.payload["errors"].is_array() {
+                        return Err(payload_error(&message.payload["errors"]));
+                    }
+                    let pulse_message: PulseMessage =
+                        serde_json::from_value(message.payload["data"]["pulseMessages"].clone())
+                            .context("while parsing subscription data")?;
+                    return Ok(Some((TaskEvent::from_message(pulse_message)?, ws)));
+                }
+                "error" => return Err(payload_error(&message.payload)),
+                "complete" => return Ok(None),
+                // keepalives and anything else
+                _ => {}
+            }
+        }
+        Ok(None)
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures_util::stream::StreamExt;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    fn message(value: Value) -> Message {
+        Message::Text(value.to_string())
+    }
+
+    fn completed_message(task_id: &str) -> Message {
+        message(json!({
+            "id": "1",
+            "type": "data",
+            "payload": {"data": {"pulseMessages": {
+                "exchange": "exchange/taskcluster-queue/v1/task-completed",
+                "routingKey": format!("primary.{}.0.wg.wi.prov.wt.sched.group1._", task_id),
+                "payload": {
+                    "status": {"taskId": task_id, "taskGroupId": "group1", "state": "completed"},
+                    "runId": 0,
+                },
+            }}},
+        }))
+    }
+
+    /// Run a mock subscription server for one connection, which expects the `graphql-ws`
+    /// handshake and a `start` message with the given variables, then sends the given messages.
+    /// Returns the root URL for the server.
+    async fn mock_server(expected_variables: Value, messages: Vec<Message>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let root_url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let callback = |req: &Request, mut resp: Response| {
+                assert_eq!(req.uri().path(), "/subscription");
+                assert_eq!(req.headers()["Sec-WebSocket-Protocol"], SUBPROTOCOL);
+                resp.headers_mut().insert(
+                    "Sec-WebSocket-Protocol",
+                    HeaderValue::from_static(SUBPROTOCOL),
+                );
+                Ok(resp)
+            };
+            let mut ws = tokio_tungstenite::accept_hdr_async(stream, callback)
+                .await
+                .unwrap();
+
+            let recv = |message: Option<Result<Message, _>>| -> Value {
+                serde_json::from_str(message.unwrap().unwrap().to_text().unwrap()).unwrap()
+            };
+            let init = recv(ws.next().await);
+            assert_eq!(init["type"], "connection_init");
+            ws.send(message(json!({"type": "connection_ack"})))
+                .await
+                .unwrap();
+            let start = recv(ws.next().await);
+            assert_eq!(start["type"], "start");
+            assert_eq!(start["payload"]["variables"], expected_variables);
+
+            for m in messages {
+                ws.send(m).await.unwrap();
+            }
+        });
+
+        root_url
+    }
+
+    #[tokio::test]
+    async fn test_task_events_task_group() -> Result<(), Error> {
+        let root_url = mock_server(
+            json!({"subscriptions": [{
+                "exchange": "exchange/taskcluster-queue/v1/task-completed",
+                "pattern": "primary.*.*.*.*.*.*.*.group1.#",
+            }]}),
+            vec![
+                message(json!({"type": "ka"})),
+                completed_message("task1"),
+                completed_message("task2"),
+                message(json!({"id": "1", "type": "complete"})),
+            ],
+        )
+        .await;
+
+        let events = task_events(
+            &root_url,
+            &TaskEventFilter::TaskGroup("group1".to_owned()),
+            &[TaskEventKind::Completed],
+        )
+        .await?;
+        let events: Vec<TaskEvent> = events.try_collect().await?;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, TaskEventKind::Completed);
+        assert_eq!(events[0].task_id, "task1");
+        assert_eq!(events[0].task_group_id, "group1");
+        assert_eq!(events[0].state, "completed");
+        assert_eq!(events[0].run_id, Some(0));
+        assert_eq!(events[1].task_id, "task2");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_task_events_route() -> Result<(), Error> {
+        let root_url = mock_server(
+            json!({"subscriptions": [
+                {"exchange": "exchange/taskcluster-queue/v1/task-failed", "pattern": "route.my.route"},
+                {"exchange": "exchange/taskcluster-queue/v1/task-exception", "pattern": "route.my.route"},
+            ]}),
+            vec![message(json!({"id": "1", "type": "complete"}))],
+        )
+        .await;
+
+        let events = task_events(
+            &root_url,
+            &TaskEventFilter::Route("my.route".to_owned()),
+            &[TaskEventKind::Failed, TaskEventKind::Exception],
+        )
+        .await?;
+        let events: Vec<TaskEvent> = events.try_collect().await?;
+        assert!(events.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_task_events_error() -> Result<(), Error> {
+        let root_url = mock_server(
+            json!({"subscriptions": [{
+                "exchange": "exchange/taskcluster-queue/v1/task-pending",
+                "pattern": "primary.*.*.*.*.*.*.*.group1.#",
+            }]}),
+            vec![message(json!({
+                "id": "1",
+                "type": "data",
+                "payload": {"data": null, "errors": [{"message": "uhoh"}]},
+            }))],
+        )
+        .await;
+
+        let events = task_events(
+            &root_url,
+            &TaskEventFilter::TaskGroup("group1".to_owned()),
+            &[TaskEventKind::Pending],
+        )
+        .await?;
+        futures_util::pin_mut!(events);
+        let err = events.next().await.unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "Subscription failed: uhoh");
+        Ok(())
+    }
+
+    #[test]
+    fn test_subscription_url() -> Result<(), Error> {
+        assert_eq!(
+            subscription_url("https://tc.example.com")?.as_str(),
+            "wss://tc.example.com/subscription"
+        );
+        assert_eq!(
+            subscription_url("http://localhost:5080/")?.as_str(),
+            "ws://localhost:5080/subscription"
+        );
+        assert!(subscription_url("ftp://tc.example.com").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_kind_from_exchange() {
+        assert_eq!(
+            TaskEventKind::from_exchange("exchange/taskcluster-queue/v1/task-running"),
+            Some(TaskEventKind::Running)
+        );
+        assert_eq!(
+            TaskEventKind::from_exchange("exchange/taskcluster-queue/v1/artifact-created"),
+            None
+        );
+    }
+}
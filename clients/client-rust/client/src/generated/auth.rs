@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -16,7 +17,7 @@ use crate::util::urlencode;
 ///   * Inspect or audit clients and roles,
 ///   * Gain access to various services guarded by this API.
 ///
-pub struct Auth (Client);
+pub struct Auth (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Auth {
@@ -28,6 +29,23 @@ impl Auth {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Auth instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -76,8 +94,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::listClients_details(prefix, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listClients endpoint
@@ -92,6 +109,13 @@ impl Auth {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listClients endpoint, following `continuationToken`
+    pub fn listClients_paginated<'a>(&'a self, prefix: Option<&'a str>, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listClients_details(prefix, None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listClients
     fn listClients_details<'a>(prefix: Option<&'a str>, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "clients/";
@@ -116,8 +140,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::client_details(clientId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the client endpoint
@@ -158,8 +181,7 @@ impl Auth {
         let method = "PUT";
         let (path, query) = Self::createClient_details(clientId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createClient
@@ -182,8 +204,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::resetAccessToken_details(clientId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for resetAccessToken
@@ -205,8 +226,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::updateClient_details(clientId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for updateClient
@@ -228,8 +248,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::enableClient_details(clientId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for enableClient
@@ -250,8 +269,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::disableClient_details(clientId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for disableClient
@@ -294,8 +312,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::listRoles_details();
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listRoles endpoint
@@ -331,8 +348,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::listRoles2_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listRoles2 endpoint
@@ -347,6 +363,13 @@ impl Auth {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listRoles2 endpoint, following `continuationToken`
+    pub fn listRoles2_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listRoles2_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listRoles2
     fn listRoles2_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "roles2/";
@@ -372,8 +395,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::listRoleIds_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listRoleIds endpoint
@@ -388,6 +410,13 @@ impl Auth {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listRoleIds endpoint, following `continuationToken`
+    pub fn listRoleIds_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listRoleIds_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listRoleIds
     fn listRoleIds_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "roleids/";
@@ -410,8 +439,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::role_details(roleId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the role endpoint
@@ -449,8 +477,7 @@ impl Auth {
         let method = "PUT";
         let (path, query) = Self::createRole_details(roleId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createRole
@@ -474,8 +501,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::updateRole_details(roleId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for updateRole
@@ -515,8 +541,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::expandScopes_details();
         let body = Some(payload);
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Determine the HTTP request details for expandScopes
@@ -536,8 +561,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::currentScopes_details();
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the currentScopes endpoint
@@ -602,8 +626,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::awsS3Credentials_details(level, bucket, prefix, format);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the awsS3Credentials endpoint
@@ -636,8 +659,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::azureAccounts_details();
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the azureAccounts endpoint
@@ -667,8 +689,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::azureTables_details(account, continuationToken);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the azureTables endpoint
@@ -683,6 +704,13 @@ impl Auth {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the azureTables endpoint, following `continuationToken`
+    pub fn azureTables_paginated<'a>(&'a self, account: &'a str) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::azureTables_details(account, None);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for azureTables
     fn azureTables_details<'a>(account: &'a str, continuationToken: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("azure/{}/tables", urlencode(account));
@@ -706,8 +734,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::azureTableSAS_details(account, table, level);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the azureTableSAS endpoint
@@ -737,8 +764,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::azureContainers_details(account, continuationToken);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the azureContainers endpoint
@@ -753,6 +779,13 @@ impl Auth {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the azureContainers endpoint, following `continuationToken`
+    pub fn azureContainers_paginated<'a>(&'a self, account: &'a str) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::azureContainers_details(account, None);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for azureContainers
     fn azureContainers_details<'a>(account: &'a str, continuationToken: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("azure/{}/containers", urlencode(account));
@@ -776,8 +809,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::azureContainerSAS_details(account, container, level);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the azureContainerSAS endpoint
@@ -813,8 +845,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::sentryDSN_details(project);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the sentryDSN endpoint
@@ -853,8 +884,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::websocktunnelToken_details(wstAudience, wstClient);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the websocktunnelToken endpoint
@@ -892,8 +922,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::gcpCredentials_details(projectId, serviceAccount);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the gcpCredentials endpoint
@@ -928,8 +957,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::authenticateHawk_details();
         let body = Some(payload);
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Determine the HTTP request details for authenticateHawk
@@ -957,8 +985,7 @@ impl Auth {
         let method = "POST";
         let (path, query) = Self::testAuthenticate_details();
         let body = Some(payload);
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Determine the HTTP request details for testAuthenticate
@@ -990,8 +1017,7 @@ impl Auth {
         let method = "GET";
         let (path, query) = Self::testAuthenticateGet_details();
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the testAuthenticateGet endpoint
@@ -0,0 +1,462 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Auth`](crate::Auth).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Auth {
+    client: crate::Auth,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Auth {
+    /// Create a new blocking Auth instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Auth::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Auth::health`](crate::Auth::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Auth::is_alive`](crate::Auth::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Auth instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Auth::ping`](crate::Auth::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// List Clients
+    ///
+    /// See [`Auth::listClients`](crate::Auth::listClients).
+    pub fn listClients(&self, prefix: Option<&str>, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listClients(prefix, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listClients endpoint
+    pub fn listClients_url(&self, prefix: Option<&str>, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listClients_url(prefix, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listClients endpoint
+    pub fn listClients_signed_url(&self, prefix: Option<&str>, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listClients_signed_url(prefix, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listClients endpoint, following `continuationToken`
+    pub fn listClients_paginated<'a>(&'a self, prefix: Option<&'a str>, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listClients_paginated(prefix, limit))
+    }
+
+    /// Get Client
+    ///
+    /// See [`Auth::client`](crate::Auth::client).
+    pub fn client(&self, clientId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.client(clientId))
+    }
+
+    /// Generate an unsigned URL for the client endpoint
+    pub fn client_url(&self, clientId: &str) -> Result<String, Error> {
+        self.client.client_url(clientId)
+    }
+
+    /// Generate a signed URL for the client endpoint
+    pub fn client_signed_url(&self, clientId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.client_signed_url(clientId, ttl)
+    }
+
+    /// Create Client
+    ///
+    /// See [`Auth::createClient`](crate::Auth::createClient).
+    pub fn createClient(&self, clientId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createClient(clientId, payload))
+    }
+
+    /// Reset `accessToken`
+    ///
+    /// See [`Auth::resetAccessToken`](crate::Auth::resetAccessToken).
+    pub fn resetAccessToken(&self, clientId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.resetAccessToken(clientId))
+    }
+
+    /// Update Client
+    ///
+    /// See [`Auth::updateClient`](crate::Auth::updateClient).
+    pub fn updateClient(&self, clientId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.updateClient(clientId, payload))
+    }
+
+    /// Enable Client
+    ///
+    /// See [`Auth::enableClient`](crate::Auth::enableClient).
+    pub fn enableClient(&self, clientId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.enableClient(clientId))
+    }
+
+    /// Disable Client
+    ///
+    /// See [`Auth::disableClient`](crate::Auth::disableClient).
+    pub fn disableClient(&self, clientId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.disableClient(clientId))
+    }
+
+    /// Delete Client
+    ///
+    /// See [`Auth::deleteClient`](crate::Auth::deleteClient).
+    pub fn deleteClient(&self, clientId: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.deleteClient(clientId))
+    }
+
+    /// List Roles (no pagination)
+    ///
+    /// See [`Auth::listRoles`](crate::Auth::listRoles).
+    pub fn listRoles(&self) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listRoles())
+    }
+
+    /// Generate an unsigned URL for the listRoles endpoint
+    pub fn listRoles_url(&self) -> Result<String, Error> {
+        self.client.listRoles_url()
+    }
+
+    /// Generate a signed URL for the listRoles endpoint
+    pub fn listRoles_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.listRoles_signed_url(ttl)
+    }
+
+    /// List Roles
+    ///
+    /// See [`Auth::listRoles2`](crate::Auth::listRoles2).
+    pub fn listRoles2(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listRoles2(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listRoles2 endpoint
+    pub fn listRoles2_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listRoles2_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listRoles2 endpoint
+    pub fn listRoles2_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listRoles2_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listRoles2 endpoint, following `continuationToken`
+    pub fn listRoles2_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listRoles2_paginated(limit))
+    }
+
+    /// List Role IDs
+    ///
+    /// See [`Auth::listRoleIds`](crate::Auth::listRoleIds).
+    pub fn listRoleIds(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listRoleIds(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listRoleIds endpoint
+    pub fn listRoleIds_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listRoleIds_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listRoleIds endpoint
+    pub fn listRoleIds_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listRoleIds_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listRoleIds endpoint, following `continuationToken`
+    pub fn listRoleIds_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listRoleIds_paginated(limit))
+    }
+
+    /// Get Role
+    ///
+    /// See [`Auth::role`](crate::Auth::role).
+    pub fn role(&self, roleId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.role(roleId))
+    }
+
+    /// Generate an unsigned URL for the role endpoint
+    pub fn role_url(&self, roleId: &str) -> Result<String, Error> {
+        self.client.role_url(roleId)
+    }
+
+    /// Generate a signed URL for the role endpoint
+    pub fn role_signed_url(&self, roleId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.role_signed_url(roleId, ttl)
+    }
+
+    /// Create Role
+    ///
+    /// See [`Auth::createRole`](crate::Auth::createRole).
+    pub fn createRole(&self, roleId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createRole(roleId, payload))
+    }
+
+    /// Update Role
+    ///
+    /// See [`Auth::updateRole`](crate::Auth::updateRole).
+    pub fn updateRole(&self, roleId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.updateRole(roleId, payload))
+    }
+
+    /// Delete Role
+    ///
+    /// See [`Auth::deleteRole`](crate::Auth::deleteRole).
+    pub fn deleteRole(&self, roleId: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.deleteRole(roleId))
+    }
+
+    /// Expand Scopes
+    ///
+    /// See [`Auth::expandScopes`](crate::Auth::expandScopes).
+    pub fn expandScopes(&self, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.expandScopes(payload))
+    }
+
+    /// Get Current Scopes
+    ///
+    /// See [`Auth::currentScopes`](crate::Auth::currentScopes).
+    pub fn currentScopes(&self) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.currentScopes())
+    }
+
+    /// Generate an unsigned URL for the currentScopes endpoint
+    pub fn currentScopes_url(&self) -> Result<String, Error> {
+        self.client.currentScopes_url()
+    }
+
+    /// Generate a signed URL for the currentScopes endpoint
+    pub fn currentScopes_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.currentScopes_signed_url(ttl)
+    }
+
+    /// Get Temporary Read/Write Credentials S3
+    ///
+    /// See [`Auth::awsS3Credentials`](crate::Auth::awsS3Credentials).
+    pub fn awsS3Credentials(&self, level: &str, bucket: &str, prefix: &str, format: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.awsS3Credentials(level, bucket, prefix, format))
+    }
+
+    /// Generate an unsigned URL for the awsS3Credentials endpoint
+    pub fn awsS3Credentials_url(&self, level: &str, bucket: &str, prefix: &str, format: Option<&str>) -> Result<String, Error> {
+        self.client.awsS3Credentials_url(level, bucket, prefix, format)
+    }
+
+    /// Generate a signed URL for the awsS3Credentials endpoint
+    pub fn awsS3Credentials_signed_url(&self, level: &str, bucket: &str, prefix: &str, format: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.awsS3Credentials_signed_url(level, bucket, prefix, format, ttl)
+    }
+
+    /// List Accounts Managed by Auth
+    ///
+    /// See [`Auth::azureAccounts`](crate::Auth::azureAccounts).
+    pub fn azureAccounts(&self) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.azureAccounts())
+    }
+
+    /// Generate an unsigned URL for the azureAccounts endpoint
+    pub fn azureAccounts_url(&self) -> Result<String, Error> {
+        self.client.azureAccounts_url()
+    }
+
+    /// Generate a signed URL for the azureAccounts endpoint
+    pub fn azureAccounts_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.azureAccounts_signed_url(ttl)
+    }
+
+    /// List Tables in an Account Managed by Auth
+    ///
+    /// See [`Auth::azureTables`](crate::Auth::azureTables).
+    pub fn azureTables(&self, account: &str, continuationToken: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.azureTables(account, continuationToken))
+    }
+
+    /// Generate an unsigned URL for the azureTables endpoint
+    pub fn azureTables_url(&self, account: &str, continuationToken: Option<&str>) -> Result<String, Error> {
+        self.client.azureTables_url(account, continuationToken)
+    }
+
+    /// Generate a signed URL for the azureTables endpoint
+    pub fn azureTables_signed_url(&self, account: &str, continuationToken: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.azureTables_signed_url(account, continuationToken, ttl)
+    }
+
+    /// Iterate over all pages of results from the azureTables endpoint, following `continuationToken`
+    pub fn azureTables_paginated<'a>(&'a self, account: &'a str) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.azureTables_paginated(account))
+    }
+
+    /// Get Shared-Access-Signature for Azure Table
+    ///
+    /// See [`Auth::azureTableSAS`](crate::Auth::azureTableSAS).
+    pub fn azureTableSAS(&self, account: &str, table: &str, level: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.azureTableSAS(account, table, level))
+    }
+
+    /// Generate an unsigned URL for the azureTableSAS endpoint
+    pub fn azureTableSAS_url(&self, account: &str, table: &str, level: &str) -> Result<String, Error> {
+        self.client.azureTableSAS_url(account, table, level)
+    }
+
+    /// Generate a signed URL for the azureTableSAS endpoint
+    pub fn azureTableSAS_signed_url(&self, account: &str, table: &str, level: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.azureTableSAS_signed_url(account, table, level, ttl)
+    }
+
+    /// List containers in an Account Managed by Auth
+    ///
+    /// See [`Auth::azureContainers`](crate::Auth::azureContainers).
+    pub fn azureContainers(&self, account: &str, continuationToken: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.azureContainers(account, continuationToken))
+    }
+
+    /// Generate an unsigned URL for the azureContainers endpoint
+    pub fn azureContainers_url(&self, account: &str, continuationToken: Option<&str>) -> Result<String, Error> {
+        self.client.azureContainers_url(account, continuationToken)
+    }
+
+    /// Generate a signed URL for the azureContainers endpoint
+    pub fn azureContainers_signed_url(&self, account: &str, continuationToken: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.azureContainers_signed_url(account, continuationToken, ttl)
+    }
+
+    /// Iterate over all pages of results from the azureContainers endpoint, following `continuationToken`
+    pub fn azureContainers_paginated<'a>(&'a self, account: &'a str) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.azureContainers_paginated(account))
+    }
+
+    /// Get Shared-Access-Signature for Azure Container
+    ///
+    /// See [`Auth::azureContainerSAS`](crate::Auth::azureContainerSAS).
+    pub fn azureContainerSAS(&self, account: &str, container: &str, level: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.azureContainerSAS(account, container, level))
+    }
+
+    /// Generate an unsigned URL for the azureContainerSAS endpoint
+    pub fn azureContainerSAS_url(&self, account: &str, container: &str, level: &str) -> Result<String, Error> {
+        self.client.azureContainerSAS_url(account, container, level)
+    }
+
+    /// Generate a signed URL for the azureContainerSAS endpoint
+    pub fn azureContainerSAS_signed_url(&self, account: &str, container: &str, level: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.azureContainerSAS_signed_url(account, container, level, ttl)
+    }
+
+    /// Get DSN for Sentry Project
+    ///
+    /// See [`Auth::sentryDSN`](crate::Auth::sentryDSN).
+    pub fn sentryDSN(&self, project: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.sentryDSN(project))
+    }
+
+    /// Generate an unsigned URL for the sentryDSN endpoint
+    pub fn sentryDSN_url(&self, project: &str) -> Result<String, Error> {
+        self.client.sentryDSN_url(project)
+    }
+
+    /// Generate a signed URL for the sentryDSN endpoint
+    pub fn sentryDSN_signed_url(&self, project: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.sentryDSN_signed_url(project, ttl)
+    }
+
+    /// Get a client token for the Websocktunnel service
+    ///
+    /// See [`Auth::websocktunnelToken`](crate::Auth::websocktunnelToken).
+    pub fn websocktunnelToken(&self, wstAudience: &str, wstClient: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.websocktunnelToken(wstAudience, wstClient))
+    }
+
+    /// Generate an unsigned URL for the websocktunnelToken endpoint
+    pub fn websocktunnelToken_url(&self, wstAudience: &str, wstClient: &str) -> Result<String, Error> {
+        self.client.websocktunnelToken_url(wstAudience, wstClient)
+    }
+
+    /// Generate a signed URL for the websocktunnelToken endpoint
+    pub fn websocktunnelToken_signed_url(&self, wstAudience: &str, wstClient: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.websocktunnelToken_signed_url(wstAudience, wstClient, ttl)
+    }
+
+    /// Get Temporary GCP Credentials
+    ///
+    /// See [`Auth::gcpCredentials`](crate::Auth::gcpCredentials).
+    pub fn gcpCredentials(&self, projectId: &str, serviceAccount: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.gcpCredentials(projectId, serviceAccount))
+    }
+
+    /// Generate an unsigned URL for the gcpCredentials endpoint
+    pub fn gcpCredentials_url(&self, projectId: &str, serviceAccount: &str) -> Result<String, Error> {
+        self.client.gcpCredentials_url(projectId, serviceAccount)
+    }
+
+    /// Generate a signed URL for the gcpCredentials endpoint
+    pub fn gcpCredentials_signed_url(&self, projectId: &str, serviceAccount: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.gcpCredentials_signed_url(projectId, serviceAccount, ttl)
+    }
+
+    /// Authenticate Hawk Request
+    ///
+    /// See [`Auth::authenticateHawk`](crate::Auth::authenticateHawk).
+    pub fn authenticateHawk(&self, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.authenticateHawk(payload))
+    }
+
+    /// Test Authentication
+    ///
+    /// See [`Auth::testAuthenticate`](crate::Auth::testAuthenticate).
+    pub fn testAuthenticate(&self, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.testAuthenticate(payload))
+    }
+
+    /// Test Authentication (GET)
+    ///
+    /// See [`Auth::testAuthenticateGet`](crate::Auth::testAuthenticateGet).
+    pub fn testAuthenticateGet(&self) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.testAuthenticateGet())
+    }
+
+    /// Generate an unsigned URL for the testAuthenticateGet endpoint
+    pub fn testAuthenticateGet_url(&self) -> Result<String, Error> {
+        self.client.testAuthenticateGet_url()
+    }
+
+    /// Generate a signed URL for the testAuthenticateGet endpoint
+    pub fn testAuthenticateGet_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.testAuthenticateGet_signed_url(ttl)
+    }
+}
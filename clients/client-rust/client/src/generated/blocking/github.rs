@@ -0,0 +1,158 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Github`](crate::Github).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Github {
+    client: crate::Github,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Github {
+    /// Create a new blocking Github instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Github::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Github::health`](crate::Github::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Github::is_alive`](crate::Github::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Github instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Github::ping`](crate::Github::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// Consume GitHub WebHook
+    ///
+    /// See [`Github::githubWebHookConsumer`](crate::Github::githubWebHookConsumer).
+    pub fn githubWebHookConsumer(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.githubWebHookConsumer())
+    }
+
+    /// List of Builds
+    ///
+    /// See [`Github::builds`](crate::Github::builds).
+    pub fn builds(&self, continuationToken: Option<&str>, limit: Option<&str>, organization: Option<&str>, repository: Option<&str>, sha: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.builds(continuationToken, limit, organization, repository, sha))
+    }
+
+    /// Generate an unsigned URL for the builds endpoint
+    pub fn builds_url(&self, continuationToken: Option<&str>, limit: Option<&str>, organization: Option<&str>, repository: Option<&str>, sha: Option<&str>) -> Result<String, Error> {
+        self.client.builds_url(continuationToken, limit, organization, repository, sha)
+    }
+
+    /// Generate a signed URL for the builds endpoint
+    pub fn builds_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, organization: Option<&str>, repository: Option<&str>, sha: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.builds_signed_url(continuationToken, limit, organization, repository, sha, ttl)
+    }
+
+    /// Iterate over all pages of results from the builds endpoint, following `continuationToken`
+    pub fn builds_paginated<'a>(&'a self, limit: Option<&'a str>, organization: Option<&'a str>, repository: Option<&'a str>, sha: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.builds_paginated(limit, organization, repository, sha))
+    }
+
+    /// Latest Build Status Badge
+    ///
+    /// See [`Github::badge`](crate::Github::badge).
+    pub fn badge(&self, owner: &str, repo: &str, branch: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.badge(owner, repo, branch))
+    }
+
+    /// Generate an unsigned URL for the badge endpoint
+    pub fn badge_url(&self, owner: &str, repo: &str, branch: &str) -> Result<String, Error> {
+        self.client.badge_url(owner, repo, branch)
+    }
+
+    /// Generate a signed URL for the badge endpoint
+    pub fn badge_signed_url(&self, owner: &str, repo: &str, branch: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.badge_signed_url(owner, repo, branch, ttl)
+    }
+
+    /// Get Repository Info
+    ///
+    /// See [`Github::repository`](crate::Github::repository).
+    pub fn repository(&self, owner: &str, repo: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.repository(owner, repo))
+    }
+
+    /// Generate an unsigned URL for the repository endpoint
+    pub fn repository_url(&self, owner: &str, repo: &str) -> Result<String, Error> {
+        self.client.repository_url(owner, repo)
+    }
+
+    /// Generate a signed URL for the repository endpoint
+    pub fn repository_signed_url(&self, owner: &str, repo: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.repository_signed_url(owner, repo, ttl)
+    }
+
+    /// Latest Status for Branch
+    ///
+    /// See [`Github::latest`](crate::Github::latest).
+    pub fn latest(&self, owner: &str, repo: &str, branch: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.latest(owner, repo, branch))
+    }
+
+    /// Generate an unsigned URL for the latest endpoint
+    pub fn latest_url(&self, owner: &str, repo: &str, branch: &str) -> Result<String, Error> {
+        self.client.latest_url(owner, repo, branch)
+    }
+
+    /// Generate a signed URL for the latest endpoint
+    pub fn latest_signed_url(&self, owner: &str, repo: &str, branch: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.latest_signed_url(owner, repo, branch, ttl)
+    }
+
+    /// Post a status against a given changeset
+    ///
+    /// See [`Github::createStatus`](crate::Github::createStatus).
+    pub fn createStatus(&self, owner: &str, repo: &str, sha: &str, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.createStatus(owner, repo, sha, payload))
+    }
+
+    /// Post a comment on a given GitHub Issue or Pull Request
+    ///
+    /// See [`Github::createComment`](crate::Github::createComment).
+    pub fn createComment(&self, owner: &str, repo: &str, number: &str, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.createComment(owner, repo, number, payload))
+    }
+}
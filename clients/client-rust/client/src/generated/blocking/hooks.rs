@@ -0,0 +1,208 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Hooks`](crate::Hooks).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Hooks {
+    client: crate::Hooks,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Hooks {
+    /// Create a new blocking Hooks instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Hooks::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Hooks::health`](crate::Hooks::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Hooks::is_alive`](crate::Hooks::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Hooks instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Hooks::ping`](crate::Hooks::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// List hook groups
+    ///
+    /// See [`Hooks::listHookGroups`](crate::Hooks::listHookGroups).
+    pub fn listHookGroups(&self) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listHookGroups())
+    }
+
+    /// Generate an unsigned URL for the listHookGroups endpoint
+    pub fn listHookGroups_url(&self) -> Result<String, Error> {
+        self.client.listHookGroups_url()
+    }
+
+    /// Generate a signed URL for the listHookGroups endpoint
+    pub fn listHookGroups_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.listHookGroups_signed_url(ttl)
+    }
+
+    /// List hooks in a given group
+    ///
+    /// See [`Hooks::listHooks`](crate::Hooks::listHooks).
+    pub fn listHooks(&self, hookGroupId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listHooks(hookGroupId))
+    }
+
+    /// Generate an unsigned URL for the listHooks endpoint
+    pub fn listHooks_url(&self, hookGroupId: &str) -> Result<String, Error> {
+        self.client.listHooks_url(hookGroupId)
+    }
+
+    /// Generate a signed URL for the listHooks endpoint
+    pub fn listHooks_signed_url(&self, hookGroupId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.listHooks_signed_url(hookGroupId, ttl)
+    }
+
+    /// Get hook definition
+    ///
+    /// See [`Hooks::hook`](crate::Hooks::hook).
+    pub fn hook(&self, hookGroupId: &str, hookId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.hook(hookGroupId, hookId))
+    }
+
+    /// Generate an unsigned URL for the hook endpoint
+    pub fn hook_url(&self, hookGroupId: &str, hookId: &str) -> Result<String, Error> {
+        self.client.hook_url(hookGroupId, hookId)
+    }
+
+    /// Generate a signed URL for the hook endpoint
+    pub fn hook_signed_url(&self, hookGroupId: &str, hookId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.hook_signed_url(hookGroupId, hookId, ttl)
+    }
+
+    /// Get hook status
+    ///
+    /// See [`Hooks::getHookStatus`](crate::Hooks::getHookStatus).
+    pub fn getHookStatus(&self, hookGroupId: &str, hookId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getHookStatus(hookGroupId, hookId))
+    }
+
+    /// Generate an unsigned URL for the getHookStatus endpoint
+    pub fn getHookStatus_url(&self, hookGroupId: &str, hookId: &str) -> Result<String, Error> {
+        self.client.getHookStatus_url(hookGroupId, hookId)
+    }
+
+    /// Generate a signed URL for the getHookStatus endpoint
+    pub fn getHookStatus_signed_url(&self, hookGroupId: &str, hookId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getHookStatus_signed_url(hookGroupId, hookId, ttl)
+    }
+
+    /// Create a hook
+    ///
+    /// See [`Hooks::createHook`](crate::Hooks::createHook).
+    pub fn createHook(&self, hookGroupId: &str, hookId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createHook(hookGroupId, hookId, payload))
+    }
+
+    /// Update a hook
+    ///
+    /// See [`Hooks::updateHook`](crate::Hooks::updateHook).
+    pub fn updateHook(&self, hookGroupId: &str, hookId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.updateHook(hookGroupId, hookId, payload))
+    }
+
+    /// Delete a hook
+    ///
+    /// See [`Hooks::removeHook`](crate::Hooks::removeHook).
+    pub fn removeHook(&self, hookGroupId: &str, hookId: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.removeHook(hookGroupId, hookId))
+    }
+
+    /// Trigger a hook
+    ///
+    /// See [`Hooks::triggerHook`](crate::Hooks::triggerHook).
+    pub fn triggerHook(&self, hookGroupId: &str, hookId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.triggerHook(hookGroupId, hookId, payload))
+    }
+
+    /// Get a trigger token
+    ///
+    /// See [`Hooks::getTriggerToken`](crate::Hooks::getTriggerToken).
+    pub fn getTriggerToken(&self, hookGroupId: &str, hookId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getTriggerToken(hookGroupId, hookId))
+    }
+
+    /// Generate an unsigned URL for the getTriggerToken endpoint
+    pub fn getTriggerToken_url(&self, hookGroupId: &str, hookId: &str) -> Result<String, Error> {
+        self.client.getTriggerToken_url(hookGroupId, hookId)
+    }
+
+    /// Generate a signed URL for the getTriggerToken endpoint
+    pub fn getTriggerToken_signed_url(&self, hookGroupId: &str, hookId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getTriggerToken_signed_url(hookGroupId, hookId, ttl)
+    }
+
+    /// Reset a trigger token
+    ///
+    /// See [`Hooks::resetTriggerToken`](crate::Hooks::resetTriggerToken).
+    pub fn resetTriggerToken(&self, hookGroupId: &str, hookId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.resetTriggerToken(hookGroupId, hookId))
+    }
+
+    /// Trigger a hook with a token
+    ///
+    /// See [`Hooks::triggerHookWithToken`](crate::Hooks::triggerHookWithToken).
+    pub fn triggerHookWithToken(&self, hookGroupId: &str, hookId: &str, token: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.triggerHookWithToken(hookGroupId, hookId, token, payload))
+    }
+
+    /// Get information about recent hook fires
+    ///
+    /// See [`Hooks::listLastFires`](crate::Hooks::listLastFires).
+    pub fn listLastFires(&self, hookGroupId: &str, hookId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listLastFires(hookGroupId, hookId))
+    }
+
+    /// Generate an unsigned URL for the listLastFires endpoint
+    pub fn listLastFires_url(&self, hookGroupId: &str, hookId: &str) -> Result<String, Error> {
+        self.client.listLastFires_url(hookGroupId, hookId)
+    }
+
+    /// Generate a signed URL for the listLastFires endpoint
+    pub fn listLastFires_signed_url(&self, hookGroupId: &str, hookId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.listLastFires_signed_url(hookGroupId, hookId, ttl)
+    }
+}
@@ -0,0 +1,156 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Index`](crate::Index).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Index {
+    client: crate::Index,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Index {
+    /// Create a new blocking Index instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Index::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Index::health`](crate::Index::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Index::is_alive`](crate::Index::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Index instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Index::ping`](crate::Index::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// Find Indexed Task
+    ///
+    /// See [`Index::findTask`](crate::Index::findTask).
+    pub fn findTask(&self, indexPath: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.findTask(indexPath))
+    }
+
+    /// Generate an unsigned URL for the findTask endpoint
+    pub fn findTask_url(&self, indexPath: &str) -> Result<String, Error> {
+        self.client.findTask_url(indexPath)
+    }
+
+    /// Generate a signed URL for the findTask endpoint
+    pub fn findTask_signed_url(&self, indexPath: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.findTask_signed_url(indexPath, ttl)
+    }
+
+    /// List Namespaces
+    ///
+    /// See [`Index::listNamespaces`](crate::Index::listNamespaces).
+    pub fn listNamespaces(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listNamespaces(namespace, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listNamespaces endpoint
+    pub fn listNamespaces_url(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listNamespaces_url(namespace, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listNamespaces endpoint
+    pub fn listNamespaces_signed_url(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listNamespaces_signed_url(namespace, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listNamespaces endpoint, following `continuationToken`
+    pub fn listNamespaces_paginated<'a>(&'a self, namespace: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listNamespaces_paginated(namespace, limit))
+    }
+
+    /// List Tasks
+    ///
+    /// See [`Index::listTasks`](crate::Index::listTasks).
+    pub fn listTasks(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listTasks(namespace, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listTasks endpoint
+    pub fn listTasks_url(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listTasks_url(namespace, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listTasks endpoint
+    pub fn listTasks_signed_url(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listTasks_signed_url(namespace, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listTasks endpoint, following `continuationToken`
+    pub fn listTasks_paginated<'a>(&'a self, namespace: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listTasks_paginated(namespace, limit))
+    }
+
+    /// Insert Task into Index
+    ///
+    /// See [`Index::insertTask`](crate::Index::insertTask).
+    pub fn insertTask(&self, namespace: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.insertTask(namespace, payload))
+    }
+
+    /// Remove Task from Index
+    ///
+    /// See [`Index::deleteTask`](crate::Index::deleteTask).
+    pub fn deleteTask(&self, namespace: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.deleteTask(namespace))
+    }
+
+    /// Get Artifact From Indexed Task
+    ///
+    /// See [`Index::findArtifactFromTask`](crate::Index::findArtifactFromTask).
+    pub fn findArtifactFromTask(&self, indexPath: &str, name: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.findArtifactFromTask(indexPath, name))
+    }
+
+    /// Generate an unsigned URL for the findArtifactFromTask endpoint
+    pub fn findArtifactFromTask_url(&self, indexPath: &str, name: &str) -> Result<String, Error> {
+        self.client.findArtifactFromTask_url(indexPath, name)
+    }
+
+    /// Generate a signed URL for the findArtifactFromTask endpoint
+    pub fn findArtifactFromTask_signed_url(&self, indexPath: &str, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.findArtifactFromTask_signed_url(indexPath, name, ttl)
+    }
+}
@@ -0,0 +1,21 @@
+mod auth;
+mod github;
+mod hooks;
+mod index;
+mod notify;
+mod object;
+mod purgecache;
+mod queue;
+mod secrets;
+mod workermanager;
+
+pub use auth::Auth;
+pub use github::Github;
+pub use hooks::Hooks;
+pub use index::Index;
+pub use notify::Notify;
+pub use object::Object;
+pub use purgecache::PurgeCache;
+pub use queue::Queue;
+pub use secrets::Secrets;
+pub use workermanager::WorkerManager;
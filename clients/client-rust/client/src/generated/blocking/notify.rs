@@ -0,0 +1,128 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Notify`](crate::Notify).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Notify {
+    client: crate::Notify,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Notify {
+    /// Create a new blocking Notify instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Notify::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Notify::health`](crate::Notify::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Notify::is_alive`](crate::Notify::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Notify instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Notify::ping`](crate::Notify::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// Send an Email
+    ///
+    /// See [`Notify::email`](crate::Notify::email).
+    pub fn email(&self, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.email(payload))
+    }
+
+    /// Publish a Pulse Message
+    ///
+    /// See [`Notify::pulse`](crate::Notify::pulse).
+    pub fn pulse(&self, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.pulse(payload))
+    }
+
+    /// Post Matrix Message
+    ///
+    /// See [`Notify::matrix`](crate::Notify::matrix).
+    pub fn matrix(&self, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.matrix(payload))
+    }
+
+    /// Post Slack Message
+    ///
+    /// See [`Notify::slack`](crate::Notify::slack).
+    pub fn slack(&self, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.slack(payload))
+    }
+
+    /// Denylist Given Address
+    ///
+    /// See [`Notify::addDenylistAddress`](crate::Notify::addDenylistAddress).
+    pub fn addDenylistAddress(&self, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.addDenylistAddress(payload))
+    }
+
+    /// Delete Denylisted Address
+    ///
+    /// See [`Notify::deleteDenylistAddress`](crate::Notify::deleteDenylistAddress).
+    pub fn deleteDenylistAddress(&self, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.deleteDenylistAddress(payload))
+    }
+
+    /// List Denylisted Notifications
+    ///
+    /// See [`Notify::listDenylist`](crate::Notify::listDenylist).
+    pub fn listDenylist(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listDenylist(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listDenylist endpoint
+    pub fn listDenylist_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listDenylist_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listDenylist endpoint
+    pub fn listDenylist_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listDenylist_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listDenylist endpoint, following `continuationToken`
+    pub fn listDenylist_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listDenylist_paginated(limit))
+    }
+}
@@ -0,0 +1,102 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Object`](crate::Object).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Object {
+    client: crate::Object,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Object {
+    /// Create a new blocking Object instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Object::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Object::health`](crate::Object::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Object::is_alive`](crate::Object::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Object instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Object::ping`](crate::Object::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// Begin upload of a new object
+    ///
+    /// See [`Object::createUpload`](crate::Object::createUpload).
+    pub fn createUpload(&self, name: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createUpload(name, payload))
+    }
+
+    /// Mark an upload as complete.
+    ///
+    /// See [`Object::finishUpload`](crate::Object::finishUpload).
+    pub fn finishUpload(&self, name: &str, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.finishUpload(name, payload))
+    }
+
+    /// Download object data
+    ///
+    /// See [`Object::startDownload`](crate::Object::startDownload).
+    pub fn startDownload(&self, name: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.startDownload(name, payload))
+    }
+
+    /// Get an object's data
+    ///
+    /// See [`Object::download`](crate::Object::download).
+    pub fn download(&self, name: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.download(name))
+    }
+
+    /// Generate an unsigned URL for the download endpoint
+    pub fn download_url(&self, name: &str) -> Result<String, Error> {
+        self.client.download_url(name)
+    }
+
+    /// Generate a signed URL for the download endpoint
+    pub fn download_signed_url(&self, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.download_signed_url(name, ttl)
+    }
+}
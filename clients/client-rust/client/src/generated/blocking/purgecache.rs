@@ -0,0 +1,110 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`PurgeCache`](crate::PurgeCache).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct PurgeCache {
+    client: crate::PurgeCache,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl PurgeCache {
+    /// Create a new blocking PurgeCache instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::PurgeCache::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`PurgeCache::health`](crate::PurgeCache::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`PurgeCache::is_alive`](crate::PurgeCache::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this PurgeCache instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`PurgeCache::ping`](crate::PurgeCache::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// Purge Worker Cache
+    ///
+    /// See [`PurgeCache::purgeCache`](crate::PurgeCache::purgeCache).
+    pub fn purgeCache(&self, workerPoolId: &str, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.purgeCache(workerPoolId, payload))
+    }
+
+    /// All Open Purge Requests
+    ///
+    /// See [`PurgeCache::allPurgeRequests`](crate::PurgeCache::allPurgeRequests).
+    pub fn allPurgeRequests(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.allPurgeRequests(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the allPurgeRequests endpoint
+    pub fn allPurgeRequests_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.allPurgeRequests_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the allPurgeRequests endpoint
+    pub fn allPurgeRequests_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.allPurgeRequests_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the allPurgeRequests endpoint, following `continuationToken`
+    pub fn allPurgeRequests_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.allPurgeRequests_paginated(limit))
+    }
+
+    /// Open Purge Requests for a worker pool
+    ///
+    /// See [`PurgeCache::purgeRequests`](crate::PurgeCache::purgeRequests).
+    pub fn purgeRequests(&self, workerPoolId: &str, since: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.purgeRequests(workerPoolId, since))
+    }
+
+    /// Generate an unsigned URL for the purgeRequests endpoint
+    pub fn purgeRequests_url(&self, workerPoolId: &str, since: Option<&str>) -> Result<String, Error> {
+        self.client.purgeRequests_url(workerPoolId, since)
+    }
+
+    /// Generate a signed URL for the purgeRequests endpoint
+    pub fn purgeRequests_signed_url(&self, workerPoolId: &str, since: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.purgeRequests_signed_url(workerPoolId, since, ttl)
+    }
+}
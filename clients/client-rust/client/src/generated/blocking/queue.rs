@@ -0,0 +1,566 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Queue`](crate::Queue).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Queue {
+    client: crate::Queue,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Queue {
+    /// Create a new blocking Queue instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Queue::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Queue::health`](crate::Queue::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Queue::is_alive`](crate::Queue::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Queue instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Queue::ping`](crate::Queue::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// Get Task Definition
+    ///
+    /// See [`Queue::task`](crate::Queue::task).
+    pub fn task(&self, taskId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.task(taskId))
+    }
+
+    /// Generate an unsigned URL for the task endpoint
+    pub fn task_url(&self, taskId: &str) -> Result<String, Error> {
+        self.client.task_url(taskId)
+    }
+
+    /// Generate a signed URL for the task endpoint
+    pub fn task_signed_url(&self, taskId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.task_signed_url(taskId, ttl)
+    }
+
+    /// Get task status
+    ///
+    /// See [`Queue::status`](crate::Queue::status).
+    pub fn status(&self, taskId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.status(taskId))
+    }
+
+    /// Generate an unsigned URL for the status endpoint
+    pub fn status_url(&self, taskId: &str) -> Result<String, Error> {
+        self.client.status_url(taskId)
+    }
+
+    /// Generate a signed URL for the status endpoint
+    pub fn status_signed_url(&self, taskId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.status_signed_url(taskId, ttl)
+    }
+
+    /// List Task Group
+    ///
+    /// See [`Queue::listTaskGroup`](crate::Queue::listTaskGroup).
+    pub fn listTaskGroup(&self, taskGroupId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listTaskGroup(taskGroupId, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listTaskGroup endpoint
+    pub fn listTaskGroup_url(&self, taskGroupId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listTaskGroup_url(taskGroupId, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listTaskGroup endpoint
+    pub fn listTaskGroup_signed_url(&self, taskGroupId: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listTaskGroup_signed_url(taskGroupId, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listTaskGroup endpoint, following `continuationToken`
+    pub fn listTaskGroup_paginated<'a>(&'a self, taskGroupId: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listTaskGroup_paginated(taskGroupId, limit))
+    }
+
+    /// List Dependent Tasks
+    ///
+    /// See [`Queue::listDependentTasks`](crate::Queue::listDependentTasks).
+    pub fn listDependentTasks(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listDependentTasks(taskId, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listDependentTasks endpoint
+    pub fn listDependentTasks_url(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listDependentTasks_url(taskId, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listDependentTasks endpoint
+    pub fn listDependentTasks_signed_url(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listDependentTasks_signed_url(taskId, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listDependentTasks endpoint, following `continuationToken`
+    pub fn listDependentTasks_paginated<'a>(&'a self, taskId: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listDependentTasks_paginated(taskId, limit))
+    }
+
+    /// Create New Task
+    ///
+    /// See [`Queue::createTask`](crate::Queue::createTask).
+    pub fn createTask(&self, taskId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createTask(taskId, payload))
+    }
+
+    /// Schedule Defined Task
+    ///
+    /// See [`Queue::scheduleTask`](crate::Queue::scheduleTask).
+    pub fn scheduleTask(&self, taskId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.scheduleTask(taskId))
+    }
+
+    /// Rerun a Resolved Task
+    ///
+    /// See [`Queue::rerunTask`](crate::Queue::rerunTask).
+    pub fn rerunTask(&self, taskId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.rerunTask(taskId))
+    }
+
+    /// Cancel Task
+    ///
+    /// See [`Queue::cancelTask`](crate::Queue::cancelTask).
+    pub fn cancelTask(&self, taskId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.cancelTask(taskId))
+    }
+
+    /// Claim Work
+    ///
+    /// See [`Queue::claimWork`](crate::Queue::claimWork).
+    pub fn claimWork(&self, taskQueueId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.claimWork(taskQueueId, payload))
+    }
+
+    /// Claim Task
+    ///
+    /// See [`Queue::claimTask`](crate::Queue::claimTask).
+    pub fn claimTask(&self, taskId: &str, runId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.claimTask(taskId, runId, payload))
+    }
+
+    /// Reclaim task
+    ///
+    /// See [`Queue::reclaimTask`](crate::Queue::reclaimTask).
+    pub fn reclaimTask(&self, taskId: &str, runId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.reclaimTask(taskId, runId))
+    }
+
+    /// Report Run Completed
+    ///
+    /// See [`Queue::reportCompleted`](crate::Queue::reportCompleted).
+    pub fn reportCompleted(&self, taskId: &str, runId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.reportCompleted(taskId, runId))
+    }
+
+    /// Report Run Failed
+    ///
+    /// See [`Queue::reportFailed`](crate::Queue::reportFailed).
+    pub fn reportFailed(&self, taskId: &str, runId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.reportFailed(taskId, runId))
+    }
+
+    /// Report Task Exception
+    ///
+    /// See [`Queue::reportException`](crate::Queue::reportException).
+    pub fn reportException(&self, taskId: &str, runId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.reportException(taskId, runId, payload))
+    }
+
+    /// Create Artifact
+    ///
+    /// See [`Queue::createArtifact`](crate::Queue::createArtifact).
+    pub fn createArtifact(&self, taskId: &str, runId: &str, name: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createArtifact(taskId, runId, name, payload))
+    }
+
+    /// Get Artifact Data from Run
+    ///
+    /// See [`Queue::getArtifact`](crate::Queue::getArtifact).
+    pub fn getArtifact(&self, taskId: &str, runId: &str, name: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getArtifact(taskId, runId, name))
+    }
+
+    /// Generate an unsigned URL for the getArtifact endpoint
+    pub fn getArtifact_url(&self, taskId: &str, runId: &str, name: &str) -> Result<String, Error> {
+        self.client.getArtifact_url(taskId, runId, name)
+    }
+
+    /// Generate a signed URL for the getArtifact endpoint
+    pub fn getArtifact_signed_url(&self, taskId: &str, runId: &str, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getArtifact_signed_url(taskId, runId, name, ttl)
+    }
+
+    /// Get Artifact Data from Latest Run
+    ///
+    /// See [`Queue::getLatestArtifact`](crate::Queue::getLatestArtifact).
+    pub fn getLatestArtifact(&self, taskId: &str, name: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getLatestArtifact(taskId, name))
+    }
+
+    /// Generate an unsigned URL for the getLatestArtifact endpoint
+    pub fn getLatestArtifact_url(&self, taskId: &str, name: &str) -> Result<String, Error> {
+        self.client.getLatestArtifact_url(taskId, name)
+    }
+
+    /// Generate a signed URL for the getLatestArtifact endpoint
+    pub fn getLatestArtifact_signed_url(&self, taskId: &str, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getLatestArtifact_signed_url(taskId, name, ttl)
+    }
+
+    /// Get Artifacts from Run
+    ///
+    /// See [`Queue::listArtifacts`](crate::Queue::listArtifacts).
+    pub fn listArtifacts(&self, taskId: &str, runId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listArtifacts(taskId, runId, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listArtifacts endpoint
+    pub fn listArtifacts_url(&self, taskId: &str, runId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listArtifacts_url(taskId, runId, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listArtifacts endpoint
+    pub fn listArtifacts_signed_url(&self, taskId: &str, runId: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listArtifacts_signed_url(taskId, runId, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listArtifacts endpoint, following `continuationToken`
+    pub fn listArtifacts_paginated<'a>(&'a self, taskId: &'a str, runId: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listArtifacts_paginated(taskId, runId, limit))
+    }
+
+    /// Get Artifacts from Latest Run
+    ///
+    /// See [`Queue::listLatestArtifacts`](crate::Queue::listLatestArtifacts).
+    pub fn listLatestArtifacts(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listLatestArtifacts(taskId, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listLatestArtifacts endpoint
+    pub fn listLatestArtifacts_url(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listLatestArtifacts_url(taskId, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listLatestArtifacts endpoint
+    pub fn listLatestArtifacts_signed_url(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listLatestArtifacts_signed_url(taskId, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listLatestArtifacts endpoint, following `continuationToken`
+    pub fn listLatestArtifacts_paginated<'a>(&'a self, taskId: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listLatestArtifacts_paginated(taskId, limit))
+    }
+
+    /// Get Artifact Information From Run
+    ///
+    /// See [`Queue::artifactInfo`](crate::Queue::artifactInfo).
+    pub fn artifactInfo(&self, taskId: &str, runId: &str, name: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.artifactInfo(taskId, runId, name))
+    }
+
+    /// Generate an unsigned URL for the artifactInfo endpoint
+    pub fn artifactInfo_url(&self, taskId: &str, runId: &str, name: &str) -> Result<String, Error> {
+        self.client.artifactInfo_url(taskId, runId, name)
+    }
+
+    /// Generate a signed URL for the artifactInfo endpoint
+    pub fn artifactInfo_signed_url(&self, taskId: &str, runId: &str, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.artifactInfo_signed_url(taskId, runId, name, ttl)
+    }
+
+    /// Get Artifact Information From Latest Run
+    ///
+    /// See [`Queue::latestArtifactInfo`](crate::Queue::latestArtifactInfo).
+    pub fn latestArtifactInfo(&self, taskId: &str, name: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.latestArtifactInfo(taskId, name))
+    }
+
+    /// Generate an unsigned URL for the latestArtifactInfo endpoint
+    pub fn latestArtifactInfo_url(&self, taskId: &str, name: &str) -> Result<String, Error> {
+        self.client.latestArtifactInfo_url(taskId, name)
+    }
+
+    /// Generate a signed URL for the latestArtifactInfo endpoint
+    pub fn latestArtifactInfo_signed_url(&self, taskId: &str, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.latestArtifactInfo_signed_url(taskId, name, ttl)
+    }
+
+    /// Get Artifact Content From Run
+    ///
+    /// See [`Queue::artifact`](crate::Queue::artifact).
+    pub fn artifact(&self, taskId: &str, runId: &str, name: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.artifact(taskId, runId, name))
+    }
+
+    /// Generate an unsigned URL for the artifact endpoint
+    pub fn artifact_url(&self, taskId: &str, runId: &str, name: &str) -> Result<String, Error> {
+        self.client.artifact_url(taskId, runId, name)
+    }
+
+    /// Generate a signed URL for the artifact endpoint
+    pub fn artifact_signed_url(&self, taskId: &str, runId: &str, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.artifact_signed_url(taskId, runId, name, ttl)
+    }
+
+    /// Get Artifact Content From Latest Run
+    ///
+    /// See [`Queue::latestArtifact`](crate::Queue::latestArtifact).
+    pub fn latestArtifact(&self, taskId: &str, name: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.latestArtifact(taskId, name))
+    }
+
+    /// Generate an unsigned URL for the latestArtifact endpoint
+    pub fn latestArtifact_url(&self, taskId: &str, name: &str) -> Result<String, Error> {
+        self.client.latestArtifact_url(taskId, name)
+    }
+
+    /// Generate a signed URL for the latestArtifact endpoint
+    pub fn latestArtifact_signed_url(&self, taskId: &str, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.latestArtifact_signed_url(taskId, name, ttl)
+    }
+
+    /// Get a list of all active provisioners
+    ///
+    /// See [`Queue::listProvisioners`](crate::Queue::listProvisioners).
+    pub fn listProvisioners(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listProvisioners(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listProvisioners endpoint
+    pub fn listProvisioners_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listProvisioners_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listProvisioners endpoint
+    pub fn listProvisioners_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listProvisioners_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listProvisioners endpoint, following `continuationToken`
+    pub fn listProvisioners_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listProvisioners_paginated(limit))
+    }
+
+    /// Get an active provisioner
+    ///
+    /// See [`Queue::getProvisioner`](crate::Queue::getProvisioner).
+    pub fn getProvisioner(&self, provisionerId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getProvisioner(provisionerId))
+    }
+
+    /// Generate an unsigned URL for the getProvisioner endpoint
+    pub fn getProvisioner_url(&self, provisionerId: &str) -> Result<String, Error> {
+        self.client.getProvisioner_url(provisionerId)
+    }
+
+    /// Generate a signed URL for the getProvisioner endpoint
+    pub fn getProvisioner_signed_url(&self, provisionerId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getProvisioner_signed_url(provisionerId, ttl)
+    }
+
+    /// Update a provisioner
+    ///
+    /// See [`Queue::declareProvisioner`](crate::Queue::declareProvisioner).
+    pub fn declareProvisioner(&self, provisionerId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.declareProvisioner(provisionerId, payload))
+    }
+
+    /// Get Number of Pending Tasks
+    ///
+    /// See [`Queue::pendingTasks`](crate::Queue::pendingTasks).
+    pub fn pendingTasks(&self, taskQueueId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.pendingTasks(taskQueueId))
+    }
+
+    /// Generate an unsigned URL for the pendingTasks endpoint
+    pub fn pendingTasks_url(&self, taskQueueId: &str) -> Result<String, Error> {
+        self.client.pendingTasks_url(taskQueueId)
+    }
+
+    /// Generate a signed URL for the pendingTasks endpoint
+    pub fn pendingTasks_signed_url(&self, taskQueueId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.pendingTasks_signed_url(taskQueueId, ttl)
+    }
+
+    /// Get a list of all active worker-types
+    ///
+    /// See [`Queue::listWorkerTypes`](crate::Queue::listWorkerTypes).
+    pub fn listWorkerTypes(&self, provisionerId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listWorkerTypes(provisionerId, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listWorkerTypes endpoint
+    pub fn listWorkerTypes_url(&self, provisionerId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listWorkerTypes_url(provisionerId, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listWorkerTypes endpoint
+    pub fn listWorkerTypes_signed_url(&self, provisionerId: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listWorkerTypes_signed_url(provisionerId, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listWorkerTypes endpoint, following `continuationToken`
+    pub fn listWorkerTypes_paginated<'a>(&'a self, provisionerId: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listWorkerTypes_paginated(provisionerId, limit))
+    }
+
+    /// Get a worker-type
+    ///
+    /// See [`Queue::getWorkerType`](crate::Queue::getWorkerType).
+    pub fn getWorkerType(&self, provisionerId: &str, workerType: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getWorkerType(provisionerId, workerType))
+    }
+
+    /// Generate an unsigned URL for the getWorkerType endpoint
+    pub fn getWorkerType_url(&self, provisionerId: &str, workerType: &str) -> Result<String, Error> {
+        self.client.getWorkerType_url(provisionerId, workerType)
+    }
+
+    /// Generate a signed URL for the getWorkerType endpoint
+    pub fn getWorkerType_signed_url(&self, provisionerId: &str, workerType: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getWorkerType_signed_url(provisionerId, workerType, ttl)
+    }
+
+    /// Update a worker-type
+    ///
+    /// See [`Queue::declareWorkerType`](crate::Queue::declareWorkerType).
+    pub fn declareWorkerType(&self, provisionerId: &str, workerType: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.declareWorkerType(provisionerId, workerType, payload))
+    }
+
+    /// Get a list of all active task queues
+    ///
+    /// See [`Queue::listTaskQueues`](crate::Queue::listTaskQueues).
+    pub fn listTaskQueues(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listTaskQueues(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listTaskQueues endpoint
+    pub fn listTaskQueues_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listTaskQueues_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listTaskQueues endpoint
+    pub fn listTaskQueues_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listTaskQueues_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listTaskQueues endpoint, following `continuationToken`
+    pub fn listTaskQueues_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listTaskQueues_paginated(limit))
+    }
+
+    /// Get a task queue
+    ///
+    /// See [`Queue::getTaskQueue`](crate::Queue::getTaskQueue).
+    pub fn getTaskQueue(&self, taskQueueId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getTaskQueue(taskQueueId))
+    }
+
+    /// Generate an unsigned URL for the getTaskQueue endpoint
+    pub fn getTaskQueue_url(&self, taskQueueId: &str) -> Result<String, Error> {
+        self.client.getTaskQueue_url(taskQueueId)
+    }
+
+    /// Generate a signed URL for the getTaskQueue endpoint
+    pub fn getTaskQueue_signed_url(&self, taskQueueId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getTaskQueue_signed_url(taskQueueId, ttl)
+    }
+
+    /// Get a list of all active workers of a workerType
+    ///
+    /// See [`Queue::listWorkers`](crate::Queue::listWorkers).
+    pub fn listWorkers(&self, provisionerId: &str, workerType: &str, continuationToken: Option<&str>, limit: Option<&str>, quarantined: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listWorkers(provisionerId, workerType, continuationToken, limit, quarantined))
+    }
+
+    /// Generate an unsigned URL for the listWorkers endpoint
+    pub fn listWorkers_url(&self, provisionerId: &str, workerType: &str, continuationToken: Option<&str>, limit: Option<&str>, quarantined: Option<&str>) -> Result<String, Error> {
+        self.client.listWorkers_url(provisionerId, workerType, continuationToken, limit, quarantined)
+    }
+
+    /// Generate a signed URL for the listWorkers endpoint
+    pub fn listWorkers_signed_url(&self, provisionerId: &str, workerType: &str, continuationToken: Option<&str>, limit: Option<&str>, quarantined: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listWorkers_signed_url(provisionerId, workerType, continuationToken, limit, quarantined, ttl)
+    }
+
+    /// Iterate over all pages of results from the listWorkers endpoint, following `continuationToken`
+    pub fn listWorkers_paginated<'a>(&'a self, provisionerId: &'a str, workerType: &'a str, limit: Option<&'a str>, quarantined: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listWorkers_paginated(provisionerId, workerType, limit, quarantined))
+    }
+
+    /// Get a worker-type
+    ///
+    /// See [`Queue::getWorker`](crate::Queue::getWorker).
+    pub fn getWorker(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.getWorker(provisionerId, workerType, workerGroup, workerId))
+    }
+
+    /// Generate an unsigned URL for the getWorker endpoint
+    pub fn getWorker_url(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str) -> Result<String, Error> {
+        self.client.getWorker_url(provisionerId, workerType, workerGroup, workerId)
+    }
+
+    /// Generate a signed URL for the getWorker endpoint
+    pub fn getWorker_signed_url(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.getWorker_signed_url(provisionerId, workerType, workerGroup, workerId, ttl)
+    }
+
+    /// Quarantine a worker
+    ///
+    /// See [`Queue::quarantineWorker`](crate::Queue::quarantineWorker).
+    pub fn quarantineWorker(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.quarantineWorker(provisionerId, workerType, workerGroup, workerId, payload))
+    }
+
+    /// Declare a worker
+    ///
+    /// See [`Queue::declareWorker`](crate::Queue::declareWorker).
+    pub fn declareWorker(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.declareWorker(provisionerId, workerType, workerGroup, workerId, payload))
+    }
+}
@@ -0,0 +1,117 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`Secrets`](crate::Secrets).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct Secrets {
+    client: crate::Secrets,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl Secrets {
+    /// Create a new blocking Secrets instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Secrets::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`Secrets::health`](crate::Secrets::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`Secrets::is_alive`](crate::Secrets::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this Secrets instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Secrets::ping`](crate::Secrets::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// Set Secret
+    ///
+    /// See [`Secrets::set`](crate::Secrets::set).
+    pub fn set(&self, name: &str, payload: &Value) -> Result<(), Error> {
+        self.runtime.block_on(self.client.set(name, payload))
+    }
+
+    /// Delete Secret
+    ///
+    /// See [`Secrets::remove`](crate::Secrets::remove).
+    pub fn remove(&self, name: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.remove(name))
+    }
+
+    /// Read Secret
+    ///
+    /// See [`Secrets::get`](crate::Secrets::get).
+    pub fn get(&self, name: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.get(name))
+    }
+
+    /// Generate an unsigned URL for the get endpoint
+    pub fn get_url(&self, name: &str) -> Result<String, Error> {
+        self.client.get_url(name)
+    }
+
+    /// Generate a signed URL for the get endpoint
+    pub fn get_signed_url(&self, name: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.get_signed_url(name, ttl)
+    }
+
+    /// List Secrets
+    ///
+    /// See [`Secrets::list`](crate::Secrets::list).
+    pub fn list(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.list(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the list endpoint
+    pub fn list_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.list_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the list endpoint
+    pub fn list_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.list_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the list endpoint, following `continuationToken`
+    pub fn list_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.list_paginated(limit))
+    }
+}
@@ -0,0 +1,271 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`WorkerManager`](crate::WorkerManager).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct WorkerManager {
+    client: crate::WorkerManager,
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl WorkerManager {
+    /// Create a new blocking WorkerManager instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::WorkerManager::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [`WorkerManager::health`](crate::WorkerManager::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [`WorkerManager::is_alive`](crate::WorkerManager::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this WorkerManager instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// Ping Server
+    ///
+    /// See [`WorkerManager::ping`](crate::WorkerManager::ping).
+    pub fn ping(&self) -> Result<(), Error> {
+        self.runtime.block_on(self.client.ping())
+    }
+
+    /// Generate an unsigned URL for the ping endpoint
+    pub fn ping_url(&self) -> Result<String, Error> {
+        self.client.ping_url()
+    }
+
+    /// Generate a signed URL for the ping endpoint
+    pub fn ping_signed_url(&self, ttl: Duration) -> Result<String, Error> {
+        self.client.ping_signed_url(ttl)
+    }
+
+    /// List Providers
+    ///
+    /// See [`WorkerManager::listProviders`](crate::WorkerManager::listProviders).
+    pub fn listProviders(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listProviders(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listProviders endpoint
+    pub fn listProviders_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listProviders_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listProviders endpoint
+    pub fn listProviders_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listProviders_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listProviders endpoint, following `continuationToken`
+    pub fn listProviders_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listProviders_paginated(limit))
+    }
+
+    /// Create Worker Pool
+    ///
+    /// See [`WorkerManager::createWorkerPool`](crate::WorkerManager::createWorkerPool).
+    pub fn createWorkerPool(&self, workerPoolId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createWorkerPool(workerPoolId, payload))
+    }
+
+    /// Update Worker Pool
+    ///
+    /// See [`WorkerManager::updateWorkerPool`](crate::WorkerManager::updateWorkerPool).
+    pub fn updateWorkerPool(&self, workerPoolId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.updateWorkerPool(workerPoolId, payload))
+    }
+
+    /// Delete Worker Pool
+    ///
+    /// See [`WorkerManager::deleteWorkerPool`](crate::WorkerManager::deleteWorkerPool).
+    pub fn deleteWorkerPool(&self, workerPoolId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.deleteWorkerPool(workerPoolId))
+    }
+
+    /// Get Worker Pool
+    ///
+    /// See [`WorkerManager::workerPool`](crate::WorkerManager::workerPool).
+    pub fn workerPool(&self, workerPoolId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.workerPool(workerPoolId))
+    }
+
+    /// Generate an unsigned URL for the workerPool endpoint
+    pub fn workerPool_url(&self, workerPoolId: &str) -> Result<String, Error> {
+        self.client.workerPool_url(workerPoolId)
+    }
+
+    /// Generate a signed URL for the workerPool endpoint
+    pub fn workerPool_signed_url(&self, workerPoolId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.workerPool_signed_url(workerPoolId, ttl)
+    }
+
+    /// List All Worker Pools
+    ///
+    /// See [`WorkerManager::listWorkerPools`](crate::WorkerManager::listWorkerPools).
+    pub fn listWorkerPools(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listWorkerPools(continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listWorkerPools endpoint
+    pub fn listWorkerPools_url(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listWorkerPools_url(continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listWorkerPools endpoint
+    pub fn listWorkerPools_signed_url(&self, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listWorkerPools_signed_url(continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listWorkerPools endpoint, following `continuationToken`
+    pub fn listWorkerPools_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listWorkerPools_paginated(limit))
+    }
+
+    /// Report an error from a worker
+    ///
+    /// See [`WorkerManager::reportWorkerError`](crate::WorkerManager::reportWorkerError).
+    pub fn reportWorkerError(&self, workerPoolId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.reportWorkerError(workerPoolId, payload))
+    }
+
+    /// List Worker Pool Errors
+    ///
+    /// See [`WorkerManager::listWorkerPoolErrors`](crate::WorkerManager::listWorkerPoolErrors).
+    pub fn listWorkerPoolErrors(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listWorkerPoolErrors(workerPoolId, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listWorkerPoolErrors endpoint
+    pub fn listWorkerPoolErrors_url(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listWorkerPoolErrors_url(workerPoolId, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listWorkerPoolErrors endpoint
+    pub fn listWorkerPoolErrors_signed_url(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listWorkerPoolErrors_signed_url(workerPoolId, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listWorkerPoolErrors endpoint, following `continuationToken`
+    pub fn listWorkerPoolErrors_paginated<'a>(&'a self, workerPoolId: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listWorkerPoolErrors_paginated(workerPoolId, limit))
+    }
+
+    /// Workers in a specific Worker Group in a Worker Pool
+    ///
+    /// See [`WorkerManager::listWorkersForWorkerGroup`](crate::WorkerManager::listWorkersForWorkerGroup).
+    pub fn listWorkersForWorkerGroup(&self, workerPoolId: &str, workerGroup: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listWorkersForWorkerGroup(workerPoolId, workerGroup, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listWorkersForWorkerGroup endpoint
+    pub fn listWorkersForWorkerGroup_url(&self, workerPoolId: &str, workerGroup: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listWorkersForWorkerGroup_url(workerPoolId, workerGroup, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listWorkersForWorkerGroup endpoint
+    pub fn listWorkersForWorkerGroup_signed_url(&self, workerPoolId: &str, workerGroup: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listWorkersForWorkerGroup_signed_url(workerPoolId, workerGroup, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listWorkersForWorkerGroup endpoint, following `continuationToken`
+    pub fn listWorkersForWorkerGroup_paginated<'a>(&'a self, workerPoolId: &'a str, workerGroup: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listWorkersForWorkerGroup_paginated(workerPoolId, workerGroup, limit))
+    }
+
+    /// Get a Worker
+    ///
+    /// See [`WorkerManager::worker`](crate::WorkerManager::worker).
+    pub fn worker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.worker(workerPoolId, workerGroup, workerId))
+    }
+
+    /// Generate an unsigned URL for the worker endpoint
+    pub fn worker_url(&self, workerPoolId: &str, workerGroup: &str, workerId: &str) -> Result<String, Error> {
+        self.client.worker_url(workerPoolId, workerGroup, workerId)
+    }
+
+    /// Generate a signed URL for the worker endpoint
+    pub fn worker_signed_url(&self, workerPoolId: &str, workerGroup: &str, workerId: &str, ttl: Duration) -> Result<String, Error> {
+        self.client.worker_signed_url(workerPoolId, workerGroup, workerId, ttl)
+    }
+
+    /// Create a Worker
+    ///
+    /// See [`WorkerManager::createWorker`](crate::WorkerManager::createWorker).
+    pub fn createWorker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.createWorker(workerPoolId, workerGroup, workerId, payload))
+    }
+
+    /// Update an existing Worker
+    ///
+    /// See [`WorkerManager::updateWorker`](crate::WorkerManager::updateWorker).
+    pub fn updateWorker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.updateWorker(workerPoolId, workerGroup, workerId, payload))
+    }
+
+    /// Remove a Worker
+    ///
+    /// See [`WorkerManager::removeWorker`](crate::WorkerManager::removeWorker).
+    pub fn removeWorker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str) -> Result<(), Error> {
+        self.runtime.block_on(self.client.removeWorker(workerPoolId, workerGroup, workerId))
+    }
+
+    /// Workers in a Worker Pool
+    ///
+    /// See [`WorkerManager::listWorkersForWorkerPool`](crate::WorkerManager::listWorkersForWorkerPool).
+    pub fn listWorkersForWorkerPool(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.listWorkersForWorkerPool(workerPoolId, continuationToken, limit))
+    }
+
+    /// Generate an unsigned URL for the listWorkersForWorkerPool endpoint
+    pub fn listWorkersForWorkerPool_url(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<String, Error> {
+        self.client.listWorkersForWorkerPool_url(workerPoolId, continuationToken, limit)
+    }
+
+    /// Generate a signed URL for the listWorkersForWorkerPool endpoint
+    pub fn listWorkersForWorkerPool_signed_url(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>, ttl: Duration) -> Result<String, Error> {
+        self.client.listWorkersForWorkerPool_signed_url(workerPoolId, continuationToken, limit, ttl)
+    }
+
+    /// Iterate over all pages of results from the listWorkersForWorkerPool endpoint, following `continuationToken`
+    pub fn listWorkersForWorkerPool_paginated<'a>(&'a self, workerPoolId: &'a str, limit: Option<&'a str>) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+        block_on_stream(&self.runtime, self.client.listWorkersForWorkerPool_paginated(workerPoolId, limit))
+    }
+
+    /// Register a running worker
+    ///
+    /// See [`WorkerManager::registerWorker`](crate::WorkerManager::registerWorker).
+    pub fn registerWorker(&self, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.registerWorker(payload))
+    }
+
+    /// Reregister a Worker
+    ///
+    /// See [`WorkerManager::reregisterWorker`](crate::WorkerManager::reregisterWorker).
+    pub fn reregisterWorker(&self, payload: &Value) -> Result<Value, Error> {
+        self.runtime.block_on(self.client.reregisterWorker(payload))
+    }
+}
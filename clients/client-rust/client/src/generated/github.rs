@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -17,7 +18,7 @@ use crate::util::urlencode;
 ///
 /// When Github forbids an action, this service returns an HTTP 403
 /// with code ForbiddenByGithub.
-pub struct Github (Client);
+pub struct Github (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Github {
@@ -29,6 +30,23 @@ impl Github {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Github instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -92,8 +110,7 @@ impl Github {
         let method = "GET";
         let (path, query) = Self::builds_details(continuationToken, limit, organization, repository, sha);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the builds endpoint
@@ -108,6 +125,13 @@ impl Github {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the builds endpoint, following `continuationToken`
+    pub fn builds_paginated<'a>(&'a self, limit: Option<&'a str>, organization: Option<&'a str>, repository: Option<&'a str>, sha: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::builds_details(None, limit, organization, repository, sha);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for builds
     fn builds_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>, organization: Option<&'a str>, repository: Option<&'a str>, sha: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "builds";
@@ -172,8 +196,7 @@ impl Github {
         let method = "GET";
         let (path, query) = Self::repository_details(owner, repo);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the repository endpoint
@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -11,7 +12,7 @@ use crate::util::urlencode;
 ///
 /// The hooks service provides a mechanism for creating tasks in response to events.
 ///
-pub struct Hooks (Client);
+pub struct Hooks (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Hooks {
@@ -23,6 +24,23 @@ impl Hooks {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Hooks instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -63,8 +81,7 @@ impl Hooks {
         let method = "GET";
         let (path, query) = Self::listHookGroups_details();
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listHookGroups endpoint
@@ -95,8 +112,7 @@ impl Hooks {
         let method = "GET";
         let (path, query) = Self::listHooks_details(hookGroupId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listHooks endpoint
@@ -127,8 +143,7 @@ impl Hooks {
         let method = "GET";
         let (path, query) = Self::hook_details(hookGroupId, hookId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the hook endpoint
@@ -161,8 +176,7 @@ impl Hooks {
         let method = "GET";
         let (path, query) = Self::getHookStatus_details(hookGroupId, hookId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the getHookStatus endpoint
@@ -196,8 +210,7 @@ impl Hooks {
         let method = "PUT";
         let (path, query) = Self::createHook_details(hookGroupId, hookId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createHook
@@ -216,8 +229,7 @@ impl Hooks {
         let method = "POST";
         let (path, query) = Self::updateHook_details(hookGroupId, hookId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for updateHook
@@ -259,8 +271,7 @@ impl Hooks {
         let method = "POST";
         let (path, query) = Self::triggerHook_details(hookGroupId, hookId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for triggerHook
@@ -279,8 +290,7 @@ impl Hooks {
         let method = "GET";
         let (path, query) = Self::getTriggerToken_details(hookGroupId, hookId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the getTriggerToken endpoint
@@ -311,8 +321,7 @@ impl Hooks {
         let method = "POST";
         let (path, query) = Self::resetTriggerToken_details(hookGroupId, hookId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for resetTriggerToken
@@ -334,8 +343,7 @@ impl Hooks {
         let method = "POST";
         let (path, query) = Self::triggerHookWithToken_details(hookGroupId, hookId, token);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for triggerHookWithToken
@@ -354,8 +362,7 @@ impl Hooks {
         let method = "GET";
         let (path, query) = Self::listLastFires_details(hookGroupId, hookId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listLastFires endpoint
@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -16,7 +17,7 @@ use crate::util::urlencode;
 /// messages, so the most common use of API methods is to read from the index.
 ///
 /// Slashes (`/`) aren't allowed in index paths.
-pub struct Index (Client);
+pub struct Index (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Index {
@@ -28,6 +29,23 @@ impl Index {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Index instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -69,8 +87,7 @@ impl Index {
         let method = "GET";
         let (path, query) = Self::findTask_details(indexPath);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the findTask endpoint
@@ -106,8 +123,7 @@ impl Index {
         let method = "GET";
         let (path, query) = Self::listNamespaces_details(namespace, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listNamespaces endpoint
@@ -122,6 +138,13 @@ impl Index {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listNamespaces endpoint, following `continuationToken`
+    pub fn listNamespaces_paginated<'a>(&'a self, namespace: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listNamespaces_details(namespace, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listNamespaces
     fn listNamespaces_details<'a>(namespace: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("namespaces/{}", urlencode(namespace));
@@ -152,8 +175,7 @@ impl Index {
         let method = "GET";
         let (path, query) = Self::listTasks_details(namespace, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listTasks endpoint
@@ -168,6 +190,13 @@ impl Index {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listTasks endpoint, following `continuationToken`
+    pub fn listTasks_paginated<'a>(&'a self, namespace: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listTasks_details(namespace, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listTasks
     fn listTasks_details<'a>(namespace: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("tasks/{}", urlencode(namespace));
@@ -193,8 +222,7 @@ impl Index {
         let method = "PUT";
         let (path, query) = Self::insertTask_details(namespace);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for insertTask
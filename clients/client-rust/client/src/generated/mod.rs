@@ -1,3 +1,8 @@
+#[cfg(feature = "blocking")]
+pub(crate) mod blocking;
+#[cfg(feature = "typed")]
+pub(crate) mod typed;
+
 mod auth;
 mod github;
 mod hooks;
@@ -19,3 +24,91 @@ pub use purgecache::PurgeCache;
 pub use queue::Queue;
 pub use secrets::Secrets;
 pub use workermanager::WorkerManager;
+
+use crate::ClientBuilder;
+use anyhow::Error;
+
+/// Clients for all Taskcluster services, built from a single configuration.  The clients share
+/// one HTTP client, and with it a pool of connections to the deployment.
+pub struct Taskcluster {
+    auth: Auth,
+    github: Github,
+    hooks: Hooks,
+    index: Index,
+    notify: Notify,
+    object: Object,
+    purge_cache: PurgeCache,
+    queue: Queue,
+    secrets: Secrets,
+    worker_manager: WorkerManager,
+}
+
+impl Taskcluster {
+    /// Create clients for all services from the given builder, such as
+    /// `ClientBuilder::new(root_url).credentials(credentials)`.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        let client_builder = client_builder.into().share_http_client()?;
+        Ok(Self {
+            auth: Auth::new(client_builder.clone())?,
+            github: Github::new(client_builder.clone())?,
+            hooks: Hooks::new(client_builder.clone())?,
+            index: Index::new(client_builder.clone())?,
+            notify: Notify::new(client_builder.clone())?,
+            object: Object::new(client_builder.clone())?,
+            purge_cache: PurgeCache::new(client_builder.clone())?,
+            queue: Queue::new(client_builder.clone())?,
+            secrets: Secrets::new(client_builder.clone())?,
+            worker_manager: WorkerManager::new(client_builder.clone())?,
+        })
+    }
+
+    /// The client for the Auth service
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    /// The client for the Github service
+    pub fn github(&self) -> &Github {
+        &self.github
+    }
+
+    /// The client for the Hooks service
+    pub fn hooks(&self) -> &Hooks {
+        &self.hooks
+    }
+
+    /// The client for the Index service
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// The client for the Notify service
+    pub fn notify(&self) -> &Notify {
+        &self.notify
+    }
+
+    /// The client for the Object service
+    pub fn object(&self) -> &Object {
+        &self.object
+    }
+
+    /// The client for the PurgeCache service
+    pub fn purge_cache(&self) -> &PurgeCache {
+        &self.purge_cache
+    }
+
+    /// The client for the Queue service
+    pub fn queue(&self) -> &Queue {
+        &self.queue
+    }
+
+    /// The client for the Secrets service
+    pub fn secrets(&self) -> &Secrets {
+        &self.secrets
+    }
+
+    /// The client for the WorkerManager service
+    pub fn worker_manager(&self) -> &WorkerManager {
+        &self.worker_manager
+    }
+}
@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -11,7 +12,7 @@ use crate::util::urlencode;
 ///
 /// The notification service listens for tasks with associated notifications
 /// and handles requests to send emails and post pulse messages.
-pub struct Notify (Client);
+pub struct Notify (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Notify {
@@ -23,6 +24,23 @@ impl Notify {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Notify instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -208,8 +226,7 @@ impl Notify {
         let method = "GET";
         let (path, query) = Self::listDenylist_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listDenylist endpoint
@@ -224,6 +241,13 @@ impl Notify {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listDenylist endpoint, following `continuationToken`
+    pub fn listDenylist_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listDenylist_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listDenylist
     fn listDenylist_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "denylist/list";
@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -14,7 +15,7 @@ use crate::util::urlencode;
 /// Objects can be uploaded and downloaded, with the object data flowing directly
 /// from the storage "backend" to the caller, and not directly via this service.
 /// Once uploaded, objects are immutable until their expiration time.
-pub struct Object (Client);
+pub struct Object (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Object {
@@ -26,6 +27,23 @@ impl Object {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Object instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -82,8 +100,7 @@ impl Object {
         let method = "PUT";
         let (path, query) = Self::createUpload_details(name);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createUpload
@@ -131,8 +148,7 @@ impl Object {
         let method = "PUT";
         let (path, query) = Self::startDownload_details(name);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for startDownload
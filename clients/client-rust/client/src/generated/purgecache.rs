@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -14,7 +15,7 @@ use crate::util::urlencode;
 /// User create purge requests for specific caches on specific workers, and
 /// these requests are timestamped.  Workers consult the service before
 /// starting a new task, and purge any caches older than the timestamp.
-pub struct PurgeCache (Client);
+pub struct PurgeCache (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl PurgeCache {
@@ -26,6 +27,23 @@ impl PurgeCache {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this PurgeCache instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -96,8 +114,7 @@ impl PurgeCache {
         let method = "GET";
         let (path, query) = Self::allPurgeRequests_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the allPurgeRequests endpoint
@@ -112,6 +129,13 @@ impl PurgeCache {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the allPurgeRequests endpoint, following `continuationToken`
+    pub fn allPurgeRequests_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::allPurgeRequests_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for allPurgeRequests
     fn allPurgeRequests_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "purge-cache/list";
@@ -136,8 +160,7 @@ impl PurgeCache {
         let method = "GET";
         let (path, query) = Self::purgeRequests_details(workerPoolId, since);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the purgeRequests endpoint
@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -52,7 +53,7 @@ use crate::util::urlencode;
 /// * A `reference` artifact can replace an existing `reference` artifact.
 /// * A `link` artifact can replace an existing `reference` artifact.
 /// * Any artifact's `expires` can be extended (made later, but not earlier).
-pub struct Queue (Client);
+pub struct Queue (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Queue {
@@ -64,6 +65,23 @@ impl Queue {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Queue instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -106,8 +124,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::task_details(taskId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the task endpoint
@@ -137,8 +154,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::status_details(taskId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the status endpoint
@@ -183,8 +199,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listTaskGroup_details(taskGroupId, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listTaskGroup endpoint
@@ -199,6 +214,13 @@ impl Queue {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listTaskGroup endpoint, following `continuationToken`
+    pub fn listTaskGroup_paginated<'a>(&'a self, taskGroupId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listTaskGroup_details(taskGroupId, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listTaskGroup
     fn listTaskGroup_details<'a>(taskGroupId: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("task-group/{}/list", urlencode(taskGroupId));
@@ -235,8 +257,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listDependentTasks_details(taskId, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listDependentTasks endpoint
@@ -251,6 +272,13 @@ impl Queue {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listDependentTasks endpoint, following `continuationToken`
+    pub fn listDependentTasks_paginated<'a>(&'a self, taskId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listDependentTasks_details(taskId, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listDependentTasks
     fn listDependentTasks_details<'a>(taskId: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("task/{}/dependents", urlencode(taskId));
@@ -296,8 +324,7 @@ impl Queue {
         let method = "PUT";
         let (path, query) = Self::createTask_details(taskId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createTask
@@ -328,8 +355,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::scheduleTask_details(taskId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for scheduleTask
@@ -361,8 +387,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::rerunTask_details(taskId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for rerunTask
@@ -391,8 +416,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::cancelTask_details(taskId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for cancelTask
@@ -417,8 +441,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::claimWork_details(taskQueueId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json_with_timeout(method, &path, query, body, self.0.long_poll_timeout()).await
     }
 
     /// Determine the HTTP request details for claimWork
@@ -436,8 +459,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::claimTask_details(taskId, runId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for claimTask
@@ -475,8 +497,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::reclaimTask_details(taskId, runId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for reclaimTask
@@ -494,8 +515,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::reportCompleted_details(taskId, runId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for reportCompleted
@@ -519,8 +539,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::reportFailed_details(taskId, runId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for reportFailed
@@ -550,8 +569,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::reportException_details(taskId, runId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for reportException
@@ -577,8 +595,7 @@ impl Queue {
         let method = "POST";
         let (path, query) = Self::createArtifact_details(taskId, runId, name);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createArtifact
@@ -729,8 +746,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listArtifacts_details(taskId, runId, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listArtifacts endpoint
@@ -745,6 +761,13 @@ impl Queue {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listArtifacts endpoint, following `continuationToken`
+    pub fn listArtifacts_paginated<'a>(&'a self, taskId: &'a str, runId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listArtifacts_details(taskId, runId, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listArtifacts
     fn listArtifacts_details<'a>(taskId: &'a str, runId: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("task/{}/runs/{}/artifacts", urlencode(taskId), urlencode(runId));
@@ -775,8 +798,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listLatestArtifacts_details(taskId, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listLatestArtifacts endpoint
@@ -791,6 +813,13 @@ impl Queue {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listLatestArtifacts endpoint, following `continuationToken`
+    pub fn listLatestArtifacts_paginated<'a>(&'a self, taskId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listLatestArtifacts_details(taskId, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listLatestArtifacts
     fn listLatestArtifacts_details<'a>(taskId: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("task/{}/artifacts", urlencode(taskId));
@@ -816,8 +845,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::artifactInfo_details(taskId, runId, name);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the artifactInfo endpoint
@@ -851,8 +879,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::latestArtifactInfo_details(taskId, name);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the latestArtifactInfo endpoint
@@ -888,8 +915,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::artifact_details(taskId, runId, name);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the artifact endpoint
@@ -925,8 +951,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::latestArtifact_details(taskId, name);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the latestArtifact endpoint
@@ -965,8 +990,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listProvisioners_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listProvisioners endpoint
@@ -981,6 +1005,13 @@ impl Queue {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listProvisioners endpoint, following `continuationToken`
+    pub fn listProvisioners_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listProvisioners_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listProvisioners
     fn listProvisioners_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "provisioners";
@@ -1006,8 +1037,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::getProvisioner_details(provisionerId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the getProvisioner endpoint
@@ -1046,8 +1076,7 @@ impl Queue {
         let method = "PUT";
         let (path, query) = Self::declareProvisioner_details(provisionerId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for declareProvisioner
@@ -1070,8 +1099,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::pendingTasks_details(taskQueueId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the pendingTasks endpoint
@@ -1106,8 +1134,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listWorkerTypes_details(provisionerId, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listWorkerTypes endpoint
@@ -1122,6 +1149,13 @@ impl Queue {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listWorkerTypes endpoint, following `continuationToken`
+    pub fn listWorkerTypes_paginated<'a>(&'a self, provisionerId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listWorkerTypes_details(provisionerId, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listWorkerTypes
     fn listWorkerTypes_details<'a>(provisionerId: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("provisioners/{}/worker-types", urlencode(provisionerId));
@@ -1143,8 +1177,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::getWorkerType_details(provisionerId, workerType);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the getWorkerType endpoint
@@ -1179,8 +1212,7 @@ impl Queue {
         let method = "PUT";
         let (path, query) = Self::declareWorkerType_details(provisionerId, workerType);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for declareWorkerType
@@ -1203,8 +1235,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listTaskQueues_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listTaskQueues endpoint
@@ -1219,6 +1250,13 @@ impl Queue {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listTaskQueues endpoint, following `continuationToken`
+    pub fn listTaskQueues_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listTaskQueues_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listTaskQueues
     fn listTaskQueues_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "task-queues";
@@ -1240,8 +1278,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::getTaskQueue_details(taskQueueId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the getTaskQueue endpoint
@@ -1280,8 +1317,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::listWorkers_details(provisionerId, workerType, continuationToken, limit, quarantined);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listWorkers endpoint
@@ -1296,6 +1332,13 @@ impl Queue {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listWorkers endpoint, following `continuationToken`
+    pub fn listWorkers_paginated<'a>(&'a self, provisionerId: &'a str, workerType: &'a str, limit: Option<&'a str>, quarantined: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listWorkers_details(provisionerId, workerType, None, limit, quarantined);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listWorkers
     fn listWorkers_details<'a>(provisionerId: &'a str, workerType: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>, quarantined: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("provisioners/{}/worker-types/{}/workers", urlencode(provisionerId), urlencode(workerType));
@@ -1320,8 +1363,7 @@ impl Queue {
         let method = "GET";
         let (path, query) = Self::getWorker_details(provisionerId, workerType, workerGroup, workerId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the getWorker endpoint
@@ -1351,8 +1393,7 @@ impl Queue {
         let method = "PUT";
         let (path, query) = Self::quarantineWorker_details(provisionerId, workerType, workerGroup, workerId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for quarantineWorker
@@ -1373,8 +1414,7 @@ impl Queue {
         let method = "PUT";
         let (path, query) = Self::declareWorker_details(provisionerId, workerType, workerGroup, workerId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for declareWorker
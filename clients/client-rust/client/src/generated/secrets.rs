@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -16,7 +17,7 @@ use crate::util::urlencode;
 /// Secrets also have an expiration date, and once a secret has expired it can no
 /// longer be read.  This is useful for short-term secrets such as a temporary
 /// service credential or a one-time signing key.
-pub struct Secrets (Client);
+pub struct Secrets (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl Secrets {
@@ -28,6 +29,23 @@ impl Secrets {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this Secrets instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -112,8 +130,7 @@ impl Secrets {
         let method = "GET";
         let (path, query) = Self::get_details(name);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the get endpoint
@@ -153,8 +170,7 @@ impl Secrets {
         let method = "GET";
         let (path, query) = Self::list_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the list endpoint
@@ -169,6 +185,13 @@ impl Secrets {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the list endpoint, following `continuationToken`
+    pub fn list_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::list_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for list
     fn list_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "secrets";
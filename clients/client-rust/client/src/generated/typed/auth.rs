@@ -0,0 +1,747 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Auth`](crate::Auth).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Auth {
+    client: crate::Auth,
+}
+
+#[allow(non_snake_case)]
+impl Auth {
+    /// Create a new typed Auth instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Auth::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Auth {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Auth::ping`](crate::Auth::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// List Clients
+    ///
+    /// See [`Auth::listClients`](crate::Auth::listClients).
+    pub async fn listClients(&self, prefix: Option<&str>, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListClientsResponse, Error> {
+        let output = self.client.listClients(prefix, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listClients response")
+    }
+
+    /// Stream all pages of results from the listClients endpoint, following `continuationToken`
+    pub fn listClients_paginated<'a>(&'a self, prefix: Option<&'a str>, limit: Option<&'a str>) -> impl Stream<Item = Result<ListClientsResponse, Error>> + 'a {
+        self.client.listClients_paginated(prefix, limit).map(|page| {
+            serde_json::from_value::<ListClientsResponse>(page?).context("Invalid listClients response")
+        })
+    }
+
+    /// Get Client
+    ///
+    /// See [`Auth::client`](crate::Auth::client).
+    pub async fn client(&self, clientId: &str) -> Result<GetClientResponse, Error> {
+        let output = self.client.client(clientId).await?;
+        serde_json::from_value(output).context("Invalid client response")
+    }
+
+    /// Create Client
+    ///
+    /// See [`Auth::createClient`](crate::Auth::createClient).
+    pub async fn createClient(&self, clientId: &str, payload: &CreateClientRequest) -> Result<CreateClientResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.createClient(clientId, payload).await?;
+        serde_json::from_value(output).context("Invalid createClient response")
+    }
+
+    /// Reset `accessToken`
+    ///
+    /// See [`Auth::resetAccessToken`](crate::Auth::resetAccessToken).
+    pub async fn resetAccessToken(&self, clientId: &str) -> Result<CreateClientResponse, Error> {
+        let output = self.client.resetAccessToken(clientId).await?;
+        serde_json::from_value(output).context("Invalid resetAccessToken response")
+    }
+
+    /// Update Client
+    ///
+    /// See [`Auth::updateClient`](crate::Auth::updateClient).
+    pub async fn updateClient(&self, clientId: &str, payload: &CreateClientRequest) -> Result<GetClientResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.updateClient(clientId, payload).await?;
+        serde_json::from_value(output).context("Invalid updateClient response")
+    }
+
+    /// Enable Client
+    ///
+    /// See [`Auth::enableClient`](crate::Auth::enableClient).
+    pub async fn enableClient(&self, clientId: &str) -> Result<GetClientResponse, Error> {
+        let output = self.client.enableClient(clientId).await?;
+        serde_json::from_value(output).context("Invalid enableClient response")
+    }
+
+    /// Disable Client
+    ///
+    /// See [`Auth::disableClient`](crate::Auth::disableClient).
+    pub async fn disableClient(&self, clientId: &str) -> Result<GetClientResponse, Error> {
+        let output = self.client.disableClient(clientId).await?;
+        serde_json::from_value(output).context("Invalid disableClient response")
+    }
+
+    /// Delete Client
+    ///
+    /// See [`Auth::deleteClient`](crate::Auth::deleteClient).
+    pub async fn deleteClient(&self, clientId: &str) -> Result<(), Error> {
+        self.client.deleteClient(clientId).await
+    }
+
+    /// List Roles (no pagination)
+    ///
+    /// See [`Auth::listRoles`](crate::Auth::listRoles).
+    pub async fn listRoles(&self) -> Result<Vec<GetRoleResponse>, Error> {
+        let output = self.client.listRoles().await?;
+        serde_json::from_value(output).context("Invalid listRoles response")
+    }
+
+    /// List Roles
+    ///
+    /// See [`Auth::listRoles2`](crate::Auth::listRoles2).
+    pub async fn listRoles2(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListRoles2Response, Error> {
+        let output = self.client.listRoles2(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listRoles2 response")
+    }
+
+    /// Stream all pages of results from the listRoles2 endpoint, following `continuationToken`
+    pub fn listRoles2_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<ListRoles2Response, Error>> + 'a {
+        self.client.listRoles2_paginated(limit).map(|page| {
+            serde_json::from_value::<ListRoles2Response>(page?).context("Invalid listRoles2 response")
+        })
+    }
+
+    /// List Role IDs
+    ///
+    /// See [`Auth::listRoleIds`](crate::Auth::listRoleIds).
+    pub async fn listRoleIds(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListRoleIdsResponse, Error> {
+        let output = self.client.listRoleIds(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listRoleIds response")
+    }
+
+    /// Stream all pages of results from the listRoleIds endpoint, following `continuationToken`
+    pub fn listRoleIds_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<ListRoleIdsResponse, Error>> + 'a {
+        self.client.listRoleIds_paginated(limit).map(|page| {
+            serde_json::from_value::<ListRoleIdsResponse>(page?).context("Invalid listRoleIds response")
+        })
+    }
+
+    /// Get Role
+    ///
+    /// See [`Auth::role`](crate::Auth::role).
+    pub async fn role(&self, roleId: &str) -> Result<GetRoleResponse, Error> {
+        let output = self.client.role(roleId).await?;
+        serde_json::from_value(output).context("Invalid role response")
+    }
+
+    /// Create Role
+    ///
+    /// See [`Auth::createRole`](crate::Auth::createRole).
+    pub async fn createRole(&self, roleId: &str, payload: &CreateRoleRequest) -> Result<GetRoleResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.createRole(roleId, payload).await?;
+        serde_json::from_value(output).context("Invalid createRole response")
+    }
+
+    /// Update Role
+    ///
+    /// See [`Auth::updateRole`](crate::Auth::updateRole).
+    pub async fn updateRole(&self, roleId: &str, payload: &CreateRoleRequest) -> Result<GetRoleResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.updateRole(roleId, payload).await?;
+        serde_json::from_value(output).context("Invalid updateRole response")
+    }
+
+    /// Delete Role
+    ///
+    /// See [`Auth::deleteRole`](crate::Auth::deleteRole).
+    pub async fn deleteRole(&self, roleId: &str) -> Result<(), Error> {
+        self.client.deleteRole(roleId).await
+    }
+
+    /// Expand Scopes
+    ///
+    /// See [`Auth::expandScopes`](crate::Auth::expandScopes).
+    pub async fn expandScopes(&self, payload: &Scopeset) -> Result<Scopeset, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.expandScopes(payload).await?;
+        serde_json::from_value(output).context("Invalid expandScopes response")
+    }
+
+    /// Get Current Scopes
+    ///
+    /// See [`Auth::currentScopes`](crate::Auth::currentScopes).
+    pub async fn currentScopes(&self) -> Result<Scopeset, Error> {
+        let output = self.client.currentScopes().await?;
+        serde_json::from_value(output).context("Invalid currentScopes response")
+    }
+
+    /// Get Temporary Read/Write Credentials S3
+    ///
+    /// See [`Auth::awsS3Credentials`](crate::Auth::awsS3Credentials).
+    pub async fn awsS3Credentials(&self, level: &str, bucket: &str, prefix: &str, format: Option<&str>) -> Result<AwsS3CredentialsResponse, Error> {
+        let output = self.client.awsS3Credentials(level, bucket, prefix, format).await?;
+        serde_json::from_value(output).context("Invalid awsS3Credentials response")
+    }
+
+    /// List Accounts Managed by Auth
+    ///
+    /// See [`Auth::azureAccounts`](crate::Auth::azureAccounts).
+    pub async fn azureAccounts(&self) -> Result<AzureAccountListResponse, Error> {
+        let output = self.client.azureAccounts().await?;
+        serde_json::from_value(output).context("Invalid azureAccounts response")
+    }
+
+    /// List Tables in an Account Managed by Auth
+    ///
+    /// See [`Auth::azureTables`](crate::Auth::azureTables).
+    pub async fn azureTables(&self, account: &str, continuationToken: Option<&str>) -> Result<AzureTableListResponse, Error> {
+        let output = self.client.azureTables(account, continuationToken).await?;
+        serde_json::from_value(output).context("Invalid azureTables response")
+    }
+
+    /// Stream all pages of results from the azureTables endpoint, following `continuationToken`
+    pub fn azureTables_paginated<'a>(&'a self, account: &'a str) -> impl Stream<Item = Result<AzureTableListResponse, Error>> + 'a {
+        self.client.azureTables_paginated(account).map(|page| {
+            serde_json::from_value::<AzureTableListResponse>(page?).context("Invalid azureTables response")
+        })
+    }
+
+    /// Get Shared-Access-Signature for Azure Table
+    ///
+    /// See [`Auth::azureTableSAS`](crate::Auth::azureTableSAS).
+    pub async fn azureTableSAS(&self, account: &str, table: &str, level: &str) -> Result<AzureTableAccessResponse, Error> {
+        let output = self.client.azureTableSAS(account, table, level).await?;
+        serde_json::from_value(output).context("Invalid azureTableSAS response")
+    }
+
+    /// List containers in an Account Managed by Auth
+    ///
+    /// See [`Auth::azureContainers`](crate::Auth::azureContainers).
+    pub async fn azureContainers(&self, account: &str, continuationToken: Option<&str>) -> Result<AzureContainerListResponse, Error> {
+        let output = self.client.azureContainers(account, continuationToken).await?;
+        serde_json::from_value(output).context("Invalid azureContainers response")
+    }
+
+    /// Stream all pages of results from the azureContainers endpoint, following `continuationToken`
+    pub fn azureContainers_paginated<'a>(&'a self, account: &'a str) -> impl Stream<Item = Result<AzureContainerListResponse, Error>> + 'a {
+        self.client.azureContainers_paginated(account).map(|page| {
+            serde_json::from_value::<AzureContainerListResponse>(page?).context("Invalid azureContainers response")
+        })
+    }
+
+    /// Get Shared-Access-Signature for Azure Container
+    ///
+    /// See [`Auth::azureContainerSAS`](crate::Auth::azureContainerSAS).
+    pub async fn azureContainerSAS(&self, account: &str, container: &str, level: &str) -> Result<AzureContainerResponse, Error> {
+        let output = self.client.azureContainerSAS(account, container, level).await?;
+        serde_json::from_value(output).context("Invalid azureContainerSAS response")
+    }
+
+    /// Get DSN for Sentry Project
+    ///
+    /// See [`Auth::sentryDSN`](crate::Auth::sentryDSN).
+    pub async fn sentryDSN(&self, project: &str) -> Result<SentryDsnResponse, Error> {
+        let output = self.client.sentryDSN(project).await?;
+        serde_json::from_value(output).context("Invalid sentryDSN response")
+    }
+
+    /// Get a client token for the Websocktunnel service
+    ///
+    /// See [`Auth::websocktunnelToken`](crate::Auth::websocktunnelToken).
+    pub async fn websocktunnelToken(&self, wstAudience: &str, wstClient: &str) -> Result<WebsocktunnelTokenResponse, Error> {
+        let output = self.client.websocktunnelToken(wstAudience, wstClient).await?;
+        serde_json::from_value(output).context("Invalid websocktunnelToken response")
+    }
+
+    /// Get Temporary GCP Credentials
+    ///
+    /// See [`Auth::gcpCredentials`](crate::Auth::gcpCredentials).
+    pub async fn gcpCredentials(&self, projectId: &str, serviceAccount: &str) -> Result<GcpCredentialsResponse, Error> {
+        let output = self.client.gcpCredentials(projectId, serviceAccount).await?;
+        serde_json::from_value(output).context("Invalid gcpCredentials response")
+    }
+
+    /// Authenticate Hawk Request
+    ///
+    /// See [`Auth::authenticateHawk`](crate::Auth::authenticateHawk).
+    pub async fn authenticateHawk(&self, payload: &AuthenticateHawkRequest) -> Result<Value, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.authenticateHawk(payload).await
+    }
+
+    /// Test Authentication
+    ///
+    /// See [`Auth::testAuthenticate`](crate::Auth::testAuthenticate).
+    pub async fn testAuthenticate(&self, payload: &TestAuthenticateRequest) -> Result<TestAuthenticateResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.testAuthenticate(payload).await?;
+        serde_json::from_value(output).context("Invalid testAuthenticate response")
+    }
+
+    /// Test Authentication (GET)
+    ///
+    /// See [`Auth::testAuthenticateGet`](crate::Auth::testAuthenticateGet).
+    pub async fn testAuthenticateGet(&self) -> Result<TestAuthenticateResponse, Error> {
+        let output = self.client.testAuthenticateGet().await?;
+        serde_json::from_value(output).context("Invalid testAuthenticateGet response")
+    }
+}
+
+/// List of clients
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListClientsResponse {
+    pub clients: Vec<GetClientResponse>,
+
+    /// A continuation token is returned if there are more results than listed
+    /// here. You can optionally provide the token in the request payload to
+    /// load the additional results.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// Get all details about a client, useful for tools modifying a client
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GetClientResponse {
+    /// ClientId of the client scopes is requested about
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+
+    /// Date and time when this client was created
+    pub created: DateTime<Utc>,
+
+    /// If `true`, the service may delete this client after it has expired.  If
+    /// `false`, the client will remain after expiration, although it cannot be
+    /// used for authentication in that state.
+    #[serde(rename = "deleteOnExpiration")]
+    pub delete_on_expiration: bool,
+
+    /// Description of what these credentials are used for in markdown.
+    /// Should include who is the owner, point of contact.
+    pub description: String,
+
+    /// If true, this client is disabled and cannot be used.  This usually occurs when the
+    /// scopes available to the user owning the client no longer satisfy the client.
+    pub disabled: bool,
+
+    /// List of scopes granted to this client by matching roles.  Scopes must be
+    /// composed of printable ASCII characters and spaces.
+    #[serde(rename = "expandedScopes")]
+    pub expanded_scopes: Vec<String>,
+
+    /// Date and time where the clients access is set to expire
+    pub expires: DateTime<Utc>,
+
+    /// Date of last time this client was used. Will only be updated every 6 hours
+    /// or so this may be off by up-to 6 hours. But it still gives a solid hint
+    /// as to whether or not this client is in use.
+    #[serde(rename = "lastDateUsed")]
+    pub last_date_used: DateTime<Utc>,
+
+    /// Date and time of last modification
+    #[serde(rename = "lastModified")]
+    pub last_modified: DateTime<Utc>,
+
+    /// Date and time of when the `accessToken` was reset last time.
+    #[serde(rename = "lastRotated")]
+    pub last_rotated: DateTime<Utc>,
+
+    /// List of scopes the client has (unexpanded).  Scopes must be composed of
+    /// printable ASCII characters and spaces.
+    pub scopes: Vec<String>,
+}
+
+/// Properties to create a client.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateClientRequest {
+    /// If `true`, the service may delete this client after it has expired.  If
+    /// `false` (the default), the client will remain after expiration, although
+    /// it cannot be used for authentication in that state.
+    #[serde(rename = "deleteOnExpiration", default, skip_serializing_if = "Option::is_none")]
+    pub delete_on_expiration: Option<bool>,
+
+    /// Description of what these credentials are used for in markdown.
+    /// Should include who is the owner, point of contact.
+    pub description: String,
+
+    /// Date and time where the clients access is set to expire
+    pub expires: DateTime<Utc>,
+
+    /// List of scopes the client has (unexpanded).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+}
+
+/// All details about a client including the `accessToken`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateClientResponse {
+    /// AccessToken used for authenticating requests, you should store this
+    /// you won't be able to retrive it again!
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    /// ClientId of the client
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+
+    /// Date and time when this client was created
+    pub created: DateTime<Utc>,
+
+    /// If `true`, the service may delete this client after it has expired.  If
+    /// `false`, the client will remain after expiration, although it cannot be
+    /// used for authentication in that state.
+    #[serde(rename = "deleteOnExpiration")]
+    pub delete_on_expiration: bool,
+
+    /// Description of what these credentials are used for in markdown.
+    /// Should include who is the owner, point of contact.
+    pub description: String,
+
+    /// If true, this client is disabled and cannot be used.  This usually occurs when the
+    /// scopes available to the user owning the client no longer satisfy the client.
+    pub disabled: bool,
+
+    /// List of scopes granted to this client by matching roles, including the
+    /// client's scopes and the implicit role `client-id:<clientId>`.
+    #[serde(rename = "expandedScopes")]
+    pub expanded_scopes: Vec<String>,
+
+    /// Date and time where the clients access is set to expire
+    pub expires: DateTime<Utc>,
+
+    /// Date of last time this client was used. Will only be updated every 6 hours
+    /// or so this may be off by up-to 6 hours. But it still gives a solid hint
+    /// as to whether or not this client is in use.
+    #[serde(rename = "lastDateUsed")]
+    pub last_date_used: DateTime<Utc>,
+
+    /// Date and time of last modification
+    #[serde(rename = "lastModified")]
+    pub last_modified: DateTime<Utc>,
+
+    /// Date and time of when the `accessToken` was reset last time.
+    #[serde(rename = "lastRotated")]
+    pub last_rotated: DateTime<Utc>,
+
+    /// List of scopes the client has (unexpanded).
+    pub scopes: Vec<String>,
+}
+
+/// Get all details about a role
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GetRoleResponse {
+    /// Date and time when this role was created
+    pub created: DateTime<Utc>,
+
+    /// Description of what this role is used for in markdown.
+    /// Should include who is the owner, point of contact.
+    pub description: String,
+
+    /// List of scopes granted anyone who assumes this role, including anything
+    /// granted by roles that can be assumed when you have this role.
+    /// Hence, this includes any scopes in-directly granted as well.
+    #[serde(rename = "expandedScopes")]
+    pub expanded_scopes: Vec<String>,
+
+    /// Date and time of last modification
+    #[serde(rename = "lastModified")]
+    pub last_modified: DateTime<Utc>,
+
+    /// roleId of the role requested
+    #[serde(rename = "roleId")]
+    pub role_id: String,
+
+    /// List of scopes the role grants access to.  Scopes must be composed of
+    /// printable ASCII characters and spaces.
+    pub scopes: Vec<String>,
+}
+
+/// If no limit is given, all roles are returned. Since this
+/// list may become long, callers can use the `limit` and `continuationToken`
+/// query arguments to page through the responses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListRoles2Response {
+    /// A continuation token is returned if there are more results than listed
+    /// here. You can optionally provide the token in the request payload to
+    /// load the additional results.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// A list of requested roles
+    pub roles: Vec<GetRoleResponse>,
+}
+
+/// If no limit is given, the roleIds of all roles are returned. Since this
+/// list may become long, callers can use the `limit` and `continuationToken`
+/// query arguments to page through the responses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListRoleIdsResponse {
+    /// A continuation token is returned if there are more results than listed
+    /// here. You can optionally provide the token in the request payload to
+    /// load the additional results.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// A list of requested roleIds
+    #[serde(rename = "roleIds")]
+    pub role_ids: Vec<String>,
+}
+
+/// Data to create or update a role.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateRoleRequest {
+    /// Description of what this role is used for in markdown.
+    /// Should include who is the owner, point of contact.
+    pub description: String,
+
+    /// List of scopes the role grants access to.  Scopes must be composed of
+    /// printable ASCII characters and spaces.
+    pub scopes: Vec<String>,
+}
+
+/// A set of scopes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scopeset {
+    /// List of scopes.  Scopes must be composed of printable ASCII characters and spaces.
+    pub scopes: Vec<String>,
+}
+
+/// Response for a request to get access to an S3 bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwsS3CredentialsResponse {
+    /// Temporary STS credentials for use when operating on S3
+    pub credentials: AwsS3CredentialsResponseCredentials,
+
+    /// Date and time of when the temporary credentials expires.
+    pub expires: DateTime<Utc>,
+}
+
+/// Temporary STS credentials for use when operating on S3
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwsS3CredentialsResponseCredentials {
+    /// Access key identifier that identifies the temporary security
+    /// credentials.
+    #[serde(rename = "accessKeyId")]
+    pub access_key_id: String,
+
+    /// Secret access key used to sign requests
+    #[serde(rename = "secretAccessKey")]
+    pub secret_access_key: String,
+
+    /// A token that must passed with request to use the temporary
+    /// security credentials.
+    #[serde(rename = "sessionToken")]
+    pub session_token: String,
+}
+
+/// A list of Azure accounts managed by taskcluster-auth
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureAccountListResponse {
+    /// A list of accountIds that are managed by auth. These are
+    /// the accounts that can have SAS credentials fetched for tables
+    /// within them.
+    pub accounts: Vec<String>,
+}
+
+/// A list of Azure tables in an account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureTableListResponse {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of tables.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `azureAccountTables` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// A list of tables that are in an account. These are
+    /// the tables that can have SAS credentials fetched for them.
+    pub tables: Vec<String>,
+}
+
+/// Response to a request for an Shared-Access-Signature to access and Azure
+/// Table Storage table.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureTableAccessResponse {
+    /// Date and time of when the Shared-Access-Signature expires.
+    pub expiry: DateTime<Utc>,
+
+    /// Shared-Access-Signature string. This is the querystring parameters to
+    /// be appened after `?` or `&` depending on whether or not a querystring is
+    /// already present in the URL.
+    pub sas: String,
+}
+
+/// A list of Azure containers in an account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureContainerListResponse {
+    /// A list of containers that are in an account.  Credentials are available for
+    /// these containers from the `azureBlobSAS` method.
+    pub containers: Vec<String>,
+
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of containers.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called this method with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// Response to a request for an Shared-Access-Signature to access an Azure
+/// Blob Storage container.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureContainerResponse {
+    /// Date and time of when the Shared-Access-Signature expires.
+    pub expiry: DateTime<Utc>,
+
+    /// Shared-Access-Signature string. This is the querystring parameters to
+    /// be appened after `?` or `&` depending on whether or not a querystring is
+    /// already present in the URL.
+    pub sas: String,
+}
+
+/// Sentry DSN for submitting errors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentryDsnResponse {
+    /// Access credentials and urls for the Sentry project.
+    /// Credentials will expire in 24-48 hours, you should refresh them within
+    /// 24 hours.
+    pub dsn: SentryDsnResponseDsn,
+
+    /// Expiration time for the credentials. The credentials should not be used
+    /// after this time. They might not be revoked immediately, but will be at
+    /// some arbitrary point after this date-time.
+    pub expires: DateTime<Utc>,
+
+    /// Project name that the DSN grants access to.
+    pub project: String,
+}
+
+/// Access credentials and urls for the Sentry project.
+/// Credentials will expire in 24-48 hours, you should refresh them within
+/// 24 hours.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentryDsnResponseDsn {
+    /// Access credential and URL for public error reports.
+    /// These credentials can be used for up-to 24 hours.
+    /// This is for use in client-side applications only.
+    pub public: String,
+
+    /// Access credential and URL for private error reports.
+    /// These credentials can be used for up-to 24 hours.
+    /// This is for use in serser-side applications and should **not** be
+    /// leaked.
+    pub secret: String,
+}
+
+/// Token for connecting a worker to websocktunnel proxy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebsocktunnelTokenResponse {
+    /// The time at which the JWT will expire.
+    pub expires: DateTime<Utc>,
+
+    /// The JWT to be used as `Bearer <token>` when connecting to the service.
+    pub token: String,
+
+    /// Audience identifying the websocktunnel servers that will honor this token; this will be the
+    /// same as the requested `wstAudience`.
+    #[serde(rename = "wstAudience")]
+    pub wst_audience: String,
+
+    /// Id for the websocktunnel client connection; this will be the same as the requested `wstClient`.
+    #[serde(rename = "wstClient")]
+    pub wst_client: String,
+}
+
+/// Response for a request to get a GCP temporary credential.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GcpCredentialsResponse {
+    /// Temporary oauth2 access token to access the given service account
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    /// The access token expire time
+    #[serde(rename = "expireTime")]
+    pub expire_time: DateTime<Utc>,
+}
+
+/// Request to authenticate a hawk request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticateHawkRequest {
+    /// Authorization header, **must** only be specified if request being
+    /// authenticated has a `Authorization` header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>,
+
+    /// Host for which the request came in, this is typically the `Host` header
+    /// excluding the port if any.
+    pub host: String,
+
+    /// HTTP method of the request being authenticated.
+    pub method: String,
+
+    /// Port on which the request came in, this is typically `80` or `443`.
+    /// If you are running behind a reverse proxy look for the `x-forwarded-port`
+    /// header.
+    pub port: i64,
+
+    /// Resource the request operates on including querystring. This is the
+    /// string that follows the HTTP method.
+    /// **Note,** order of querystring elements is important.
+    pub resource: String,
+
+    /// Source IP of the authentication request or request that requires
+    /// authentication. This is only used for audit logging.
+    #[serde(rename = "sourceIp", default, skip_serializing_if = "Option::is_none")]
+    pub source_ip: Option<Value>,
+}
+
+/// Details on how the test request should be authenticated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestAuthenticateRequest {
+    /// List of scopes that should be client used should be given.
+    #[serde(rename = "clientScopes")]
+    pub client_scopes: Vec<String>,
+
+    /// List of scopes the request should require.
+    #[serde(rename = "requiredScopes")]
+    pub required_scopes: Vec<String>,
+}
+
+/// Details on how the test request was authenticated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestAuthenticateResponse {
+    /// ClientId from the request as it will be logged
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+
+    /// List of scopes the request was authorized.
+    pub scopes: Vec<String>,
+}
@@ -0,0 +1,180 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Github`](crate::Github).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Github {
+    client: crate::Github,
+}
+
+#[allow(non_snake_case)]
+impl Github {
+    /// Create a new typed Github instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Github::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Github {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Github::ping`](crate::Github::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// Consume GitHub WebHook
+    ///
+    /// See [`Github::githubWebHookConsumer`](crate::Github::githubWebHookConsumer).
+    pub async fn githubWebHookConsumer(&self) -> Result<(), Error> {
+        self.client.githubWebHookConsumer().await
+    }
+
+    /// List of Builds
+    ///
+    /// See [`Github::builds`](crate::Github::builds).
+    pub async fn builds(&self, continuationToken: Option<&str>, limit: Option<&str>, organization: Option<&str>, repository: Option<&str>, sha: Option<&str>) -> Result<BuildList, Error> {
+        let output = self.client.builds(continuationToken, limit, organization, repository, sha).await?;
+        serde_json::from_value(output).context("Invalid builds response")
+    }
+
+    /// Stream all pages of results from the builds endpoint, following `continuationToken`
+    pub fn builds_paginated<'a>(&'a self, limit: Option<&'a str>, organization: Option<&'a str>, repository: Option<&'a str>, sha: Option<&'a str>) -> impl Stream<Item = Result<BuildList, Error>> + 'a {
+        self.client.builds_paginated(limit, organization, repository, sha).map(|page| {
+            serde_json::from_value::<BuildList>(page?).context("Invalid builds response")
+        })
+    }
+
+    /// Latest Build Status Badge
+    ///
+    /// See [`Github::badge`](crate::Github::badge).
+    pub async fn badge(&self, owner: &str, repo: &str, branch: &str) -> Result<(), Error> {
+        self.client.badge(owner, repo, branch).await
+    }
+
+    /// Get Repository Info
+    ///
+    /// See [`Github::repository`](crate::Github::repository).
+    pub async fn repository(&self, owner: &str, repo: &str) -> Result<Repository, Error> {
+        let output = self.client.repository(owner, repo).await?;
+        serde_json::from_value(output).context("Invalid repository response")
+    }
+
+    /// Latest Status for Branch
+    ///
+    /// See [`Github::latest`](crate::Github::latest).
+    pub async fn latest(&self, owner: &str, repo: &str, branch: &str) -> Result<(), Error> {
+        self.client.latest(owner, repo, branch).await
+    }
+
+    /// Post a status against a given changeset
+    ///
+    /// See [`Github::createStatus`](crate::Github::createStatus).
+    pub async fn createStatus(&self, owner: &str, repo: &str, sha: &str, payload: &CreateStatus) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.createStatus(owner, repo, sha, payload).await
+    }
+
+    /// Post a comment on a given GitHub Issue or Pull Request
+    ///
+    /// See [`Github::createComment`](crate::Github::createComment).
+    pub async fn createComment(&self, owner: &str, repo: &str, number: &str, payload: &CreateComment) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.createComment(owner, repo, number, payload).await
+    }
+}
+
+/// A paginated list of builds
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildList {
+    /// A simple list of builds.
+    pub builds: Vec<BuildListBuildsItem>,
+
+    /// Passed back from Azure to allow us to page through long result sets.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// Build
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BuildListBuildsItem {
+    /// The initial creation time of the build. This is when it became pending.
+    pub created: DateTime<Utc>,
+
+    /// The GitHub webhook deliveryId. Extracted from the header 'X-GitHub-Delivery'
+    #[serde(rename = "eventId")]
+    pub event_id: Value,
+
+    /// Type of Github event that triggered the build (i.e. push, pull_request.opened).
+    #[serde(rename = "eventType")]
+    pub event_type: String,
+
+    /// Github organization associated with the build.
+    pub organization: String,
+
+    /// Github repository associated with the build.
+    pub repository: String,
+
+    /// Github revision associated with the build.
+    pub sha: String,
+
+    /// Github status associated with the build.
+    pub state: String,
+
+    /// Taskcluster task-group associated with the build.
+    #[serde(rename = "taskGroupId")]
+    pub task_group_id: String,
+
+    /// The last updated of the build. If it is done, this is when it finished.
+    pub updated: DateTime<Utc>,
+}
+
+/// Any Taskcluster-specific Github repository information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Repository {
+    /// True if integration is installed, False otherwise.
+    pub installed: bool,
+}
+
+/// Create a commit status on GitHub.
+/// Full specification on [GitHub docs](https://developer.github.com/v3/repos/statuses/#create-a-status)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateStatus {
+    /// A string label to differentiate this status from the status of other systems.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+
+    /// A short description of the status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The state of the status.
+    pub state: String,
+
+    /// The target URL to associate with this status. This URL will be linked from the GitHub UI to allow users to easily see the 'source' of the Status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+}
+
+/// Write a new comment on a GitHub Issue or Pull Request.
+/// Full specification on [GitHub docs](https://developer.github.com/v3/issues/comments/#create-a-comment)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateComment {
+    /// The contents of the comment.
+    pub body: String,
+}
@@ -0,0 +1,296 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Hooks`](crate::Hooks).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Hooks {
+    client: crate::Hooks,
+}
+
+#[allow(non_snake_case)]
+impl Hooks {
+    /// Create a new typed Hooks instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Hooks::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Hooks {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Hooks::ping`](crate::Hooks::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// List hook groups
+    ///
+    /// See [`Hooks::listHookGroups`](crate::Hooks::listHookGroups).
+    pub async fn listHookGroups(&self) -> Result<ListHookGroupsResponse, Error> {
+        let output = self.client.listHookGroups().await?;
+        serde_json::from_value(output).context("Invalid listHookGroups response")
+    }
+
+    /// List hooks in a given group
+    ///
+    /// See [`Hooks::listHooks`](crate::Hooks::listHooks).
+    pub async fn listHooks(&self, hookGroupId: &str) -> Result<ListHooksResponse, Error> {
+        let output = self.client.listHooks(hookGroupId).await?;
+        serde_json::from_value(output).context("Invalid listHooks response")
+    }
+
+    /// Get hook definition
+    ///
+    /// See [`Hooks::hook`](crate::Hooks::hook).
+    pub async fn hook(&self, hookGroupId: &str, hookId: &str) -> Result<HookDefinition, Error> {
+        let output = self.client.hook(hookGroupId, hookId).await?;
+        serde_json::from_value(output).context("Invalid hook response")
+    }
+
+    /// Get hook status
+    ///
+    /// See [`Hooks::getHookStatus`](crate::Hooks::getHookStatus).
+    pub async fn getHookStatus(&self, hookGroupId: &str, hookId: &str) -> Result<HookStatus, Error> {
+        let output = self.client.getHookStatus(hookGroupId, hookId).await?;
+        serde_json::from_value(output).context("Invalid getHookStatus response")
+    }
+
+    /// Create a hook
+    ///
+    /// See [`Hooks::createHook`](crate::Hooks::createHook).
+    pub async fn createHook(&self, hookGroupId: &str, hookId: &str, payload: &CreateHookRequest) -> Result<HookDefinition, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.createHook(hookGroupId, hookId, payload).await?;
+        serde_json::from_value(output).context("Invalid createHook response")
+    }
+
+    /// Update a hook
+    ///
+    /// See [`Hooks::updateHook`](crate::Hooks::updateHook).
+    pub async fn updateHook(&self, hookGroupId: &str, hookId: &str, payload: &CreateHookRequest) -> Result<HookDefinition, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.updateHook(hookGroupId, hookId, payload).await?;
+        serde_json::from_value(output).context("Invalid updateHook response")
+    }
+
+    /// Delete a hook
+    ///
+    /// See [`Hooks::removeHook`](crate::Hooks::removeHook).
+    pub async fn removeHook(&self, hookGroupId: &str, hookId: &str) -> Result<(), Error> {
+        self.client.removeHook(hookGroupId, hookId).await
+    }
+
+    /// Trigger a hook
+    ///
+    /// See [`Hooks::triggerHook`](crate::Hooks::triggerHook).
+    pub async fn triggerHook(&self, hookGroupId: &str, hookId: &str, payload: &Value) -> Result<Value, Error> {
+        self.client.triggerHook(hookGroupId, hookId, payload).await
+    }
+
+    /// Get a trigger token
+    ///
+    /// See [`Hooks::getTriggerToken`](crate::Hooks::getTriggerToken).
+    pub async fn getTriggerToken(&self, hookGroupId: &str, hookId: &str) -> Result<TriggerTokenResponse, Error> {
+        let output = self.client.getTriggerToken(hookGroupId, hookId).await?;
+        serde_json::from_value(output).context("Invalid getTriggerToken response")
+    }
+
+    /// Reset a trigger token
+    ///
+    /// See [`Hooks::resetTriggerToken`](crate::Hooks::resetTriggerToken).
+    pub async fn resetTriggerToken(&self, hookGroupId: &str, hookId: &str) -> Result<TriggerTokenResponse, Error> {
+        let output = self.client.resetTriggerToken(hookGroupId, hookId).await?;
+        serde_json::from_value(output).context("Invalid resetTriggerToken response")
+    }
+
+    /// Trigger a hook with a token
+    ///
+    /// See [`Hooks::triggerHookWithToken`](crate::Hooks::triggerHookWithToken).
+    pub async fn triggerHookWithToken(&self, hookGroupId: &str, hookId: &str, token: &str, payload: &Value) -> Result<Value, Error> {
+        self.client.triggerHookWithToken(hookGroupId, hookId, token, payload).await
+    }
+
+    /// Get information about recent hook fires
+    ///
+    /// See [`Hooks::listLastFires`](crate::Hooks::listLastFires).
+    pub async fn listLastFires(&self, hookGroupId: &str, hookId: &str) -> Result<ListLastFiresResponse, Error> {
+        let output = self.client.listLastFires(hookGroupId, hookId).await?;
+        serde_json::from_value(output).context("Invalid listLastFires response")
+    }
+}
+
+/// List of `hookGroupIds`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListHookGroupsResponse {
+    /// Groups
+    pub groups: Vec<String>,
+}
+
+/// List of hooks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListHooksResponse {
+    /// Hooks
+    pub hooks: Vec<HookDefinition>,
+}
+
+/// Definition of a hook that will create tasks when defined events occur.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookDefinition {
+    /// List of Bindings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<Vec<BindingsItem>>,
+
+    #[serde(rename = "hookGroupId")]
+    pub hook_group_id: String,
+
+    #[serde(rename = "hookId")]
+    pub hook_id: String,
+
+    /// Hook Metadata
+    pub metadata: HookMetadata,
+
+    /// A list of cron-style definitions to represent a set of moments in (UTC) time.
+    /// If several patterns are specified, a given moment in time represented by
+    /// more than one pattern is considered only to be counted once, in other words
+    /// it is allowed for the cron patterns to overlap; duplicates are redundant.
+    pub schedule: Vec<String>,
+
+    /// Template for the task definition.  This is rendered using [JSON-e](https://taskcluster.github.io/json-e/)
+    /// as described in [firing hooks](/docs/reference/core/hooks/firing-hooks) to produce
+    /// a task definition that is submitted to the Queue service.
+    pub task: Value,
+
+    #[serde(rename = "triggerSchema")]
+    pub trigger_schema: Value,
+}
+
+/// Exchange and RoutingKeyPattern for each binding
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BindingsItem {
+    pub exchange: String,
+
+    #[serde(rename = "routingKeyPattern")]
+    pub routing_key_pattern: String,
+}
+
+/// Hook Metadata
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookMetadata {
+    /// Long-form of the hook's purpose and behavior
+    pub description: String,
+
+    /// Whether to email the owner on an error creating the task.
+    #[serde(rename = "emailOnError", default, skip_serializing_if = "Option::is_none")]
+    pub email_on_error: Option<bool>,
+
+    /// Human readable name of the hook
+    pub name: String,
+
+    /// Email of the person or group responsible for this hook.
+    pub owner: String,
+}
+
+/// A snapshot of the current status of a hook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookStatus {
+    /// Information about the last time this hook fired.  This property is only present
+    /// if the hook has fired at least once.
+    #[serde(rename = "lastFire")]
+    pub last_fire: Value,
+
+    /// The next time this hook's task is scheduled to be created. This property
+    /// is only present if there is a scheduled next time. Some hooks don't have
+    /// any schedules.
+    #[serde(rename = "nextScheduledDate", default, skip_serializing_if = "Option::is_none")]
+    pub next_scheduled_date: Option<DateTime<Utc>>,
+}
+
+/// Definition of a hook that can create tasks at defined times.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateHookRequest {
+    /// List of Bindings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bindings: Option<Vec<BindingsItem>>,
+
+    #[serde(rename = "hookGroupId", default, skip_serializing_if = "Option::is_none")]
+    pub hook_group_id: Option<String>,
+
+    #[serde(rename = "hookId", default, skip_serializing_if = "Option::is_none")]
+    pub hook_id: Option<String>,
+
+    /// Hook Metadata
+    pub metadata: HookMetadata,
+
+    /// Definition of the times at which a hook will result in creation of a task.
+    /// If several patterns are specified, tasks will be created at any time
+    /// specified by one or more patterns.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<Vec<String>>,
+
+    /// Template for the task definition.  This is rendered using [JSON-e](https://taskcluster.github.io/json-e/)
+    /// as described in [firing hooks](/docs/reference/core/hooks/firing-hooks) to produce
+    /// a task definition that is submitted to the Queue service.
+    pub task: Value,
+
+    #[serde(rename = "triggerSchema", default, skip_serializing_if = "Option::is_none")]
+    pub trigger_schema: Option<Value>,
+}
+
+/// Secret token for a trigger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TriggerTokenResponse {
+    /// Token
+    pub token: String,
+}
+
+/// List of lastFires
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListLastFiresResponse {
+    /// LastFires
+    #[serde(rename = "lastFires")]
+    pub last_fires: Vec<ListLastFiresResponseLastFiresItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListLastFiresResponseLastFiresItem {
+    /// The error that occurred when firing the task. This is typically,
+    /// but not always, an API error message.
+    pub error: String,
+
+    #[serde(rename = "firedBy")]
+    pub fired_by: String,
+
+    #[serde(rename = "hookGroupId")]
+    pub hook_group_id: String,
+
+    #[serde(rename = "hookId")]
+    pub hook_id: String,
+
+    /// Information about success or failure of firing of the hook
+    pub result: String,
+
+    /// Time when the task was created
+    #[serde(rename = "taskCreateTime")]
+    pub task_create_time: DateTime<Utc>,
+
+    /// Unique task identifier, this is UUID encoded as
+    /// [URL-safe base64](http://tools.ietf.org/html/rfc4648#section-5) and
+    /// stripped of `=` padding.
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+}
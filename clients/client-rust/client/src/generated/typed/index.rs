@@ -0,0 +1,213 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Index`](crate::Index).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Index {
+    client: crate::Index,
+}
+
+#[allow(non_snake_case)]
+impl Index {
+    /// Create a new typed Index instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Index::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Index {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Index::ping`](crate::Index::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// Find Indexed Task
+    ///
+    /// See [`Index::findTask`](crate::Index::findTask).
+    pub async fn findTask(&self, indexPath: &str) -> Result<IndexedTaskResponse, Error> {
+        let output = self.client.findTask(indexPath).await?;
+        serde_json::from_value(output).context("Invalid findTask response")
+    }
+
+    /// List Namespaces
+    ///
+    /// See [`Index::listNamespaces`](crate::Index::listNamespaces).
+    pub async fn listNamespaces(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListNamespacesResponse, Error> {
+        let output = self.client.listNamespaces(namespace, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listNamespaces response")
+    }
+
+    /// Stream all pages of results from the listNamespaces endpoint, following `continuationToken`
+    pub fn listNamespaces_paginated<'a>(&'a self, namespace: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<ListNamespacesResponse, Error>> + 'a {
+        self.client.listNamespaces_paginated(namespace, limit).map(|page| {
+            serde_json::from_value::<ListNamespacesResponse>(page?).context("Invalid listNamespaces response")
+        })
+    }
+
+    /// List Tasks
+    ///
+    /// See [`Index::listTasks`](crate::Index::listTasks).
+    pub async fn listTasks(&self, namespace: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListTasksResponse, Error> {
+        let output = self.client.listTasks(namespace, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listTasks response")
+    }
+
+    /// Stream all pages of results from the listTasks endpoint, following `continuationToken`
+    pub fn listTasks_paginated<'a>(&'a self, namespace: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<ListTasksResponse, Error>> + 'a {
+        self.client.listTasks_paginated(namespace, limit).map(|page| {
+            serde_json::from_value::<ListTasksResponse>(page?).context("Invalid listTasks response")
+        })
+    }
+
+    /// Insert Task into Index
+    ///
+    /// See [`Index::insertTask`](crate::Index::insertTask).
+    pub async fn insertTask(&self, namespace: &str, payload: &InsertTaskRequest) -> Result<IndexedTaskResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.insertTask(namespace, payload).await?;
+        serde_json::from_value(output).context("Invalid insertTask response")
+    }
+
+    /// Remove Task from Index
+    ///
+    /// See [`Index::deleteTask`](crate::Index::deleteTask).
+    pub async fn deleteTask(&self, namespace: &str) -> Result<(), Error> {
+        self.client.deleteTask(namespace).await
+    }
+
+    /// Get Artifact From Indexed Task
+    ///
+    /// See [`Index::findArtifactFromTask`](crate::Index::findArtifactFromTask).
+    pub async fn findArtifactFromTask(&self, indexPath: &str, name: &str) -> Result<(), Error> {
+        self.client.findArtifactFromTask(indexPath, name).await
+    }
+}
+
+/// Representation of an indexed task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedTaskResponse {
+    /// Data that was reported with the task. This is an arbitrary JSON object.
+    pub data: Value,
+
+    /// Date at which this entry expires from the task index.
+    pub expires: DateTime<Utc>,
+
+    /// Namespace of the indexed task, used to find the indexed task in the index.
+    pub namespace: String,
+
+    /// If multiple tasks are indexed with the same `namespace` the task with the
+    /// highest `rank` will be stored and returned in later requests. If two tasks
+    /// has the same `rank` the latest task will be stored.
+    pub rank: f64,
+
+    /// Unique task identifier, this is UUID encoded as
+    /// [URL-safe base64](http://tools.ietf.org/html/rfc4648#section-5) and
+    /// stripped of `=` padding.
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+}
+
+/// Response from a request to list namespaces within a given namespace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListNamespacesResponse {
+    /// A continuation token is returned if there are more results than listed
+    /// here. You can optionally provide the token in the request payload to
+    /// load the additional results.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of namespaces.
+    pub namespaces: Vec<ListNamespacesResponseNamespacesItem>,
+}
+
+/// Representation of a namespace that contains indexed tasks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListNamespacesResponseNamespacesItem {
+    /// Date at which this entry, and by implication all entries below it,
+    /// expires from the task index.
+    pub expires: DateTime<Utc>,
+
+    /// Name of namespace within it's parent namespace.
+    pub name: String,
+
+    /// Fully qualified name of the namespace, you can use this to list
+    /// namespaces or tasks under this namespace.
+    pub namespace: String,
+}
+
+/// Representation of an indexed task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListTasksResponse {
+    /// A continuation token is returned if there are more results than listed
+    /// here. You can optionally provide the token in the request payload to
+    /// load the additional results.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of tasks.
+    pub tasks: Vec<ListTasksResponseTasksItem>,
+}
+
+/// Representation of a task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListTasksResponseTasksItem {
+    /// Data that was reported with the task. This is an arbitrary JSON
+    /// object.
+    pub data: Value,
+
+    /// Date at which this entry expires from the task index.
+    pub expires: DateTime<Utc>,
+
+    /// Index path of the task.
+    pub namespace: String,
+
+    /// If multiple tasks are indexed with the same `namespace` the task
+    /// with the highest `rank` will be stored and returned in later
+    /// requests. If two tasks has the same `rank` the latest task will be
+    /// stored.
+    pub rank: f64,
+
+    /// Unique task identifier for the task currently indexed at `namespace`.
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+}
+
+/// Representation of the index entry to insert.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InsertTaskRequest {
+    /// This is an arbitrary JSON object. Feel free to put whatever data you want
+    /// here, but do limit it, you'll get errors if you store more than 32KB.
+    /// So stay well, below that limit.
+    pub data: Value,
+
+    /// Date at which this entry expires from the task index.
+    pub expires: DateTime<Utc>,
+
+    /// If multiple tasks are indexed with the same `namespace` the task with the
+    /// highest `rank` will be stored and returned in later requests. If two tasks
+    /// has the same `rank` the latest task will be stored.
+    pub rank: f64,
+
+    /// Unique task identifier, this is UUID encoded as
+    /// [URL-safe base64](http://tools.ietf.org/html/rfc4648#section-5) and
+    /// stripped of `=` padding.
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+}
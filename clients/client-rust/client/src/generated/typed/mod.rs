@@ -0,0 +1,21 @@
+pub mod auth;
+pub mod github;
+pub mod hooks;
+pub mod index;
+pub mod notify;
+pub mod object;
+pub mod purgecache;
+pub mod queue;
+pub mod secrets;
+pub mod workermanager;
+
+pub use auth::Auth;
+pub use github::Github;
+pub use hooks::Hooks;
+pub use index::Index;
+pub use notify::Notify;
+pub use object::Object;
+pub use purgecache::PurgeCache;
+pub use queue::Queue;
+pub use secrets::Secrets;
+pub use workermanager::WorkerManager;
@@ -0,0 +1,227 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Notify`](crate::Notify).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Notify {
+    client: crate::Notify,
+}
+
+#[allow(non_snake_case)]
+impl Notify {
+    /// Create a new typed Notify instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Notify::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Notify {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Notify::ping`](crate::Notify::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// Send an Email
+    ///
+    /// See [`Notify::email`](crate::Notify::email).
+    pub async fn email(&self, payload: &EmailRequest) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.email(payload).await
+    }
+
+    /// Publish a Pulse Message
+    ///
+    /// See [`Notify::pulse`](crate::Notify::pulse).
+    pub async fn pulse(&self, payload: &PulseRequest) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.pulse(payload).await
+    }
+
+    /// Post Matrix Message
+    ///
+    /// See [`Notify::matrix`](crate::Notify::matrix).
+    pub async fn matrix(&self, payload: &MatrixRequest) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.matrix(payload).await
+    }
+
+    /// Post Slack Message
+    ///
+    /// See [`Notify::slack`](crate::Notify::slack).
+    pub async fn slack(&self, payload: &SlackRequest) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.slack(payload).await
+    }
+
+    /// Denylist Given Address
+    ///
+    /// See [`Notify::addDenylistAddress`](crate::Notify::addDenylistAddress).
+    pub async fn addDenylistAddress(&self, payload: &NotificationAddress) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.addDenylistAddress(payload).await
+    }
+
+    /// Delete Denylisted Address
+    ///
+    /// See [`Notify::deleteDenylistAddress`](crate::Notify::deleteDenylistAddress).
+    pub async fn deleteDenylistAddress(&self, payload: &NotificationAddress) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.deleteDenylistAddress(payload).await
+    }
+
+    /// List Denylisted Notifications
+    ///
+    /// See [`Notify::listDenylist`](crate::Notify::listDenylist).
+    pub async fn listDenylist(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<NotificationAddressList, Error> {
+        let output = self.client.listDenylist(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listDenylist response")
+    }
+
+    /// Stream all pages of results from the listDenylist endpoint, following `continuationToken`
+    pub fn listDenylist_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<NotificationAddressList, Error>> + 'a {
+        self.client.listDenylist_paginated(limit).map(|page| {
+            serde_json::from_value::<NotificationAddressList>(page?).context("Invalid listDenylist response")
+        })
+    }
+}
+
+/// Request to send an email
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmailRequest {
+    /// E-mail address to which the message should be sent
+    pub address: String,
+
+    /// Content of the e-mail as **markdown**, will be rendered to HTML before
+    /// the email is sent. Notice that markdown allows for a few HTML tags, but
+    /// won't allow inclusion of script tags and other unpleasantries.
+    pub content: String,
+
+    /// Optional link that can be added as a button to the email.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<EmailRequestLink>,
+
+    /// Reply-to e-mail (this property is optional)
+    #[serde(rename = "replyTo", default, skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+
+    /// Subject line of the e-mail, this is plain-text
+    pub subject: String,
+
+    /// E-mail html template used to format your content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// Optional link that can be added as a button to the email.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmailRequestLink {
+    /// Where the link should point to.
+    pub href: String,
+
+    /// Text to display on link.
+    pub text: String,
+}
+
+/// Request to post a message on pulse.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PulseRequest {
+    /// Pulse message to send as plain text.
+    pub message: Value,
+
+    /// Routing-key to use when posting the message.
+    #[serde(rename = "routingKey")]
+    pub routing_key: String,
+}
+
+/// Request to send a Matrix notice. Many of these fields are better understood by
+/// checking the matrix spec itself. The precise definitions of these fields is
+/// beyond the scope of this document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatrixRequest {
+    /// Unformatted text that will be displayed in the room if you do not
+    /// specify `formattedBody` or if a user's client can not render the format.
+    pub body: String,
+
+    /// The format for `formattedBody`. For instance, `org.matrix.custom.html`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Text that will be rendered by matrix clients that support the given
+    /// format in that format. For instance, `<h1>Header Text</h1>`.
+    #[serde(rename = "formattedBody", default, skip_serializing_if = "Option::is_none")]
+    pub formatted_body: Option<String>,
+
+    /// Which of the `m.room.message` msgtypes to use. At the moment only the
+    /// types that take `body`/`format`/`formattedBody` are supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub msgtype: Option<String>,
+
+    /// The fully qualified room name, such as `!whDRjjSmICCgrhFHsQ:mozilla.org`
+    /// If you are using riot, you can find this under the advanced settings for a room.
+    #[serde(rename = "roomId")]
+    pub room_id: String,
+}
+
+/// Request to send a message to a Slack channel. The most interesting field in
+/// this request is the `blocks` field which allows you to specify advanced
+/// display layout for messages. This is best understood via the Slack API
+/// documentation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlackRequest {
+    /// An array of Slack attachments. See https://api.slack.com/messaging/composing/layouts#attachments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Value>>,
+
+    /// An array of Slack layout blocks. See https://api.slack.com/reference/block-kit/blocks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Value>>,
+
+    /// The unique Slack channel ID, such as `C123456GZ`.
+    /// In the app, this is the last section of the 'copy link' URL for a channel.
+    #[serde(rename = "channelId")]
+    pub channel_id: String,
+
+    /// The main message text. If no blocks are included, this is used as the
+    /// message text, otherwise this is used as alternative text and the blocks
+    /// are used.
+    pub text: String,
+}
+
+/// Type of notification and its corresponding address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationAddress {
+    #[serde(rename = "notificationAddress")]
+    pub notification_address: String,
+
+    #[serde(rename = "notificationType")]
+    pub notification_type: String,
+}
+
+/// List of notification addresses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationAddressList {
+    pub addresses: Vec<NotificationAddress>,
+
+    /// A continuation token is returned if there are more results than listed
+    /// here. You can optionally provide the token in the request payload to
+    /// load the additional results.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
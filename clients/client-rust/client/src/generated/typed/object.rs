@@ -0,0 +1,229 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Object`](crate::Object).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Object {
+    client: crate::Object,
+}
+
+#[allow(non_snake_case)]
+impl Object {
+    /// Create a new typed Object instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Object::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Object {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Object::ping`](crate::Object::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// Begin upload of a new object
+    ///
+    /// See [`Object::createUpload`](crate::Object::createUpload).
+    pub async fn createUpload(&self, name: &str, payload: &CreateUploadRequest) -> Result<CreateUploadResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.createUpload(name, payload).await?;
+        serde_json::from_value(output).context("Invalid createUpload response")
+    }
+
+    /// Mark an upload as complete.
+    ///
+    /// See [`Object::finishUpload`](crate::Object::finishUpload).
+    pub async fn finishUpload(&self, name: &str, payload: &FinishUploadRequest) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.finishUpload(name, payload).await
+    }
+
+    /// Download object data
+    ///
+    /// See [`Object::startDownload`](crate::Object::startDownload).
+    pub async fn startDownload(&self, name: &str, payload: &DownloadObjectRequest) -> Result<Value, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.startDownload(name, payload).await
+    }
+
+    /// Get an object's data
+    ///
+    /// See [`Object::download`](crate::Object::download).
+    pub async fn download(&self, name: &str) -> Result<(), Error> {
+        self.client.download(name).await
+    }
+}
+
+/// A request to begin an upload, containing proposed upload methods to which the
+/// server may agree to or reject.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateUploadRequest {
+    /// Date at which this entry expires from the object table.  The expiration cannot
+    /// be changed in subsequent calls to the same method.
+    pub expires: DateTime<Utc>,
+
+    /// Project identifier.
+    #[serde(rename = "projectId")]
+    pub project_id: String,
+
+    /// Upload methods, with details, that the caller is prepared to execute.  If this
+    /// object is empty, then the server will reject the request but still create the
+    /// upload with the given `uploadId` and `expires`, so any subsequent calls must
+    /// share those values.  The server may choose any of the proposed methods at its
+    /// discretion.
+    #[serde(rename = "proposedUploadMethods")]
+    pub proposed_upload_methods: CreateUploadRequestProposedUploadMethods,
+
+    /// Unique identifier for this upload.   Once an object is created with an uploadId,
+    /// uploads of the same object with different uploadIds will be rejected.  Callers
+    /// should pass a randomly-generated slugid here.
+    #[serde(rename = "uploadId")]
+    pub upload_id: String,
+}
+
+/// Upload methods, with details, that the caller is prepared to execute.  If this
+/// object is empty, then the server will reject the request but still create the
+/// upload with the given `uploadId` and `expires`, so any subsequent calls must
+/// share those values.  The server may choose any of the proposed methods at its
+/// discretion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateUploadRequestProposedUploadMethods {
+    /// Upload data included directly in the request.  The data has a fixed maximum length, so this should
+    /// be used only for value that are known to be of constant, fairly small size to avoid surprises as
+    /// the payload grows. In general, this is useful for testing and for metadata objects such as
+    /// separate cryptographic signatures.
+    #[serde(rename = "dataInline", default, skip_serializing_if = "Option::is_none")]
+    pub data_inline: Option<UploadMethodDataInlineRequest>,
+
+    /// Request a URL to which a PUT request can be made.
+    #[serde(rename = "putUrl", default, skip_serializing_if = "Option::is_none")]
+    pub put_url: Option<UploadMethodPutUrlRequest>,
+}
+
+/// Upload data included directly in the request.  The data has a fixed maximum length, so this should
+/// be used only for value that are known to be of constant, fairly small size to avoid surprises as
+/// the payload grows. In general, this is useful for testing and for metadata objects such as
+/// separate cryptographic signatures.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadMethodDataInlineRequest {
+    /// Content-type to be returned when downloading this data
+    #[serde(rename = "contentType")]
+    pub content_type: String,
+
+    /// Base64-encoded byte data, with decoded size at most 8k.
+    #[serde(rename = "objectData")]
+    pub object_data: String,
+}
+
+/// Request a URL to which a PUT request can be made.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadMethodPutUrlRequest {
+    /// Length, in bytes, of the uploaded data.
+    #[serde(rename = "contentLength")]
+    pub content_length: i64,
+
+    /// Content-type of the data to be uploaded.
+    #[serde(rename = "contentType")]
+    pub content_type: String,
+}
+
+/// A response from the `createUpload` method, either agreeing to an upload method or
+/// rejecting the proposal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateUploadResponse {
+    /// Expiration
+    pub expires: DateTime<Utc>,
+
+    /// Project Identifier
+    #[serde(rename = "projectId")]
+    pub project_id: String,
+
+    /// Upload ID
+    #[serde(rename = "uploadId")]
+    pub upload_id: String,
+
+    /// The selected upload method, from those contained in the request.  At most one
+    /// property will be set, indicating the selected method.  If no properties are set,
+    /// then none of the proposed methods were selected.
+    #[serde(rename = "uploadMethod")]
+    pub upload_method: CreateUploadResponseUploadMethod,
+}
+
+/// The selected upload method, from those contained in the request.  At most one
+/// property will be set, indicating the selected method.  If no properties are set,
+/// then none of the proposed methods were selected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateUploadResponseUploadMethod {
+    /// Indication that the data has been uploaded.
+    #[serde(rename = "dataInline", default, skip_serializing_if = "Option::is_none")]
+    pub data_inline: Option<bool>,
+
+    /// Response containing a URL to which to PUT the data.
+    #[serde(rename = "putUrl", default, skip_serializing_if = "Option::is_none")]
+    pub put_url: Option<UploadMethodPutUrlResponse>,
+}
+
+/// Response containing a URL to which to PUT the data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadMethodPutUrlResponse {
+    /// Expiration time for the URL.  After this time, the client must
+    /// call `createUpload` again to get a fresh URL.
+    pub expires: DateTime<Utc>,
+
+    /// Headers which must be included with the PUT request.  In many
+    /// cases, these are included in a signature embedded in the URL,
+    /// and must be provided verbatim.
+    pub headers: HashMap<String, String>,
+
+    /// URL to which a PUT request should be made.
+    pub url: String,
+}
+
+/// Finish Upload Request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinishUploadRequest {
+    /// Project identifier.
+    #[serde(rename = "projectId")]
+    pub project_id: String,
+
+    /// Unique identifier for this upload.
+    #[serde(rename = "uploadId")]
+    pub upload_id: String,
+}
+
+/// See [Download Methods](https://docs.taskcluster.net/docs/docs/reference/platform/object/download-methods) for details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DownloadObjectRequest {
+    /// Download methods that the caller can suport, together with parameters for each method.
+    /// The server will choose one method and make the corresponding response.
+    #[serde(rename = "acceptDownloadMethods")]
+    pub accept_download_methods: DownloadObjectRequestAcceptDownloadMethods,
+}
+
+/// Download methods that the caller can suport, together with parameters for each method.
+/// The server will choose one method and make the corresponding response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DownloadObjectRequestAcceptDownloadMethods {
+    #[serde(rename = "HTTP:GET", default, skip_serializing_if = "Option::is_none")]
+    pub http_get: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simple: Option<bool>,
+}
@@ -0,0 +1,118 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`PurgeCache`](crate::PurgeCache).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct PurgeCache {
+    client: crate::PurgeCache,
+}
+
+#[allow(non_snake_case)]
+impl PurgeCache {
+    /// Create a new typed PurgeCache instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::PurgeCache::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::PurgeCache {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`PurgeCache::ping`](crate::PurgeCache::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// Purge Worker Cache
+    ///
+    /// See [`PurgeCache::purgeCache`](crate::PurgeCache::purgeCache).
+    pub async fn purgeCache(&self, workerPoolId: &str, payload: &PurgeCacheRequest) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.purgeCache(workerPoolId, payload).await
+    }
+
+    /// All Open Purge Requests
+    ///
+    /// See [`PurgeCache::allPurgeRequests`](crate::PurgeCache::allPurgeRequests).
+    pub async fn allPurgeRequests(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<AllPurgeCacheRequestList, Error> {
+        let output = self.client.allPurgeRequests(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid allPurgeRequests response")
+    }
+
+    /// Stream all pages of results from the allPurgeRequests endpoint, following `continuationToken`
+    pub fn allPurgeRequests_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<AllPurgeCacheRequestList, Error>> + 'a {
+        self.client.allPurgeRequests_paginated(limit).map(|page| {
+            serde_json::from_value::<AllPurgeCacheRequestList>(page?).context("Invalid allPurgeRequests response")
+        })
+    }
+
+    /// Open Purge Requests for a worker pool
+    ///
+    /// See [`PurgeCache::purgeRequests`](crate::PurgeCache::purgeRequests).
+    pub async fn purgeRequests(&self, workerPoolId: &str, since: Option<&str>) -> Result<PurgeCacheRequestList, Error> {
+        let output = self.client.purgeRequests(workerPoolId, since).await?;
+        serde_json::from_value(output).context("Invalid purgeRequests response")
+    }
+}
+
+/// Request that a message be published to purge a specific cache.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PurgeCacheRequest {
+    /// Name of cache to purge. Notice that if a `workerType` have multiple kinds
+    /// of caches (with independent names), it should purge all caches identified
+    /// by `cacheName` regardless of cache type.
+    #[serde(rename = "cacheName")]
+    pub cache_name: String,
+}
+
+/// A list of currently open purge-cache requests. Should not be used by workers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AllPurgeCacheRequestList {
+    /// Passed back from Azure to allow us to page through long result sets.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// A list of Purge Cache requests that the Purge Cache service has previously received.
+    pub requests: Vec<PurgeCacheRequestsItem>,
+}
+
+/// An entry in a list of Purge Cache Requests that the Purge Cache service has previously received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PurgeCacheRequestsItem {
+    /// All caches that match this provisionerId, workerType, and cacheName must be destroyed if they were created _before_ this time.
+    pub before: DateTime<Utc>,
+
+    /// Name of cache to purge.
+    #[serde(rename = "cacheName")]
+    pub cache_name: String,
+
+    /// ProvisionerId associated with the workerType.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// Workertype cache exists on.
+    #[serde(rename = "workerType")]
+    pub worker_type: String,
+}
+
+/// A list of currently open purge-cache requests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PurgeCacheRequestList {
+    /// A list of Purge Cache requests that the Purge Cache service has previously received.
+    pub requests: Vec<PurgeCacheRequestsItem>,
+}
@@ -0,0 +1,1581 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Queue`](crate::Queue).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Queue {
+    client: crate::Queue,
+}
+
+#[allow(non_snake_case)]
+impl Queue {
+    /// Create a new typed Queue instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Queue::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Queue {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Queue::ping`](crate::Queue::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// Get Task Definition
+    ///
+    /// See [`Queue::task`](crate::Queue::task).
+    pub async fn task(&self, taskId: &str) -> Result<Task, Error> {
+        let output = self.client.task(taskId).await?;
+        serde_json::from_value(output).context("Invalid task response")
+    }
+
+    /// Get task status
+    ///
+    /// See [`Queue::status`](crate::Queue::status).
+    pub async fn status(&self, taskId: &str) -> Result<TaskStatusResponse, Error> {
+        let output = self.client.status(taskId).await?;
+        serde_json::from_value(output).context("Invalid status response")
+    }
+
+    /// List Task Group
+    ///
+    /// See [`Queue::listTaskGroup`](crate::Queue::listTaskGroup).
+    pub async fn listTaskGroup(&self, taskGroupId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListTaskGroupResponse, Error> {
+        let output = self.client.listTaskGroup(taskGroupId, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listTaskGroup response")
+    }
+
+    /// Stream all pages of results from the listTaskGroup endpoint, following `continuationToken`
+    pub fn listTaskGroup_paginated<'a>(&'a self, taskGroupId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<ListTaskGroupResponse, Error>> + 'a {
+        self.client.listTaskGroup_paginated(taskGroupId, limit).map(|page| {
+            serde_json::from_value::<ListTaskGroupResponse>(page?).context("Invalid listTaskGroup response")
+        })
+    }
+
+    /// List Dependent Tasks
+    ///
+    /// See [`Queue::listDependentTasks`](crate::Queue::listDependentTasks).
+    pub async fn listDependentTasks(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListDependentTasksResponse, Error> {
+        let output = self.client.listDependentTasks(taskId, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listDependentTasks response")
+    }
+
+    /// Stream all pages of results from the listDependentTasks endpoint, following `continuationToken`
+    pub fn listDependentTasks_paginated<'a>(&'a self, taskId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<ListDependentTasksResponse, Error>> + 'a {
+        self.client.listDependentTasks_paginated(taskId, limit).map(|page| {
+            serde_json::from_value::<ListDependentTasksResponse>(page?).context("Invalid listDependentTasks response")
+        })
+    }
+
+    /// Create New Task
+    ///
+    /// See [`Queue::createTask`](crate::Queue::createTask).
+    pub async fn createTask(&self, taskId: &str, payload: &CreateTaskRequest) -> Result<TaskStatusResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.createTask(taskId, payload).await?;
+        serde_json::from_value(output).context("Invalid createTask response")
+    }
+
+    /// Schedule Defined Task
+    ///
+    /// See [`Queue::scheduleTask`](crate::Queue::scheduleTask).
+    pub async fn scheduleTask(&self, taskId: &str) -> Result<TaskStatusResponse, Error> {
+        let output = self.client.scheduleTask(taskId).await?;
+        serde_json::from_value(output).context("Invalid scheduleTask response")
+    }
+
+    /// Rerun a Resolved Task
+    ///
+    /// See [`Queue::rerunTask`](crate::Queue::rerunTask).
+    pub async fn rerunTask(&self, taskId: &str) -> Result<TaskStatusResponse, Error> {
+        let output = self.client.rerunTask(taskId).await?;
+        serde_json::from_value(output).context("Invalid rerunTask response")
+    }
+
+    /// Cancel Task
+    ///
+    /// See [`Queue::cancelTask`](crate::Queue::cancelTask).
+    pub async fn cancelTask(&self, taskId: &str) -> Result<TaskStatusResponse, Error> {
+        let output = self.client.cancelTask(taskId).await?;
+        serde_json::from_value(output).context("Invalid cancelTask response")
+    }
+
+    /// Claim Work
+    ///
+    /// See [`Queue::claimWork`](crate::Queue::claimWork).
+    pub async fn claimWork(&self, taskQueueId: &str, payload: &ClaimWorkRequest) -> Result<ClaimWorkResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.claimWork(taskQueueId, payload).await?;
+        serde_json::from_value(output).context("Invalid claimWork response")
+    }
+
+    /// Claim Task
+    ///
+    /// See [`Queue::claimTask`](crate::Queue::claimTask).
+    pub async fn claimTask(&self, taskId: &str, runId: &str, payload: &TaskClaimRequest) -> Result<TaskClaimResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.claimTask(taskId, runId, payload).await?;
+        serde_json::from_value(output).context("Invalid claimTask response")
+    }
+
+    /// Reclaim task
+    ///
+    /// See [`Queue::reclaimTask`](crate::Queue::reclaimTask).
+    pub async fn reclaimTask(&self, taskId: &str, runId: &str) -> Result<TaskReclaimResponse, Error> {
+        let output = self.client.reclaimTask(taskId, runId).await?;
+        serde_json::from_value(output).context("Invalid reclaimTask response")
+    }
+
+    /// Report Run Completed
+    ///
+    /// See [`Queue::reportCompleted`](crate::Queue::reportCompleted).
+    pub async fn reportCompleted(&self, taskId: &str, runId: &str) -> Result<TaskStatusResponse, Error> {
+        let output = self.client.reportCompleted(taskId, runId).await?;
+        serde_json::from_value(output).context("Invalid reportCompleted response")
+    }
+
+    /// Report Run Failed
+    ///
+    /// See [`Queue::reportFailed`](crate::Queue::reportFailed).
+    pub async fn reportFailed(&self, taskId: &str, runId: &str) -> Result<TaskStatusResponse, Error> {
+        let output = self.client.reportFailed(taskId, runId).await?;
+        serde_json::from_value(output).context("Invalid reportFailed response")
+    }
+
+    /// Report Task Exception
+    ///
+    /// See [`Queue::reportException`](crate::Queue::reportException).
+    pub async fn reportException(&self, taskId: &str, runId: &str, payload: &TaskExceptionRequest) -> Result<TaskStatusResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.reportException(taskId, runId, payload).await?;
+        serde_json::from_value(output).context("Invalid reportException response")
+    }
+
+    /// Create Artifact
+    ///
+    /// See [`Queue::createArtifact`](crate::Queue::createArtifact).
+    pub async fn createArtifact(&self, taskId: &str, runId: &str, name: &str, payload: &Value) -> Result<Value, Error> {
+        self.client.createArtifact(taskId, runId, name, payload).await
+    }
+
+    /// Get Artifact Data from Run
+    ///
+    /// See [`Queue::getArtifact`](crate::Queue::getArtifact).
+    pub async fn getArtifact(&self, taskId: &str, runId: &str, name: &str) -> Result<GetArtifactResponse, Error> {
+        let output = self.client.getArtifact(taskId, runId, name).await?;
+        serde_json::from_value(output).context("Invalid getArtifact response")
+    }
+
+    /// Get Artifact Data from Latest Run
+    ///
+    /// See [`Queue::getLatestArtifact`](crate::Queue::getLatestArtifact).
+    pub async fn getLatestArtifact(&self, taskId: &str, name: &str) -> Result<GetArtifactResponse, Error> {
+        let output = self.client.getLatestArtifact(taskId, name).await?;
+        serde_json::from_value(output).context("Invalid getLatestArtifact response")
+    }
+
+    /// Get Artifacts from Run
+    ///
+    /// See [`Queue::listArtifacts`](crate::Queue::listArtifacts).
+    pub async fn listArtifacts(&self, taskId: &str, runId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListArtifactsResponse, Error> {
+        let output = self.client.listArtifacts(taskId, runId, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listArtifacts response")
+    }
+
+    /// Stream all pages of results from the listArtifacts endpoint, following `continuationToken`
+    pub fn listArtifacts_paginated<'a>(&'a self, taskId: &'a str, runId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<ListArtifactsResponse, Error>> + 'a {
+        self.client.listArtifacts_paginated(taskId, runId, limit).map(|page| {
+            serde_json::from_value::<ListArtifactsResponse>(page?).context("Invalid listArtifacts response")
+        })
+    }
+
+    /// Get Artifacts from Latest Run
+    ///
+    /// See [`Queue::listLatestArtifacts`](crate::Queue::listLatestArtifacts).
+    pub async fn listLatestArtifacts(&self, taskId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListArtifactsResponse, Error> {
+        let output = self.client.listLatestArtifacts(taskId, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listLatestArtifacts response")
+    }
+
+    /// Stream all pages of results from the listLatestArtifacts endpoint, following `continuationToken`
+    pub fn listLatestArtifacts_paginated<'a>(&'a self, taskId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<ListArtifactsResponse, Error>> + 'a {
+        self.client.listLatestArtifacts_paginated(taskId, limit).map(|page| {
+            serde_json::from_value::<ListArtifactsResponse>(page?).context("Invalid listLatestArtifacts response")
+        })
+    }
+
+    /// Get Artifact Information From Run
+    ///
+    /// See [`Queue::artifactInfo`](crate::Queue::artifactInfo).
+    pub async fn artifactInfo(&self, taskId: &str, runId: &str, name: &str) -> Result<ArtifactResponse, Error> {
+        let output = self.client.artifactInfo(taskId, runId, name).await?;
+        serde_json::from_value(output).context("Invalid artifactInfo response")
+    }
+
+    /// Get Artifact Information From Latest Run
+    ///
+    /// See [`Queue::latestArtifactInfo`](crate::Queue::latestArtifactInfo).
+    pub async fn latestArtifactInfo(&self, taskId: &str, name: &str) -> Result<ArtifactResponse, Error> {
+        let output = self.client.latestArtifactInfo(taskId, name).await?;
+        serde_json::from_value(output).context("Invalid latestArtifactInfo response")
+    }
+
+    /// Get Artifact Content From Run
+    ///
+    /// See [`Queue::artifact`](crate::Queue::artifact).
+    pub async fn artifact(&self, taskId: &str, runId: &str, name: &str) -> Result<Value, Error> {
+        self.client.artifact(taskId, runId, name).await
+    }
+
+    /// Get Artifact Content From Latest Run
+    ///
+    /// See [`Queue::latestArtifact`](crate::Queue::latestArtifact).
+    pub async fn latestArtifact(&self, taskId: &str, name: &str) -> Result<Value, Error> {
+        self.client.latestArtifact(taskId, name).await
+    }
+
+    /// Get a list of all active provisioners
+    ///
+    /// See [`Queue::listProvisioners`](crate::Queue::listProvisioners).
+    pub async fn listProvisioners(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListProvisionersResponse, Error> {
+        let output = self.client.listProvisioners(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listProvisioners response")
+    }
+
+    /// Stream all pages of results from the listProvisioners endpoint, following `continuationToken`
+    pub fn listProvisioners_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<ListProvisionersResponse, Error>> + 'a {
+        self.client.listProvisioners_paginated(limit).map(|page| {
+            serde_json::from_value::<ListProvisionersResponse>(page?).context("Invalid listProvisioners response")
+        })
+    }
+
+    /// Get an active provisioner
+    ///
+    /// See [`Queue::getProvisioner`](crate::Queue::getProvisioner).
+    pub async fn getProvisioner(&self, provisionerId: &str) -> Result<ProvisionerResponse, Error> {
+        let output = self.client.getProvisioner(provisionerId).await?;
+        serde_json::from_value(output).context("Invalid getProvisioner response")
+    }
+
+    /// Update a provisioner
+    ///
+    /// See [`Queue::declareProvisioner`](crate::Queue::declareProvisioner).
+    pub async fn declareProvisioner(&self, provisionerId: &str, payload: &UpdateProvisionerRequest) -> Result<ProvisionerResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.declareProvisioner(provisionerId, payload).await?;
+        serde_json::from_value(output).context("Invalid declareProvisioner response")
+    }
+
+    /// Get Number of Pending Tasks
+    ///
+    /// See [`Queue::pendingTasks`](crate::Queue::pendingTasks).
+    pub async fn pendingTasks(&self, taskQueueId: &str) -> Result<PendingTasksResponse, Error> {
+        let output = self.client.pendingTasks(taskQueueId).await?;
+        serde_json::from_value(output).context("Invalid pendingTasks response")
+    }
+
+    /// Get a list of all active worker-types
+    ///
+    /// See [`Queue::listWorkerTypes`](crate::Queue::listWorkerTypes).
+    pub async fn listWorkerTypes(&self, provisionerId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListWorkertypesResponse, Error> {
+        let output = self.client.listWorkerTypes(provisionerId, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listWorkerTypes response")
+    }
+
+    /// Stream all pages of results from the listWorkerTypes endpoint, following `continuationToken`
+    pub fn listWorkerTypes_paginated<'a>(&'a self, provisionerId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<ListWorkertypesResponse, Error>> + 'a {
+        self.client.listWorkerTypes_paginated(provisionerId, limit).map(|page| {
+            serde_json::from_value::<ListWorkertypesResponse>(page?).context("Invalid listWorkerTypes response")
+        })
+    }
+
+    /// Get a worker-type
+    ///
+    /// See [`Queue::getWorkerType`](crate::Queue::getWorkerType).
+    pub async fn getWorkerType(&self, provisionerId: &str, workerType: &str) -> Result<WorkertypeResponse, Error> {
+        let output = self.client.getWorkerType(provisionerId, workerType).await?;
+        serde_json::from_value(output).context("Invalid getWorkerType response")
+    }
+
+    /// Update a worker-type
+    ///
+    /// See [`Queue::declareWorkerType`](crate::Queue::declareWorkerType).
+    pub async fn declareWorkerType(&self, provisionerId: &str, workerType: &str, payload: &UpdateWorkertypeRequest) -> Result<WorkertypeResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.declareWorkerType(provisionerId, workerType, payload).await?;
+        serde_json::from_value(output).context("Invalid declareWorkerType response")
+    }
+
+    /// Get a list of all active task queues
+    ///
+    /// See [`Queue::listTaskQueues`](crate::Queue::listTaskQueues).
+    pub async fn listTaskQueues(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ListTaskqueuesResponse, Error> {
+        let output = self.client.listTaskQueues(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listTaskQueues response")
+    }
+
+    /// Stream all pages of results from the listTaskQueues endpoint, following `continuationToken`
+    pub fn listTaskQueues_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<ListTaskqueuesResponse, Error>> + 'a {
+        self.client.listTaskQueues_paginated(limit).map(|page| {
+            serde_json::from_value::<ListTaskqueuesResponse>(page?).context("Invalid listTaskQueues response")
+        })
+    }
+
+    /// Get a task queue
+    ///
+    /// See [`Queue::getTaskQueue`](crate::Queue::getTaskQueue).
+    pub async fn getTaskQueue(&self, taskQueueId: &str) -> Result<TaskqueueResponse, Error> {
+        let output = self.client.getTaskQueue(taskQueueId).await?;
+        serde_json::from_value(output).context("Invalid getTaskQueue response")
+    }
+
+    /// Get a list of all active workers of a workerType
+    ///
+    /// See [`Queue::listWorkers`](crate::Queue::listWorkers).
+    pub async fn listWorkers(&self, provisionerId: &str, workerType: &str, continuationToken: Option<&str>, limit: Option<&str>, quarantined: Option<&str>) -> Result<ListWorkersResponse, Error> {
+        let output = self.client.listWorkers(provisionerId, workerType, continuationToken, limit, quarantined).await?;
+        serde_json::from_value(output).context("Invalid listWorkers response")
+    }
+
+    /// Stream all pages of results from the listWorkers endpoint, following `continuationToken`
+    pub fn listWorkers_paginated<'a>(&'a self, provisionerId: &'a str, workerType: &'a str, limit: Option<&'a str>, quarantined: Option<&'a str>) -> impl Stream<Item = Result<ListWorkersResponse, Error>> + 'a {
+        self.client.listWorkers_paginated(provisionerId, workerType, limit, quarantined).map(|page| {
+            serde_json::from_value::<ListWorkersResponse>(page?).context("Invalid listWorkers response")
+        })
+    }
+
+    /// Get a worker-type
+    ///
+    /// See [`Queue::getWorker`](crate::Queue::getWorker).
+    pub async fn getWorker(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str) -> Result<WorkerResponse, Error> {
+        let output = self.client.getWorker(provisionerId, workerType, workerGroup, workerId).await?;
+        serde_json::from_value(output).context("Invalid getWorker response")
+    }
+
+    /// Quarantine a worker
+    ///
+    /// See [`Queue::quarantineWorker`](crate::Queue::quarantineWorker).
+    pub async fn quarantineWorker(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str, payload: &QuarantineWorkerRequest) -> Result<WorkerResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.quarantineWorker(provisionerId, workerType, workerGroup, workerId, payload).await?;
+        serde_json::from_value(output).context("Invalid quarantineWorker response")
+    }
+
+    /// Declare a worker
+    ///
+    /// See [`Queue::declareWorker`](crate::Queue::declareWorker).
+    pub async fn declareWorker(&self, provisionerId: &str, workerType: &str, workerGroup: &str, workerId: &str, payload: &UpdateWorkerRequest) -> Result<WorkerResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.declareWorker(provisionerId, workerType, workerGroup, workerId, payload).await?;
+        serde_json::from_value(output).context("Invalid declareWorker response")
+    }
+}
+
+/// Definition of a task that can be scheduled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Task {
+    /// Creation time of task
+    pub created: DateTime<Utc>,
+
+    /// Deadline of the task, by which this task must be complete. `pending` and
+    /// `running` runs are resolved as **exception** if not resolved by other means
+    /// before the deadline. After the deadline, a task is immutable. Note,
+    /// deadline cannot be more than 5 days into the future
+    pub deadline: DateTime<Utc>,
+
+    /// List of dependent tasks. These must either be _completed_ or _resolved_
+    /// before this task is scheduled. See `requires` for semantics.
+    pub dependencies: Vec<String>,
+
+    /// Task expiration, time at which task definition and status is deleted.
+    /// Notice that all artifacts for the task must have an expiration that is no
+    /// later than this. If this property isn't it will be set to `deadline`
+    /// plus one year (this default may change).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<DateTime<Utc>>,
+
+    /// Object with properties that can hold any kind of extra data that should be
+    /// associated with the task. This can be data for the task which doesn't
+    /// fit into `payload`, or it can supplementary data for use in services
+    /// listening for events from this task. For example this could be details to
+    /// display on dashboard, or information for indexing the task. Please, try
+    /// to put all related information under one property, so `extra` data keys
+    /// don't conflict.  **Warning**, do not stuff large data-sets in here --
+    /// task definitions should not take-up multiple MiBs.
+    pub extra: Value,
+
+    /// Required task metadata
+    pub metadata: TaskMetadata,
+
+    /// Task-specific payload following worker-specific format.
+    /// Refer to the documentation for the worker implementing
+    /// `<provisionerId>/<workerType>` for details.
+    pub payload: Value,
+
+    /// Priority of task. This defaults to `lowest` and the scope
+    /// `queue:create-task:<priority>/<provisionerId>/<workerType>` is required
+    /// to define a task with `<priority>`. The `normal` priority is treated as
+    /// `lowest`.
+    pub priority: String,
+
+    /// The name for the "project" with which this task is associated.  This
+    /// value can be used to control permission to manipulate tasks as well as
+    /// for usage reporting.  Project ids are typically simple identifiers,
+    /// optionally in a hierarchical namespace separated by `/` characters.
+    /// This value defaults to `none`.
+    #[serde(rename = "projectId", default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// The tasks relation to its dependencies. This property specifies the
+    /// semantics of the `task.dependencies` property.
+    /// If `all-completed` is given the task will be scheduled when all
+    /// dependencies are resolved _completed_ (successful resolution).
+    /// If `all-resolved` is given the task will be scheduled when all dependencies
+    /// have been resolved, regardless of what their resolution is.
+    pub requires: String,
+
+    /// Number of times to retry the task in case of infrastructure issues.
+    /// An _infrastructure issue_ is a worker node that crashes or is shutdown,
+    /// these events are to be expected.
+    pub retries: i64,
+
+    /// List of task-specific routes. Pulse messages about the task will be CC'ed to
+    /// `route.<value>` for each `<value>` in this array.
+    pub routes: Vec<String>,
+
+    /// All tasks in a task group must have the same `schedulerId`. This is used for several purposes:
+    #[serde(rename = "schedulerId")]
+    pub scheduler_id: String,
+
+    /// List of scopes that the task is authorized to use during its execution.
+    pub scopes: Vec<String>,
+
+    /// Arbitrary key-value tags (only strings limited to 4k). These can be used
+    /// to attach informal metadata to a task. Use this for informal tags that
+    /// tasks can be classified by. You can also think of strings here as
+    /// candidates for formal metadata. Something like
+    /// `purpose: 'build' || 'test'` is a good example.
+    pub tags: HashMap<String, String>,
+
+    /// Identifier for a group of tasks scheduled together with this task.
+    /// Generally, all tasks related to a single event such as a version-control
+    /// push or a nightly build have the same `taskGroupId`.  This property
+    /// defaults to `taskId` if it isn't specified.  Tasks with `taskId` equal to
+    /// the `taskGroupId` are, [by convention](/docs/manual/using/task-graph),
+    /// decision tasks.
+    #[serde(rename = "taskGroupId")]
+    pub task_group_id: String,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId")]
+    pub task_queue_id: String,
+
+    /// Unique identifier for a worker-type within a specific
+    /// provisioner. Deprecation is planned for this property as it will
+    /// be replaced, together with `provisionerId`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "workerType")]
+    pub worker_type: String,
+}
+
+/// Required task metadata
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskMetadata {
+    /// Human readable description of the task, please **explain** what the
+    /// task does. A few lines of documentation is not going to hurt you.
+    pub description: String,
+
+    /// Human readable name of task, used to very briefly given an idea about
+    /// what the task does.
+    pub name: String,
+
+    /// Entity who caused this task, not necessarily a person with email who did
+    /// `hg push` as it could be automation bots as well. The entity we should
+    /// contact to ask why this task is here.
+    pub owner: String,
+
+    /// Link to source of this task, should specify a file, revision and
+    /// repository. This should be place someone can go an do a git/hg blame
+    /// to who came up with recipe for this task.
+    pub source: String,
+}
+
+/// Response to a task status request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskStatusResponse {
+    /// A representation of **task status** as known by the queue
+    pub status: TaskStatus,
+}
+
+/// A representation of **task status** as known by the queue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskStatus {
+    /// Deadline of the task, by which this task must be complete. `pending` and
+    /// `running` runs are resolved as **exception** if not resolved by other means
+    /// before the deadline. After the deadline, a task is immutable. Note,
+    /// deadline cannot be more than 5 days into the future
+    pub deadline: DateTime<Utc>,
+
+    /// Task expiration, time at which task definition and
+    /// status is deleted. Notice that all artifacts for the task
+    /// must have an expiration that is no later than this.
+    pub expires: DateTime<Utc>,
+
+    /// The name for the "project" with which this task is associated.  This
+    /// value can be used to control permission to manipulate tasks as well as
+    /// for usage reporting.  Project ids are typically simple identifiers,
+    /// optionally in a hierarchical namespace separated by `/` characters.
+    /// This value defaults to `none`.
+    #[serde(rename = "projectId")]
+    pub project_id: String,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// Number of retries left for the task in case of infrastructure issues
+    #[serde(rename = "retriesLeft")]
+    pub retries_left: i64,
+
+    /// List of runs, ordered so that index `i` has `runId == i`
+    pub runs: Vec<TaskStatusRunsItem>,
+
+    /// All tasks in a task group must have the same `schedulerId`. This is used for several purposes:
+    #[serde(rename = "schedulerId")]
+    pub scheduler_id: String,
+
+    /// State of this task. This is just an auxiliary property derived from state
+    /// of latests run, or `unscheduled` if none.
+    pub state: String,
+
+    /// Identifier for a group of tasks scheduled together with this task.
+    /// Generally, all tasks related to a single event such as a version-control
+    /// push or a nightly build have the same `taskGroupId`.  This property
+    /// defaults to `taskId` if it isn't specified.  Tasks with `taskId` equal to
+    /// the `taskGroupId` are, [by convention](/docs/manual/using/task-graph),
+    /// decision tasks.
+    #[serde(rename = "taskGroupId")]
+    pub task_group_id: String,
+
+    /// Unique task identifier, this is UUID encoded as
+    /// [URL-safe base64](http://tools.ietf.org/html/rfc4648#section-5) and
+    /// stripped of `=` padding.
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId")]
+    pub task_queue_id: String,
+
+    /// Unique identifier for a worker-type within a specific
+    /// provisioner. Deprecation is planned for this property as it will
+    /// be replaced, together with `provisionerId`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "workerType")]
+    pub worker_type: String,
+}
+
+/// JSON object with information about a run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskStatusRunsItem {
+    /// Reason for the creation of this run,
+    /// **more reasons may be added in the future**.
+    #[serde(rename = "reasonCreated")]
+    pub reason_created: String,
+
+    /// Reason that run was resolved, this is mainly
+    /// useful for runs resolved as `exception`.
+    /// Note, **more reasons may be added in the future**, also this
+    /// property is only available after the run is resolved. Some of these
+    /// reasons, notably `intermittent-task`, `worker-shutdown`, and
+    /// `claim-expired`, will trigger an automatic retry of the task.
+    /// Note that 'superseded' is here only for compatibility, as that
+    /// functionality has been removed.
+    #[serde(rename = "reasonResolved", default, skip_serializing_if = "Option::is_none")]
+    pub reason_resolved: Option<String>,
+
+    /// Date-time at which this run was resolved, ie. when the run changed
+    /// state from `running` to either `completed`, `failed` or `exception`.
+    /// This property is only present after the run as been resolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<DateTime<Utc>>,
+
+    /// Id of this task run, `run-id`s always starts from `0`
+    #[serde(rename = "runId")]
+    pub run_id: i64,
+
+    /// Date-time at which this run was scheduled, ie. when the run was
+    /// created in state `pending`.
+    pub scheduled: DateTime<Utc>,
+
+    /// Date-time at which this run was claimed, ie. when the run changed
+    /// state from `pending` to `running`. This property is only present
+    /// after the run has been claimed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started: Option<DateTime<Utc>>,
+
+    /// State of this run
+    pub state: String,
+
+    /// Time at which the run expires and is resolved as `failed`, if the
+    /// run isn't reclaimed. Note, only present after the run has been
+    /// claimed.
+    #[serde(rename = "takenUntil", default, skip_serializing_if = "Option::is_none")]
+    pub taken_until: Option<DateTime<Utc>>,
+
+    /// Identifier for group that worker who executes this run is a part of,
+    /// this identifier is mainly used for efficient routing.
+    /// Note, this property is only present after the run is claimed.
+    #[serde(rename = "workerGroup", default, skip_serializing_if = "Option::is_none")]
+    pub worker_group: Option<String>,
+
+    /// Identifier for worker evaluating this run within given
+    /// `workerGroup`. Note, this property is only available after the run
+    /// has been claimed.
+    #[serde(rename = "workerId", default, skip_serializing_if = "Option::is_none")]
+    pub worker_id: Option<String>,
+}
+
+/// Response from a `listTaskGroup` request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListTaskGroupResponse {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of tasks in the task-group.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listTaskGroup` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// Identifier for the task-group being listed.
+    #[serde(rename = "taskGroupId")]
+    pub task_group_id: String,
+
+    /// List of tasks in this task-group.
+    pub tasks: Vec<TaskDefinitionAndStatus>,
+}
+
+/// Task Definition and task status structure.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskDefinitionAndStatus {
+    /// A representation of **task status** as known by the queue
+    pub status: TaskStatus,
+
+    /// Definition of a task that can be scheduled
+    pub task: Task,
+}
+
+/// Response from a `listDependentTasks` request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListDependentTasksResponse {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of dependent tasks.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listDependentTasks` with
+    /// `continuationToken` until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// Identifier for the task whose dependents are being listed.
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+
+    /// List of tasks that have `taskId` in the `task.dependencies` property.
+    pub tasks: Vec<TaskDefinitionAndStatus>,
+}
+
+/// Definition of a task that can be scheduled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateTaskRequest {
+    /// Creation time of task
+    pub created: DateTime<Utc>,
+
+    /// Deadline of the task, by which this task must be complete. `pending` and
+    /// `running` runs are resolved as **exception** if not resolved by other means
+    /// before the deadline. After the deadline, a task is immutable. Note,
+    /// deadline cannot be more than 5 days into the future
+    pub deadline: DateTime<Utc>,
+
+    /// List of dependent tasks. These must either be _completed_ or _resolved_
+    /// before this task is scheduled. See `requires` for semantics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<Vec<String>>,
+
+    /// Task expiration, time at which task definition and status is deleted.
+    /// Notice that all artifacts for the task must have an expiration that is no
+    /// later than this. If this property isn't it will be set to `deadline`
+    /// plus one year (this default may change).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<DateTime<Utc>>,
+
+    /// Object with properties that can hold any kind of extra data that should be
+    /// associated with the task. This can be data for the task which doesn't
+    /// fit into `payload`, or it can supplementary data for use in services
+    /// listening for events from this task. For example this could be details to
+    /// display on dashboard, or information for indexing the task. Please, try
+    /// to put all related information under one property, so `extra` data keys
+    /// don't conflict.  **Warning**, do not stuff large data-sets in here --
+    /// task definitions should not take-up multiple MiBs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<Value>,
+
+    /// Required task metadata
+    pub metadata: TaskMetadata,
+
+    /// Task-specific payload following worker-specific format.
+    /// Refer to the documentation for the worker implementing
+    /// `<provisionerId>/<workerType>` for details.
+    pub payload: Value,
+
+    /// Priority of task. This defaults to `lowest` and the scope
+    /// `queue:create-task:<priority>/<provisionerId>/<workerType>` is required
+    /// to define a task with `<priority>`. The `normal` priority is treated as
+    /// `lowest`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+
+    /// The name for the "project" with which this task is associated.  This
+    /// value can be used to control permission to manipulate tasks as well as
+    /// for usage reporting.  Project ids are typically simple identifiers,
+    /// optionally in a hierarchical namespace separated by `/` characters.
+    /// This value defaults to `none`.
+    #[serde(rename = "projectId", default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId", default, skip_serializing_if = "Option::is_none")]
+    pub provisioner_id: Option<String>,
+
+    /// The tasks relation to its dependencies. This property specifies the
+    /// semantics of the `task.dependencies` property.
+    /// If `all-completed` is given the task will be scheduled when all
+    /// dependencies are resolved _completed_ (successful resolution).
+    /// If `all-resolved` is given the task will be scheduled when all dependencies
+    /// have been resolved, regardless of what their resolution is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requires: Option<String>,
+
+    /// Number of times to retry the task in case of infrastructure issues.
+    /// An _infrastructure issue_ is a worker node that crashes or is shutdown,
+    /// these events are to be expected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<i64>,
+
+    /// List of task-specific routes. Pulse messages about the task will be CC'ed to
+    /// `route.<value>` for each `<value>` in this array.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub routes: Option<Vec<String>>,
+
+    /// All tasks in a task group must have the same `schedulerId`. This is used for several purposes:
+    #[serde(rename = "schedulerId", default, skip_serializing_if = "Option::is_none")]
+    pub scheduler_id: Option<String>,
+
+    /// List of scopes that the task is authorized to use during its execution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+
+    /// Arbitrary key-value tags (only strings limited to 4k). These can be used
+    /// to attach informal metadata to a task. Use this for informal tags that
+    /// tasks can be classified by. You can also think of strings here as
+    /// candidates for formal metadata. Something like
+    /// `purpose: 'build' || 'test'` is a good example.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<HashMap<String, String>>,
+
+    /// Identifier for a group of tasks scheduled together with this task.
+    /// Generally, all tasks related to a single event such as a version-control
+    /// push or a nightly build have the same `taskGroupId`.  This property
+    /// defaults to `taskId` if it isn't specified.  Tasks with `taskId` equal to
+    /// the `taskGroupId` are, [by convention](/docs/manual/using/task-graph),
+    /// decision tasks.
+    #[serde(rename = "taskGroupId", default, skip_serializing_if = "Option::is_none")]
+    pub task_group_id: Option<String>,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId", default, skip_serializing_if = "Option::is_none")]
+    pub task_queue_id: Option<String>,
+
+    /// Unique identifier for a worker-type within a specific
+    /// provisioner. Deprecation is planned for this property as it will
+    /// be replaced, together with `provisionerId`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "workerType", default, skip_serializing_if = "Option::is_none")]
+    pub worker_type: Option<String>,
+}
+
+/// Request to claim a task for a worker to process.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClaimWorkRequest {
+    /// Number of tasks to attempt to claim.
+    pub tasks: i64,
+
+    /// Identifier for group that worker claiming the task is a part of.
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Identifier for worker within the given workerGroup
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+}
+
+/// Response to an attempt to claim tasks for a worker to process.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClaimWorkResponse {
+    /// List of task claims, may be empty if no tasks was claimed, in which case
+    /// the worker should sleep a tiny bit before polling again.
+    pub tasks: Vec<ClaimWorkResponseTasksItem>,
+}
+
+/// Task Claim
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClaimWorkResponseTasksItem {
+    /// Temporary credentials granting `task.scopes` and the scope:
+    /// `queue:claim-task:<taskId>/<runId>` which allows the worker to reclaim
+    /// the task, upload artifacts and report task resolution.
+    pub credentials: TaskCredentials,
+
+    /// `run-id` assigned to this run of the task
+    #[serde(rename = "runId")]
+    pub run_id: i64,
+
+    /// A representation of **task status** as known by the queue
+    pub status: TaskStatus,
+
+    /// Time at which the run expires and is resolved as `exception`,
+    /// with reason `claim-expired` if the run haven't been reclaimed.
+    /// This will be some time in the future, with that time controlled
+    /// by the `queue.task_claim_timeout` configuration.
+    #[serde(rename = "takenUntil")]
+    pub taken_until: DateTime<Utc>,
+
+    /// Definition of a task that can be scheduled
+    pub task: Task,
+
+    /// Identifier for the worker-group within which this run started.
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Identifier for the worker executing this run.
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+}
+
+/// Temporary credentials granting `task.scopes` and the scope:
+/// `queue:claim-task:<taskId>/<runId>` which allows the worker to reclaim
+/// the task, upload artifacts and report task resolution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskCredentials {
+    /// The `accessToken` for the temporary credentials.
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    /// The `certificate` for the temporary credentials, these are required
+    /// for the temporary credentials to work.
+    pub certificate: String,
+
+    /// The `clientId` for the temporary credentials.
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+}
+
+/// Request to claim (or reclaim) a task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskClaimRequest {
+    /// Identifier for group that worker claiming the task is a part of.
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Identifier for worker within the given workerGroup
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+}
+
+/// Response to a successful task claim
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskClaimResponse {
+    /// Temporary credentials granting `task.scopes` and the scope:
+    /// `queue:claim-task:<taskId>/<runId>` which allows the worker to reclaim
+    /// the task, upload artifacts and report task resolution.
+    pub credentials: TaskCredentials,
+
+    /// `run-id` assigned to this run of the task
+    #[serde(rename = "runId")]
+    pub run_id: i64,
+
+    /// A representation of **task status** as known by the queue
+    pub status: TaskStatus,
+
+    /// Time at which the run expires and is resolved as `exception`,
+    /// with reason `claim-expired` if the run haven't been reclaimed.
+    #[serde(rename = "takenUntil")]
+    pub taken_until: DateTime<Utc>,
+
+    /// Definition of a task that can be scheduled
+    pub task: Task,
+
+    /// Identifier for the worker-group within which this run started.
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Identifier for the worker executing this run.
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+}
+
+/// Response to a successful task claim
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskReclaimResponse {
+    /// Temporary credentials granting `task.scopes` and the scope:
+    /// `queue:claim-task:<taskId>/<runId>` which allows the worker to reclaim
+    /// the task, upload artifacts and report task resolution.
+    pub credentials: TaskCredentials,
+
+    /// `run-id` assigned to this run of the task
+    #[serde(rename = "runId")]
+    pub run_id: i64,
+
+    /// A representation of **task status** as known by the queue
+    pub status: TaskStatus,
+
+    /// Time at which the run expires and is resolved as `exception`,
+    /// with reason `claim-expired` if the run haven't been reclaimed.
+    #[serde(rename = "takenUntil")]
+    pub taken_until: DateTime<Utc>,
+
+    /// Identifier for the worker-group within which this run started.
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Identifier for the worker executing this run.
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+}
+
+/// Request for a run of a task to be resolved with an exception
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskExceptionRequest {
+    /// Reason that the task is resolved with an exception. This is a subset
+    /// of the values for `resolvedReason` given in the task status structure.
+    pub reason: String,
+}
+
+/// Response to the `getArtifact` method.  This method returns a simple URL from
+/// which the artifact data can be read.  Not that this response is provided as
+/// the body of an HTTP 303 response, so clients which automatically follow
+/// redirects may not see this content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GetArtifactResponse {
+    /// Artifact storage type.  Note that this is also available in the
+    /// `x-taskcluster-artifact-storage-type` header.
+    #[serde(rename = "storageType")]
+    pub storage_type: String,
+
+    /// URL from which to download the artifact
+    pub url: String,
+}
+
+/// List of artifacts for a given `taskId` and `runId`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListArtifactsResponse {
+    /// List of artifacts for given `taskId` and `runId`.
+    pub artifacts: Vec<ArtifactResponse>,
+
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of artifacts.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called with `continuationToken` until you get a
+    /// result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// Information about an artifact
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArtifactResponse {
+    /// Expected content-type of the artifact.  This is informational only:
+    /// it is suitable for use to choose an icon for the artifact, for example.
+    /// The accurate content-type of the artifact can only be determined by
+    /// downloading it.
+    #[serde(rename = "contentType")]
+    pub content_type: String,
+
+    /// Date and time after which the artifact created will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Name of the artifact that was created, this is useful if you want to
+    /// attempt to fetch the artifact.
+    pub name: String,
+
+    /// This is the `storageType` for the request that was used to create
+    /// the artifact.
+    #[serde(rename = "storageType")]
+    pub storage_type: String,
+}
+
+/// List Provisioners Response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListProvisionersResponse {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of provisioners.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called with `continuationToken` until you get a
+    /// result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    pub provisioners: Vec<ListProvisionersResponseProvisionersItem>,
+}
+
+/// Provisioner Information
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListProvisionersResponseProvisionersItem {
+    /// See taskcluster [actions](/docs/reference/platform/taskcluster-queue/docs/actions) documentation.
+    pub actions: Vec<ActionsItem>,
+
+    /// Description of the provisioner.
+    pub description: String,
+
+    /// Date and time after which the provisioner created will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Date and time where the provisioner was last seen active
+    #[serde(rename = "lastDateActive")]
+    pub last_date_active: DateTime<Utc>,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// This is the stability of the provisioner. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    pub stability: String,
+}
+
+/// Actions provide a generic mechanism to expose additional features of a
+/// provisioner, worker type, or worker to Taskcluster clients.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionsItem {
+    /// Actions have a "context" that is one of provisioner, worker-type, or worker, indicating
+    /// which it applies to. `context` is used by the front-end to know where to display the action.
+    pub context: String,
+
+    /// Description of the provisioner.
+    pub description: String,
+
+    /// Method to indicate the desired action to be performed for a given resource.
+    pub method: String,
+
+    /// Short names for things like logging/error messages.
+    pub name: String,
+
+    /// Appropriate title for any sort of Modal prompt.
+    pub title: Value,
+
+    /// When an action is triggered, a request is made using the `url` and `method`.
+    /// Depending on the `context`, the following parameters will be substituted in the url:
+    pub url: String,
+}
+
+/// Response containing information about a provisioner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProvisionerResponse {
+    /// See taskcluster [actions](/docs/reference/platform/taskcluster-queue/docs/actions) documentation.
+    pub actions: Vec<ActionsItem>,
+
+    /// Description of the provisioner.
+    pub description: String,
+
+    /// Date and time after which the provisioner will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Date of the last time this provisioner was seen active. `lastDateActive` is updated every 6 hours
+    /// but may be off by up-to 6 hours. Nonetheless, `lastDateActive` is a good indicator
+    /// of when the provisioner was last seen active.
+    #[serde(rename = "lastDateActive")]
+    pub last_date_active: DateTime<Utc>,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// This is the stability of the provisioner. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    pub stability: String,
+}
+
+/// Request to update a provisioner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateProvisionerRequest {
+    /// See taskcluster [actions](/docs/reference/platform/taskcluster-queue/docs/actions) documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<ActionsItem>>,
+
+    /// Description of the provisioner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Date and time after which the provisioner will be automatically
+    /// deleted by the queue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<DateTime<Utc>>,
+
+    /// This is the stability of the provisioner. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stability: Option<String>,
+}
+
+/// Response to a request for the number of pending tasks for a given
+/// `provisionerId` and `workerType`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingTasksResponse {
+    /// An approximate number of pending tasks for the given `provisionerId` and
+    /// `workerType`. This is based on Azure Queue Storage metadata API, thus,
+    /// number of reported here may be higher than actual number of pending tasks.
+    /// But there cannot be more pending tasks reported here. Ie. this is an
+    /// **upper-bound** on the number of pending tasks.
+    #[serde(rename = "pendingTasks")]
+    pub pending_tasks: i64,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId")]
+    pub task_queue_id: String,
+
+    /// Unique identifier for a worker-type within a specific
+    /// provisioner. Deprecation is planned for this property as it will
+    /// be replaced, together with `provisionerId`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "workerType")]
+    pub worker_type: String,
+}
+
+/// Response from a `listWorkerTypes` request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListWorkertypesResponse {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of worker-types in the provisioner.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listWorkerTypes` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of worker-types in this provisioner.
+    #[serde(rename = "workerTypes")]
+    pub worker_types: Vec<ListWorkertypesResponseWorkerTypesItem>,
+}
+
+/// Worker Type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListWorkertypesResponseWorkerTypesItem {
+    /// Description of the worker-type.
+    pub description: String,
+
+    /// Date and time after which the worker-type will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Date and time where the worker-type was last seen active
+    #[serde(rename = "lastDateActive")]
+    pub last_date_active: DateTime<Utc>,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// This is the stability of the worker-type. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    pub stability: String,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId")]
+    pub task_queue_id: String,
+
+    /// Unique identifier for a worker-type within a specific
+    /// provisioner. Deprecation is planned for this property as it will
+    /// be replaced, together with `provisionerId`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "workerType")]
+    pub worker_type: String,
+}
+
+/// Response to a worker-type request from a provisioner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkertypeResponse {
+    /// Worker-type Actions
+    pub actions: Vec<WorkertypeResponseActionsItem>,
+
+    /// Description of the worker-type.
+    pub description: String,
+
+    /// Date and time after which the worker-type will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Date of the last time this worker-type was seen active. `lastDateActive` is updated every 6 hours
+    /// but may be off by up-to 6 hours. Nonetheless, `lastDateActive` is a good indicator
+    /// of when the worker-type was last seen active.
+    #[serde(rename = "lastDateActive")]
+    pub last_date_active: DateTime<Utc>,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// This is the stability of the worker-type. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    pub stability: String,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId")]
+    pub task_queue_id: String,
+
+    /// Unique identifier for a worker-type within a specific
+    /// provisioner. Deprecation is planned for this property as it will
+    /// be replaced, together with `provisionerId`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "workerType")]
+    pub worker_type: String,
+}
+
+/// Actions provide a generic mechanism to expose additional features of a
+/// provisioner, worker type, or worker to Taskcluster clients.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkertypeResponseActionsItem {
+    /// Only actions with the context `worker-type` are included.
+    pub context: String,
+
+    /// Description of the provisioner.
+    pub description: String,
+
+    /// Method to indicate the desired action to be performed for a given resource.
+    pub method: String,
+
+    /// Short names for things like logging/error messages.
+    pub name: String,
+
+    /// Appropriate title for any sort of Modal prompt.
+    pub title: Value,
+
+    /// When an action is triggered, a request is made using the `url` and `method`.
+    /// Depending on the `context`, the following parameters will be substituted in the url:
+    pub url: String,
+}
+
+/// Request to update a worker-type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateWorkertypeRequest {
+    /// Description of the provisioner.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Date and time after which the worker-type will be automatically
+    /// deleted by the queue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<DateTime<Utc>>,
+
+    /// This is the stability of the provisioner. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stability: Option<String>,
+}
+
+/// Response from a `listTaskQueues` request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListTaskqueuesResponse {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of task-queues.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listTaskQueues` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of all task-queues.
+    #[serde(rename = "taskQueues")]
+    pub task_queues: Vec<ListTaskqueuesResponseTaskQueuesItem>,
+}
+
+/// Task Queue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListTaskqueuesResponseTaskQueuesItem {
+    /// Description of the task queue.
+    pub description: String,
+
+    /// Date and time after which the task queue will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Date and time where the task queue was last seen active
+    #[serde(rename = "lastDateActive")]
+    pub last_date_active: DateTime<Utc>,
+
+    /// This is the stability of the task queue. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    pub stability: String,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId")]
+    pub task_queue_id: String,
+}
+
+/// Response to a task queue request from a provisioner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskqueueResponse {
+    /// Description of the task queue.
+    pub description: String,
+
+    /// Date and time after which the task queue will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Date of the last time this task queue was seen active. `lastDateActive` is updated every 6 hours
+    /// but may be off by up-to 6 hours. Nonetheless, `lastDateActive` is a good indicator
+    /// of when the task queue was last seen active.
+    #[serde(rename = "lastDateActive")]
+    pub last_date_active: DateTime<Utc>,
+
+    /// This is the stability of the task queue. Accepted values:
+    /// * `experimental`
+    /// * `stable`
+    /// * `deprecated`
+    pub stability: String,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId")]
+    pub task_queue_id: String,
+}
+
+/// Response from a `listWorkers` request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListWorkersResponse {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of workers in the worker-type.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listWorkerTypes` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of workers in this worker-type.
+    pub workers: Vec<ListWorkersResponseWorkersItem>,
+}
+
+/// Worker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListWorkersResponseWorkersItem {
+    /// Date of the first time this worker claimed a task.
+    #[serde(rename = "firstClaim")]
+    pub first_claim: DateTime<Utc>,
+
+    /// The most recent claimed task
+    #[serde(rename = "latestTask", default, skip_serializing_if = "Option::is_none")]
+    pub latest_task: Option<TaskRun>,
+
+    /// Quarantining a worker allows the machine to remain alive but not accept jobs.
+    /// Once the quarantineUntil time has elapsed, the worker resumes accepting jobs.
+    /// Note that a quarantine can be lifted by setting `quarantineUntil` to the present time (or
+    /// somewhere in the past).
+    #[serde(rename = "quarantineUntil", default, skip_serializing_if = "Option::is_none")]
+    pub quarantine_until: Option<DateTime<Utc>>,
+
+    /// Identifier for the worker group containing this worker.
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Identifier for this worker (unique within this worker group).
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+}
+
+/// A run of a task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskRun {
+    /// Id of this task run, `run-id`s always starts from `0`
+    #[serde(rename = "runId")]
+    pub run_id: i64,
+
+    /// Unique task identifier, this is UUID encoded as
+    /// [URL-safe base64](http://tools.ietf.org/html/rfc4648#section-5) and
+    /// stripped of `=` padding.
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+}
+
+/// Response containing information about a worker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerResponse {
+    /// Worker Actions
+    pub actions: Vec<WorkerResponseActionsItem>,
+
+    /// Date and time after which the worker will be automatically
+    /// deleted by the queue.
+    pub expires: DateTime<Utc>,
+
+    /// Date of the first time this worker claimed a task.
+    #[serde(rename = "firstClaim")]
+    pub first_claim: DateTime<Utc>,
+
+    /// Unique identifier for a provisioner, that can supply specified
+    /// `workerType`. Deprecation is planned for this property as it
+    /// will be replaced, together with `workerType`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "provisionerId")]
+    pub provisioner_id: String,
+
+    /// Quarantining a worker allows the machine to remain alive but not accept jobs.
+    /// Once the quarantineUntil time has elapsed, the worker resumes accepting jobs.
+    /// Note that a quarantine can be lifted by setting `quarantineUntil` to the present time (or
+    /// somewhere in the past).
+    #[serde(rename = "quarantineUntil", default, skip_serializing_if = "Option::is_none")]
+    pub quarantine_until: Option<DateTime<Utc>>,
+
+    /// List of 20 most recent tasks claimed by the worker.
+    #[serde(rename = "recentTasks")]
+    pub recent_tasks: Vec<TaskRun>,
+
+    /// Unique identifier for a task queue
+    #[serde(rename = "taskQueueId", default, skip_serializing_if = "Option::is_none")]
+    pub task_queue_id: Option<String>,
+
+    /// Identifier for group that worker who executes this run is a part of,
+    /// this identifier is mainly used for efficient routing.
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Identifier for worker evaluating this run within given
+    /// `workerGroup`.
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+
+    /// Unique identifier for a worker-type within a specific
+    /// provisioner. Deprecation is planned for this property as it will
+    /// be replaced, together with `provisionerId`, by the new
+    /// identifier `taskQueueId`.
+    #[serde(rename = "workerType")]
+    pub worker_type: String,
+}
+
+/// Actions provide a generic mechanism to expose additional features of a
+/// provisioner, worker type, or worker to Taskcluster clients.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerResponseActionsItem {
+    /// Only actions with the context `worker` are included.
+    pub context: String,
+
+    /// Description of the provisioner.
+    pub description: String,
+
+    /// Method to indicate the desired action to be performed for a given resource.
+    pub method: String,
+
+    /// Short names for things like logging/error messages.
+    pub name: String,
+
+    /// Appropriate title for any sort of Modal prompt.
+    pub title: Value,
+
+    /// When an action is triggered, a request is made using the `url` and `method`.
+    /// Depending on the `context`, the following parameters will be substituted in the url:
+    pub url: String,
+}
+
+/// Request to update a worker's quarantineUntil property.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuarantineWorkerRequest {
+    /// Quarantining a worker allows the machine to remain alive but not accept jobs.
+    /// Once the quarantineUntil time has elapsed, the worker resumes accepting jobs.
+    /// Note that a quarantine can be lifted by setting `quarantineUntil` to the present time (or
+    /// somewhere in the past).
+    #[serde(rename = "quarantineUntil")]
+    pub quarantine_until: DateTime<Utc>,
+}
+
+/// Request to update a worker.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateWorkerRequest {
+    /// Date and time after which the worker will be automatically
+    /// deleted by the queue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,105 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`Secrets`](crate::Secrets).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct Secrets {
+    client: crate::Secrets,
+}
+
+#[allow(non_snake_case)]
+impl Secrets {
+    /// Create a new typed Secrets instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::Secrets::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::Secrets {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`Secrets::ping`](crate::Secrets::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// Set Secret
+    ///
+    /// See [`Secrets::set`](crate::Secrets::set).
+    pub async fn set(&self, name: &str, payload: &Secret) -> Result<(), Error> {
+        let payload = &serde_json::to_value(payload)?;
+        self.client.set(name, payload).await
+    }
+
+    /// Delete Secret
+    ///
+    /// See [`Secrets::remove`](crate::Secrets::remove).
+    pub async fn remove(&self, name: &str) -> Result<(), Error> {
+        self.client.remove(name).await
+    }
+
+    /// Read Secret
+    ///
+    /// See [`Secrets::get`](crate::Secrets::get).
+    pub async fn get(&self, name: &str) -> Result<Secret, Error> {
+        let output = self.client.get(name).await?;
+        serde_json::from_value(output).context("Invalid get response")
+    }
+
+    /// List Secrets
+    ///
+    /// See [`Secrets::list`](crate::Secrets::list).
+    pub async fn list(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<SecretList, Error> {
+        let output = self.client.list(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid list response")
+    }
+
+    /// Stream all pages of results from the list endpoint, following `continuationToken`
+    pub fn list_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<SecretList, Error>> + 'a {
+        self.client.list_paginated(limit).map(|page| {
+            serde_json::from_value::<SecretList>(page?).context("Invalid list response")
+        })
+    }
+}
+
+/// Message containing a Taskcluster Secret
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Secret {
+    /// An expiration date for this secret.
+    pub expires: DateTime<Utc>,
+
+    /// The secret value to be encrypted.
+    pub secret: Value,
+}
+
+/// Message containing a list of secret names
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecretList {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of provisioners.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called with `continuationToken` until you get a
+    /// result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// Secret names
+    pub secrets: Vec<String>,
+}
@@ -0,0 +1,644 @@
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [`WorkerManager`](crate::WorkerManager).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// `serde_json::Value`.  A schema that cannot be expressed as a Rust type, such as one with
+/// `oneOf`, is still represented as a `Value`.
+pub struct WorkerManager {
+    client: crate::WorkerManager,
+}
+
+#[allow(non_snake_case)]
+impl WorkerManager {
+    /// Create a new typed WorkerManager instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::WorkerManager::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::WorkerManager {
+        &self.client
+    }
+
+    /// Ping Server
+    ///
+    /// See [`WorkerManager::ping`](crate::WorkerManager::ping).
+    pub async fn ping(&self) -> Result<(), Error> {
+        self.client.ping().await
+    }
+
+    /// List Providers
+    ///
+    /// See [`WorkerManager::listProviders`](crate::WorkerManager::listProviders).
+    pub async fn listProviders(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<ProviderList, Error> {
+        let output = self.client.listProviders(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listProviders response")
+    }
+
+    /// Stream all pages of results from the listProviders endpoint, following `continuationToken`
+    pub fn listProviders_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<ProviderList, Error>> + 'a {
+        self.client.listProviders_paginated(limit).map(|page| {
+            serde_json::from_value::<ProviderList>(page?).context("Invalid listProviders response")
+        })
+    }
+
+    /// Create Worker Pool
+    ///
+    /// See [`WorkerManager::createWorkerPool`](crate::WorkerManager::createWorkerPool).
+    pub async fn createWorkerPool(&self, workerPoolId: &str, payload: &CreateWorkerPoolRequest) -> Result<WorkerPoolFull, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.createWorkerPool(workerPoolId, payload).await?;
+        serde_json::from_value(output).context("Invalid createWorkerPool response")
+    }
+
+    /// Update Worker Pool
+    ///
+    /// See [`WorkerManager::updateWorkerPool`](crate::WorkerManager::updateWorkerPool).
+    pub async fn updateWorkerPool(&self, workerPoolId: &str, payload: &UpdateWorkerPoolRequest) -> Result<WorkerPoolFull, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.updateWorkerPool(workerPoolId, payload).await?;
+        serde_json::from_value(output).context("Invalid updateWorkerPool response")
+    }
+
+    /// Delete Worker Pool
+    ///
+    /// See [`WorkerManager::deleteWorkerPool`](crate::WorkerManager::deleteWorkerPool).
+    pub async fn deleteWorkerPool(&self, workerPoolId: &str) -> Result<WorkerPoolFull, Error> {
+        let output = self.client.deleteWorkerPool(workerPoolId).await?;
+        serde_json::from_value(output).context("Invalid deleteWorkerPool response")
+    }
+
+    /// Get Worker Pool
+    ///
+    /// See [`WorkerManager::workerPool`](crate::WorkerManager::workerPool).
+    pub async fn workerPool(&self, workerPoolId: &str) -> Result<WorkerPoolFull, Error> {
+        let output = self.client.workerPool(workerPoolId).await?;
+        serde_json::from_value(output).context("Invalid workerPool response")
+    }
+
+    /// List All Worker Pools
+    ///
+    /// See [`WorkerManager::listWorkerPools`](crate::WorkerManager::listWorkerPools).
+    pub async fn listWorkerPools(&self, continuationToken: Option<&str>, limit: Option<&str>) -> Result<WorkerPoolList, Error> {
+        let output = self.client.listWorkerPools(continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listWorkerPools response")
+    }
+
+    /// Stream all pages of results from the listWorkerPools endpoint, following `continuationToken`
+    pub fn listWorkerPools_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<WorkerPoolList, Error>> + 'a {
+        self.client.listWorkerPools_paginated(limit).map(|page| {
+            serde_json::from_value::<WorkerPoolList>(page?).context("Invalid listWorkerPools response")
+        })
+    }
+
+    /// Report an error from a worker
+    ///
+    /// See [`WorkerManager::reportWorkerError`](crate::WorkerManager::reportWorkerError).
+    pub async fn reportWorkerError(&self, workerPoolId: &str, payload: &ReportWorkerErrorRequest) -> Result<WorkerPoolError, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.reportWorkerError(workerPoolId, payload).await?;
+        serde_json::from_value(output).context("Invalid reportWorkerError response")
+    }
+
+    /// List Worker Pool Errors
+    ///
+    /// See [`WorkerManager::listWorkerPoolErrors`](crate::WorkerManager::listWorkerPoolErrors).
+    pub async fn listWorkerPoolErrors(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<WorkerPoolErrorList, Error> {
+        let output = self.client.listWorkerPoolErrors(workerPoolId, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listWorkerPoolErrors response")
+    }
+
+    /// Stream all pages of results from the listWorkerPoolErrors endpoint, following `continuationToken`
+    pub fn listWorkerPoolErrors_paginated<'a>(&'a self, workerPoolId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<WorkerPoolErrorList, Error>> + 'a {
+        self.client.listWorkerPoolErrors_paginated(workerPoolId, limit).map(|page| {
+            serde_json::from_value::<WorkerPoolErrorList>(page?).context("Invalid listWorkerPoolErrors response")
+        })
+    }
+
+    /// Workers in a specific Worker Group in a Worker Pool
+    ///
+    /// See [`WorkerManager::listWorkersForWorkerGroup`](crate::WorkerManager::listWorkersForWorkerGroup).
+    pub async fn listWorkersForWorkerGroup(&self, workerPoolId: &str, workerGroup: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<WorkerList, Error> {
+        let output = self.client.listWorkersForWorkerGroup(workerPoolId, workerGroup, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listWorkersForWorkerGroup response")
+    }
+
+    /// Stream all pages of results from the listWorkersForWorkerGroup endpoint, following `continuationToken`
+    pub fn listWorkersForWorkerGroup_paginated<'a>(&'a self, workerPoolId: &'a str, workerGroup: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<WorkerList, Error>> + 'a {
+        self.client.listWorkersForWorkerGroup_paginated(workerPoolId, workerGroup, limit).map(|page| {
+            serde_json::from_value::<WorkerList>(page?).context("Invalid listWorkersForWorkerGroup response")
+        })
+    }
+
+    /// Get a Worker
+    ///
+    /// See [`WorkerManager::worker`](crate::WorkerManager::worker).
+    pub async fn worker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str) -> Result<WorkerFull, Error> {
+        let output = self.client.worker(workerPoolId, workerGroup, workerId).await?;
+        serde_json::from_value(output).context("Invalid worker response")
+    }
+
+    /// Create a Worker
+    ///
+    /// See [`WorkerManager::createWorker`](crate::WorkerManager::createWorker).
+    pub async fn createWorker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str, payload: &CreateWorkerRequest) -> Result<WorkerFull, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.createWorker(workerPoolId, workerGroup, workerId, payload).await?;
+        serde_json::from_value(output).context("Invalid createWorker response")
+    }
+
+    /// Update an existing Worker
+    ///
+    /// See [`WorkerManager::updateWorker`](crate::WorkerManager::updateWorker).
+    pub async fn updateWorker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str, payload: &CreateWorkerRequest) -> Result<WorkerFull, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.updateWorker(workerPoolId, workerGroup, workerId, payload).await?;
+        serde_json::from_value(output).context("Invalid updateWorker response")
+    }
+
+    /// Remove a Worker
+    ///
+    /// See [`WorkerManager::removeWorker`](crate::WorkerManager::removeWorker).
+    pub async fn removeWorker(&self, workerPoolId: &str, workerGroup: &str, workerId: &str) -> Result<(), Error> {
+        self.client.removeWorker(workerPoolId, workerGroup, workerId).await
+    }
+
+    /// Workers in a Worker Pool
+    ///
+    /// See [`WorkerManager::listWorkersForWorkerPool`](crate::WorkerManager::listWorkersForWorkerPool).
+    pub async fn listWorkersForWorkerPool(&self, workerPoolId: &str, continuationToken: Option<&str>, limit: Option<&str>) -> Result<WorkerList, Error> {
+        let output = self.client.listWorkersForWorkerPool(workerPoolId, continuationToken, limit).await?;
+        serde_json::from_value(output).context("Invalid listWorkersForWorkerPool response")
+    }
+
+    /// Stream all pages of results from the listWorkersForWorkerPool endpoint, following `continuationToken`
+    pub fn listWorkersForWorkerPool_paginated<'a>(&'a self, workerPoolId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<WorkerList, Error>> + 'a {
+        self.client.listWorkersForWorkerPool_paginated(workerPoolId, limit).map(|page| {
+            serde_json::from_value::<WorkerList>(page?).context("Invalid listWorkersForWorkerPool response")
+        })
+    }
+
+    /// Register a running worker
+    ///
+    /// See [`WorkerManager::registerWorker`](crate::WorkerManager::registerWorker).
+    pub async fn registerWorker(&self, payload: &RegisterWorkerRequest) -> Result<RegisterWorkerResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.registerWorker(payload).await?;
+        serde_json::from_value(output).context("Invalid registerWorker response")
+    }
+
+    /// Reregister a Worker
+    ///
+    /// See [`WorkerManager::reregisterWorker`](crate::WorkerManager::reregisterWorker).
+    pub async fn reregisterWorker(&self, payload: &ReregisterWorkerRequest) -> Result<ReregisterWorkerResponse, Error> {
+        let payload = &serde_json::to_value(payload)?;
+        let output = self.client.reregisterWorker(payload).await?;
+        serde_json::from_value(output).context("Invalid reregisterWorker response")
+    }
+}
+
+/// A list of providers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderList {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of workers in the worker-manager.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listWorkerPools` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of all providers
+    pub providers: Vec<ProviderListProvidersItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderListProvidersItem {
+    /// The id of this provider
+    #[serde(rename = "providerId")]
+    pub provider_id: String,
+
+    /// The provider implementation underlying this provider
+    #[serde(rename = "providerType")]
+    pub provider_type: String,
+}
+
+/// Fields that are defined by a user for a worker pool.
+/// Used to create worker-pool definitions. There is a larger
+/// set of fields for viewing since some parts are generated
+/// by the service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateWorkerPoolRequest {
+    pub config: Value,
+
+    /// A description of this worker pool.
+    pub description: String,
+
+    /// If true, the owner should be emailed on provisioning errors
+    #[serde(rename = "emailOnError")]
+    pub email_on_error: bool,
+
+    /// An email address to notify when there are provisioning errors for this
+    /// worker pool.
+    pub owner: String,
+
+    /// The provider responsible for managing this worker pool.
+    #[serde(rename = "providerId")]
+    pub provider_id: String,
+}
+
+/// A complete worker pool definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerPoolFull {
+    pub config: Value,
+
+    /// Date and time when this worker pool was created
+    pub created: DateTime<Utc>,
+
+    /// Total capacity available across all workers for this worker pool that are currently not "stopped"
+    #[serde(rename = "currentCapacity")]
+    pub current_capacity: i64,
+
+    /// A description of this worker pool.
+    pub description: String,
+
+    /// If true, the owner should be emailed on provisioning errors
+    #[serde(rename = "emailOnError")]
+    pub email_on_error: bool,
+
+    /// Date and time when this worker pool was last updated
+    #[serde(rename = "lastModified")]
+    pub last_modified: DateTime<Utc>,
+
+    /// An email address to notify when there are provisioning errors for this
+    /// worker pool.
+    pub owner: String,
+
+    /// The provider responsible for managing this worker pool.
+    #[serde(rename = "providerId")]
+    pub provider_id: String,
+
+    /// The ID of this worker pool (of the form `providerId/workerType` for compatibility)
+    #[serde(rename = "workerPoolId", default, skip_serializing_if = "Option::is_none")]
+    pub worker_pool_id: Option<String>,
+}
+
+/// Fields that are defined by a user for a worker pool.
+/// Used to modify worker-pool definitions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateWorkerPoolRequest {
+    pub config: Value,
+
+    /// Ignored on update
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+
+    /// A description of this worker pool.
+    pub description: String,
+
+    /// If true, the owner should be emailed on provisioning errors
+    #[serde(rename = "emailOnError")]
+    pub email_on_error: bool,
+
+    /// Ignored on update
+    #[serde(rename = "lastModified", default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<DateTime<Utc>>,
+
+    /// An email address to notify when there are provisioning errors for this
+    /// worker pool.
+    pub owner: String,
+
+    /// The provider responsible for managing this worker pool.
+    #[serde(rename = "providerId")]
+    pub provider_id: String,
+
+    /// Worker Pool ID
+    #[serde(rename = "workerPoolId", default, skip_serializing_if = "Option::is_none")]
+    pub worker_pool_id: Option<String>,
+}
+
+/// A list of worker pools
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerPoolList {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of worker-types in the worker-manager.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listWorkerPools` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of all worker pools
+    #[serde(rename = "workerPools")]
+    pub worker_pools: Vec<WorkerPoolFull>,
+}
+
+/// A report of an error from a worker.  This will be recorded with kind
+/// `worker-error`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportWorkerErrorRequest {
+    /// A longer description of what occured in the error.
+    pub description: String,
+
+    /// Any extra structured information about this error
+    pub extra: Value,
+
+    /// A general machine-readable way to identify this sort of error.
+    pub kind: String,
+
+    /// A human-readable version of `kind`.
+    pub title: String,
+
+    /// Worker group to which this worker belongs
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Worker ID
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+}
+
+/// A complete worker pool error definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerPoolError {
+    /// A longer description of what occured in the error.
+    pub description: String,
+
+    /// An arbitary unique identifier for this error
+    #[serde(rename = "errorId")]
+    pub error_id: String,
+
+    /// Any extra structured information about this error
+    pub extra: Value,
+
+    /// A general machine-readable way to identify this sort of error.
+    pub kind: String,
+
+    /// Date and time when this error was reported
+    pub reported: DateTime<Utc>,
+
+    /// A human-readable version of `kind`.
+    pub title: String,
+
+    /// The ID of this worker pool (of the form `providerId/workerType` for compatibility)
+    #[serde(rename = "workerPoolId")]
+    pub worker_pool_id: String,
+}
+
+/// A list of worker pool errors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerPoolErrorList {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of worker-types in the worker-manager.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listWorkerPools` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of worker pool errors
+    #[serde(rename = "workerPoolErrors")]
+    pub worker_pool_errors: Vec<WorkerPoolError>,
+}
+
+/// A list of workers in a given worker pool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerList {
+    /// Opaque `continuationToken` to be given as query-string option to get the
+    /// next set of workers in the worker-manager.
+    /// This property is only present if another request is necessary to fetch all
+    /// results. In practice the next request with a `continuationToken` may not
+    /// return additional results, but it can. Thus, you can only be sure to have
+    /// all the results if you've called `listWorkerPools` with `continuationToken`
+    /// until you get a result without a `continuationToken`.
+    #[serde(rename = "continuationToken", default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
+    /// List of all workers in a given worker pool
+    pub workers: Vec<WorkerFull>,
+}
+
+/// A complete worker definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerFull {
+    /// Number of tasks this worker can handle at once
+    pub capacity: i64,
+
+    /// Date and time when this worker was created
+    pub created: DateTime<Utc>,
+
+    /// Date and time when this worker will be deleted from the DB
+    pub expires: DateTime<Utc>,
+
+    /// Date and time when the state of this worker was verified with a cloud api.
+    /// For providers with nothing to check, this will just be permanently set to the
+    /// time the worker was created.
+    #[serde(rename = "lastChecked")]
+    pub last_checked: DateTime<Utc>,
+
+    /// Date and time when this worker last changed state
+    #[serde(rename = "lastModified")]
+    pub last_modified: DateTime<Utc>,
+
+    /// The provider that had started the worker and responsible for managing it.
+    /// Can be different from the provider that's currently in the worker pool config.
+    #[serde(rename = "providerId")]
+    pub provider_id: String,
+
+    /// A string specifying the state this worker is in so far as worker-manager knows.
+    /// A "requested" worker is in the process of starting up, and if successful will enter
+    /// the "running" state once it has registered with the `registerWorker` API method.  A
+    /// "stopping" worker is in the process of shutting down and deleting resources, while
+    /// a "stopped" worker is completely stopped.  Stopped workers are kept for historical
+    /// purposes and are purged when they expire.  Note that some providers transition workers
+    /// directly from "running" to "stopped".
+    pub state: String,
+
+    /// Worker group to which this worker belongs
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Worker ID
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+
+    /// The ID of this worker pool (of the form `providerId/workerType` for compatibility)
+    #[serde(rename = "workerPoolId")]
+    pub worker_pool_id: String,
+}
+
+/// Request to create or update a worker. Capacity will default to 1 if not specified.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateWorkerRequest {
+    /// Number of tasks this worker can handle at once
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<i64>,
+
+    /// Date and time when this worker will be deleted from the DB
+    pub expires: DateTime<Utc>,
+
+    /// Provider-specific information
+    #[serde(rename = "providerInfo", default, skip_serializing_if = "Option::is_none")]
+    pub provider_info: Option<Value>,
+}
+
+/// Request body to `registerWorker`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisterWorkerRequest {
+    /// The provider that had started the worker and responsible for managing it.
+    /// Can be different from the provider that's currently in the worker pool config.
+    #[serde(rename = "providerId")]
+    pub provider_id: String,
+
+    /// Worker group to which this worker belongs
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Worker ID
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+
+    /// Proof that this call is coming from the worker identified by the other fields.
+    /// The form of this proof varies depending on the provider type.
+    #[serde(rename = "workerIdentityProof")]
+    pub worker_identity_proof: Value,
+
+    /// The ID of this worker pool (of the form `providerId/workerType` for compatibility)
+    #[serde(rename = "workerPoolId")]
+    pub worker_pool_id: String,
+}
+
+/// Response body to `registerWorker`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisterWorkerResponse {
+    /// The credentials the worker
+    /// will need to perform its work.  Specifically, credentials with scopes
+    /// * `assume:worker-pool:<workerPoolId>`
+    /// * `assume:worker-id:<workerGroup>/<workerId>`
+    /// * `queue:worker-id:<workerGroup>/<workerId>`
+    /// * `secrets:get:worker-pool:<workerPoolId>`
+    /// * `queue:claim-work:<workerPoolId>`
+    /// * `worker-manager:remove-worker:<workerPoolId>/<workerGroup>/<workerId>`
+    pub credentials: RegisterWorkerResponseCredentials,
+
+    /// Time at which the included credentials will expire.  Workers must either
+    /// re-register (for static workers) or terminate (for dynamically
+    /// provisioned workers) before this time.
+    pub expires: DateTime<Utc>,
+
+    /// A secret value generated by worker-manager that can be used in the call to `reregisterWorker`.
+    /// For more information, refer to https://docs.taskcluster.net/docs/reference/core/worker-manager#reregistration.
+    pub secret: String,
+
+    /// This value is supplied unchanged to the worker from the worker-pool configuration.
+    /// The expectation is that the worker will merge this information with configuration from other sources,
+    /// and this is precisely what [worker-runner](https://docs.taskcluster.net/docs/reference/workers/worker-runner) does.
+    /// This property must not be used for secret configuration, as it is visible both in the worker pool configuration and in the worker instance's metadata.
+    /// Instead, put secret configuration in the [secrets service](https://docs.taskcluster.net/docs/reference/workers/worker-runner).
+    #[serde(rename = "workerConfig")]
+    pub worker_config: Value,
+}
+
+/// The credentials the worker
+/// will need to perform its work.  Specifically, credentials with scopes
+/// * `assume:worker-pool:<workerPoolId>`
+/// * `assume:worker-id:<workerGroup>/<workerId>`
+/// * `queue:worker-id:<workerGroup>/<workerId>`
+/// * `secrets:get:worker-pool:<workerPoolId>`
+/// * `queue:claim-work:<workerPoolId>`
+/// * `worker-manager:remove-worker:<workerPoolId>/<workerGroup>/<workerId>`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegisterWorkerResponseCredentials {
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    /// Note that a certificate may not be provided, if the credentials are not temporary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+}
+
+/// Request body to `reregisterWorker`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReregisterWorkerRequest {
+    /// The secret value that was last configured in `registerWorker` (in the case of a newly registerd worker) or
+    /// `reregisterWorker`.
+    /// For more information, refer to https://docs.taskcluster.net/docs/reference/core/worker-manager#reregistration.
+    pub secret: String,
+
+    /// Worker group to which this worker belongs
+    #[serde(rename = "workerGroup")]
+    pub worker_group: String,
+
+    /// Worker ID
+    #[serde(rename = "workerId")]
+    pub worker_id: String,
+
+    /// The ID of this worker pool (of the form `providerId/workerType` for compatibility)
+    #[serde(rename = "workerPoolId")]
+    pub worker_pool_id: String,
+}
+
+/// Response body to `reregisterWorker`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReregisterWorkerResponse {
+    /// The credentials the worker
+    /// will need to perform its work. Specifically, credentials with scopes
+    /// * `assume:worker-pool:<workerPoolId>`
+    /// * `assume:worker-id:<workerGroup>/<workerId>`
+    /// * `queue:worker-id:<workerGroup>/<workerId>`
+    /// * `secrets:get:worker-pool:<workerPoolId>`
+    /// * `queue:claim-work:<workerPoolId>`
+    /// * `worker-manager:remove-worker:<workerPoolId>/<workerGroup>/<workerId>`
+    /// * `worker-manager:reregister-worker:<workerPoolId>/<workerGroup>/<workerId>`
+    pub credentials: ReregisterWorkerResponseCredentials,
+
+    /// Time at which the included credentials will expire. Workers must
+    /// re-register before this time.
+    pub expires: DateTime<Utc>,
+
+    /// The next secret value needed to reregister the worker (in `reregisterWorker).
+    /// For more information, refer to https://docs.taskcluster.net/docs/reference/core/worker-manager#reregistration.
+    pub secret: String,
+}
+
+/// The credentials the worker
+/// will need to perform its work. Specifically, credentials with scopes
+/// * `assume:worker-pool:<workerPoolId>`
+/// * `assume:worker-id:<workerGroup>/<workerId>`
+/// * `queue:worker-id:<workerGroup>/<workerId>`
+/// * `secrets:get:worker-pool:<workerPoolId>`
+/// * `queue:claim-work:<workerPoolId>`
+/// * `worker-manager:remove-worker:<workerPoolId>/<workerGroup>/<workerId>`
+/// * `worker-manager:reregister-worker:<workerPoolId>/<workerGroup>/<workerId>`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReregisterWorkerResponseCredentials {
+    #[serde(rename = "accessToken")]
+    pub access_token: String,
+
+    /// Note that a certificate may not be provided, if the credentials are not temporary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+
+    #[serde(rename = "clientId")]
+    pub client_id: String,
+}
@@ -1,8 +1,9 @@
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
@@ -14,7 +15,7 @@ use crate::util::urlencode;
 /// Methods interacting with a provider may return a 503 response if that provider has
 /// not been able to start up, such as if the service to which it interfaces has an
 /// outage.  Such requests can be retried as for any other 5xx response.
-pub struct WorkerManager (Client);
+pub struct WorkerManager (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl WorkerManager {
@@ -26,6 +27,23 @@ impl WorkerManager {
             .build()?))
     }
 
+    /// Check the health of the service, returning the parsed response from its `ping` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this WorkerManager instance whose requests carry the given authorized scopes.
+    /// See [`Client::with_authorized_scopes`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }
+
     /// Ping Server
     /// 
     /// Respond without doing anything.
@@ -66,8 +84,7 @@ impl WorkerManager {
         let method = "GET";
         let (path, query) = Self::listProviders_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listProviders endpoint
@@ -82,6 +99,13 @@ impl WorkerManager {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listProviders endpoint, following `continuationToken`
+    pub fn listProviders_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listProviders_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listProviders
     fn listProviders_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "providers";
@@ -103,8 +127,7 @@ impl WorkerManager {
         let method = "PUT";
         let (path, query) = Self::createWorkerPool_details(workerPoolId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createWorkerPool
@@ -128,8 +151,7 @@ impl WorkerManager {
         let method = "POST";
         let (path, query) = Self::updateWorkerPool_details(workerPoolId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for updateWorkerPool
@@ -149,8 +171,7 @@ impl WorkerManager {
         let method = "DELETE";
         let (path, query) = Self::deleteWorkerPool_details(workerPoolId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for deleteWorkerPool
@@ -168,8 +189,7 @@ impl WorkerManager {
         let method = "GET";
         let (path, query) = Self::workerPool_details(workerPoolId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the workerPool endpoint
@@ -199,8 +219,7 @@ impl WorkerManager {
         let method = "GET";
         let (path, query) = Self::listWorkerPools_details(continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Generate an unsigned URL for the listWorkerPools endpoint
@@ -215,6 +234,13 @@ impl WorkerManager {
         self.0.make_signed_url(path, query, ttl)
     }
 
+    /// Stream all pages of results from the listWorkerPools endpoint, following `continuationToken`
+    pub fn listWorkerPools_paginated<'a>(&'a self, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listWorkerPools_details(None, limit);
+        self.0.paginate(method, path, query)
+    }
+
     /// Determine the HTTP request details for listWorkerPools
     fn listWorkerPools_details<'a>(continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (&'static str, Option<Vec<(&'static str, &'a str)>>) {
         let path = "worker-pools";
@@ -244,8 +270,7 @@ impl WorkerManager {
         let method = "POST";
         let (path, query) = Self::reportWorkerError_details(workerPoolId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for reportWorkerError
@@ -263,8 +288,7 @@ impl WorkerManager {
         let method = "GET";
         let (path, query) = Self::listWorkerPoolErrors_details(workerPoolId, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listWorkerPoolErrors endpoint
@@ -279,6 +303,13 @@ impl WorkerManager {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listWorkerPoolErrors endpoint, following `continuationToken`
+    pub fn listWorkerPoolErrors_paginated<'a>(&'a self, workerPoolId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listWorkerPoolErrors_details(workerPoolId, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listWorkerPoolErrors
     fn listWorkerPoolErrors_details<'a>(workerPoolId: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("worker-pool-errors/{}", urlencode(workerPoolId));
@@ -300,8 +331,7 @@ impl WorkerManager {
         let method = "GET";
         let (path, query) = Self::listWorkersForWorkerGroup_details(workerPoolId, workerGroup, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listWorkersForWorkerGroup endpoint
@@ -316,6 +346,13 @@ impl WorkerManager {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listWorkersForWorkerGroup endpoint, following `continuationToken`
+    pub fn listWorkersForWorkerGroup_paginated<'a>(&'a self, workerPoolId: &'a str, workerGroup: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listWorkersForWorkerGroup_details(workerPoolId, workerGroup, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listWorkersForWorkerGroup
     fn listWorkersForWorkerGroup_details<'a>(workerPoolId: &'a str, workerGroup: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("workers/{}:/{}", urlencode(workerPoolId), urlencode(workerGroup));
@@ -337,8 +374,7 @@ impl WorkerManager {
         let method = "GET";
         let (path, query) = Self::worker_details(workerPoolId, workerGroup, workerId);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the worker endpoint
@@ -373,8 +409,7 @@ impl WorkerManager {
         let method = "PUT";
         let (path, query) = Self::createWorker_details(workerPoolId, workerGroup, workerId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for createWorker
@@ -398,8 +433,7 @@ impl WorkerManager {
         let method = "POST";
         let (path, query) = Self::updateWorker_details(workerPoolId, workerGroup, workerId);
         let body = Some(payload);
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Determine the HTTP request details for updateWorker
@@ -441,8 +475,7 @@ impl WorkerManager {
         let method = "GET";
         let (path, query) = Self::listWorkersForWorkerPool_details(workerPoolId, continuationToken, limit);
         let body = None;
-        let resp = self.0.request(method, &path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, &path, query, body).await
     }
 
     /// Generate an unsigned URL for the listWorkersForWorkerPool endpoint
@@ -457,6 +490,13 @@ impl WorkerManager {
         self.0.make_signed_url(&path, query, ttl)
     }
 
+    /// Stream all pages of results from the listWorkersForWorkerPool endpoint, following `continuationToken`
+    pub fn listWorkersForWorkerPool_paginated<'a>(&'a self, workerPoolId: &'a str, limit: Option<&'a str>) -> impl Stream<Item = Result<Value, Error>> + 'a {
+        let method = "GET";
+        let (path, query) = Self::listWorkersForWorkerPool_details(workerPoolId, None, limit);
+        self.0.paginate(method, &path, query)
+    }
+
     /// Determine the HTTP request details for listWorkersForWorkerPool
     fn listWorkersForWorkerPool_details<'a>(workerPoolId: &'a str, continuationToken: Option<&'a str>, limit: Option<&'a str>) -> (String, Option<Vec<(&'static str, &'a str)>>) {
         let path = format!("workers/{}", urlencode(workerPoolId));
@@ -482,8 +522,7 @@ impl WorkerManager {
         let method = "POST";
         let (path, query) = Self::registerWorker_details();
         let body = Some(payload);
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Determine the HTTP request details for registerWorker
@@ -506,8 +545,7 @@ impl WorkerManager {
         let method = "POST";
         let (path, query) = Self::reregisterWorker_details();
         let body = Some(payload);
-        let resp = self.0.request(method, path, query, body).await?;
-        Ok(resp.json().await?)
+        self.0.request_json(method, path, query, body).await
     }
 
     /// Determine the HTTP request details for reregisterWorker
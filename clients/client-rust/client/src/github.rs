@@ -0,0 +1,223 @@
+use crate::Github;
+use anyhow::{bail, Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::TryStreamExt;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A build of a GitHub repository, as returned by the `builds` endpoint.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Build {
+    /// GitHub organization associated with the build
+    pub organization: String,
+
+    /// GitHub repository associated with the build
+    pub repository: String,
+
+    /// GitHub revision associated with the build
+    pub sha: String,
+
+    /// GitHub status of the build: one of `pending`, `success`, `error`, or `failure`
+    pub state: String,
+
+    /// The task group for the build's tasks
+    pub task_group_id: String,
+
+    /// The type of GitHub event that triggered the build, such as `push` or
+    /// `pull_request.opened`
+    pub event_type: String,
+
+    /// The GitHub webhook delivery ID of that event, or `Unknown`
+    pub event_id: String,
+
+    /// The time at which the build was created, when it became pending
+    pub created: DateTime<Utc>,
+
+    /// The time at which the build was last updated; for a finished build, when it finished
+    pub updated: DateTime<Utc>,
+}
+
+impl Github {
+    /// List builds, following pagination to get all of them, optionally filtered by
+    /// organization, repository, and revision.
+    ///
+    /// This fails if the results do not fit in the number of pages configured with
+    /// [`ClientBuilder::max_pages`](crate::ClientBuilder::max_pages).
+    pub async fn list_builds(
+        &self,
+        organization: Option<&str>,
+        repository: Option<&str>,
+        sha: Option<&str>,
+    ) -> Result<Vec<Build>, Error> {
+        let query = builds_query(organization, repository, sha);
+        self.0
+            .request_all("GET", "builds", query, "builds")
+            .await?
+            .into_iter()
+            .map(parse_build)
+            .collect()
+    }
+
+    /// Find the build for the given task group, among the builds optionally filtered by
+    /// organization and repository, returning None if there is no such build.  Pages of builds
+    /// are only fetched until the build is found, so giving the organization and repository
+    /// makes this much faster.
+    pub async fn find_build(
+        &self,
+        organization: Option<&str>,
+        repository: Option<&str>,
+        task_group_id: &str,
+    ) -> Result<Option<Build>, Error> {
+        let pages = self.builds_paginated(None, organization, repository, None);
+        futures_util::pin_mut!(pages);
+
+        while let Some(mut page) = pages.try_next().await? {
+            let builds = match page.get_mut("builds").map(Value::take) {
+                Some(Value::Array(builds)) => builds,
+                _ => bail!("builds response has no array of builds"),
+            };
+            for build in builds {
+                if build.get("taskGroupId").and_then(Value::as_str) == Some(task_group_id) {
+                    return parse_build(build).map(Some);
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Build the query for the `builds` endpoint with the given filters.
+fn builds_query<'a>(
+    organization: Option<&'a str>,
+    repository: Option<&'a str>,
+    sha: Option<&'a str>,
+) -> Option<Vec<(&'a str, &'a str)>> {
+    let query: Vec<_> = vec![
+        ("organization", organization),
+        ("repository", repository),
+        ("sha", sha),
+    ]
+    .into_iter()
+    .filter_map(|(k, v)| v.map(|v| (k, v)))
+    .collect();
+    if query.is_empty() {
+        None
+    } else {
+        Some(query)
+    }
+}
+
+fn parse_build(build: Value) -> Result<Build, Error> {
+    serde_json::from_value(build).context("Invalid build in builds response")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::json_response;
+    use crate::ClientBuilder;
+    use httptest::{matchers::*, Expectation, Server};
+    use serde_json::json;
+
+    const BUILDS_PATH: &str = "/api/github/v1/builds";
+
+    fn build(task_group_id: &str, state: &str) -> Value {
+        json!({
+            "organization": "my-org",
+            "repository": "my-repo",
+            "sha": "0123456789012345678901234567890123456789",
+            "state": state,
+            "taskGroupId": task_group_id,
+            "eventType": "push",
+            "eventId": "Unknown",
+            "created": "2030-01-01T00:00:00.000Z",
+            "updated": "2030-01-01T00:10:00.000Z",
+        })
+    }
+
+    fn github(server: &Server) -> Result<Github, Error> {
+        let root_url = format!("http://{}", server.addr());
+        Github::new(ClientBuilder::new(&root_url))
+    }
+
+    /// Expect two pages of builds, filtered by organization and repository
+    fn expect_pages(server: &Server) {
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", BUILDS_PATH),
+                request::query(url_decoded(contains(("organization", "my-org")))),
+                request::query(url_decoded(contains(("repository", "my-repo")))),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "builds": [build("tg1", "success")],
+                "continuationToken": "page2",
+            }))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", BUILDS_PATH),
+                request::query(url_decoded(contains(("continuationToken", "page2")))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "builds": [build("tg2", "pending")],
+            }))),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_builds() -> Result<(), Error> {
+        let server = Server::run();
+        expect_pages(&server);
+
+        let builds = github(&server)?
+            .list_builds(Some("my-org"), Some("my-repo"), None)
+            .await?;
+        assert_eq!(builds.len(), 2);
+        assert_eq!(builds[0].task_group_id, "tg1");
+        assert_eq!(builds[0].state, "success");
+        assert_eq!(builds[0].event_type, "push");
+        assert_eq!(builds[1].task_group_id, "tg2");
+        assert_eq!(
+            builds[1].created,
+            "2030-01-01T00:00:00Z".parse::<DateTime<Utc>>()?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_build() -> Result<(), Error> {
+        let server = Server::run();
+        expect_pages(&server);
+
+        let build = github(&server)?
+            .find_build(Some("my-org"), Some("my-repo"), "tg2")
+            .await?;
+        assert_eq!(build.map(|b| b.state), Some("pending".to_owned()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_build_not_found() -> Result<(), Error> {
+        let server = Server::run();
+        expect_pages(&server);
+
+        let build = github(&server)?
+            .find_build(Some("my-org"), Some("my-repo"), "tg3")
+            .await?;
+        assert_eq!(build, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_builds_query() {
+        assert_eq!(builds_query(None, None, None), None);
+        assert_eq!(
+            builds_query(Some("my-org"), None, Some("abc")),
+            Some(vec![("organization", "my-org"), ("sha", "abc")])
+        );
+    }
+}
@@ -0,0 +1,153 @@
+/*!
+Helpers for common operations that span more than one Taskcluster service.
+*/
+use crate::util::context_if_not_found;
+use crate::{ClientBuilder, Index, Queue};
+use anyhow::{anyhow, Error};
+use bytes::Bytes;
+
+/// Find the task indexed at the given namespace, and download the named artifact from its latest
+/// run.
+///
+/// If no task is indexed at the namespace, or the task has no such artifact, the error has
+/// status code 404 (see [`err_status_code`](crate::err_status_code)), and its message
+/// identifies which lookup failed.
+///
+/// ```no_run
+/// # use anyhow::Result;
+/// # async fn f() -> Result<()> {
+/// use taskcluster::helpers::find_artifact_from_index;
+///
+/// let data = find_artifact_from_index(
+///     "https://tc.example.com",
+///     "project.my-project.latest",
+///     "public/build/target.tar.gz",
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn find_artifact_from_index<CB: Into<ClientBuilder>>(
+    client_builder: CB,
+    namespace: &str,
+    artifact: &str,
+) -> Result<Bytes, Error> {
+    let client_builder = client_builder.into();
+    let index = Index::new(client_builder.clone())?;
+    let queue = Queue::new(client_builder)?;
+
+    let task = index
+        .findTask(namespace)
+        .await
+        .map_err(|err| context_if_not_found(err, || format!("No task indexed at {}", namespace)))?;
+    let task_id = task
+        .get("taskId")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| anyhow!("Index response for {} has no taskId", namespace))?;
+
+    queue
+        .download_latest_artifact(task_id, artifact)
+        .await
+        .map_err(|err| {
+            context_if_not_found(err, || {
+                format!("Artifact {} not found on task {}", artifact, task_id)
+            })
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err_status_code, Retry, StatusCode};
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use std::time::Duration;
+
+    const FIND_TASK_PATH: &str = "/api/index/v1/task/project.my-project.latest";
+    const ARTIFACT_PATH: &str = "/api/queue/v1/task/abc/artifacts/public%2Fdata.txt";
+
+    fn client_builder(server: &Server) -> ClientBuilder {
+        ClientBuilder::new(format!("http://{}", server.addr())).retry(Retry {
+            retries: 0,
+            max_delay: Duration::from_millis(1),
+            ..Default::default()
+        })
+    }
+
+    fn find_task_response() -> impl Responder {
+        status_code(200)
+            .append_header("Content-Type", "application/json")
+            .body(r#"{"namespace": "project.my-project.latest", "taskId": "abc"}"#)
+    }
+
+    #[tokio::test]
+    async fn test_find_artifact() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", FIND_TASK_PATH))
+                .respond_with(find_task_response()),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH))
+                .respond_with(status_code(200).body("hello, world")),
+        );
+
+        let data = find_artifact_from_index(
+            client_builder(&server),
+            "project.my-project.latest",
+            "public/data.txt",
+        )
+        .await?;
+        assert_eq!(&data[..], b"hello, world");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_artifact_no_task() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", FIND_TASK_PATH))
+                .respond_with(status_code(404)),
+        );
+
+        let err = find_artifact_from_index(
+            client_builder(&server),
+            "project.my-project.latest",
+            "public/data.txt",
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err_status_code(&err), Some(StatusCode::NOT_FOUND));
+        assert_eq!(
+            err.to_string(),
+            "No task indexed at project.my-project.latest"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_artifact_no_artifact() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", FIND_TASK_PATH))
+                .respond_with(find_task_response()),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", ARTIFACT_PATH))
+                .respond_with(status_code(404)),
+        );
+
+        let err = find_artifact_from_index(
+            client_builder(&server),
+            "project.my-project.latest",
+            "public/data.txt",
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err_status_code(&err), Some(StatusCode::NOT_FOUND));
+        assert_eq!(
+            err.to_string(),
+            "Artifact public/data.txt not found on task abc"
+        );
+        Ok(())
+    }
+}
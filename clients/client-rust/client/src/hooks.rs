@@ -0,0 +1,361 @@
+use crate::{Hooks, Queue};
+use anyhow::{anyhow, bail, Context, Error};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use std::time::{Duration, Instant};
+
+/// The initial interval between polls of a triggered task's status
+const POLL_INITIAL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The maximum interval between polls of a triggered task's status
+const POLL_MAX_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The status of a hook, as returned by the `getHookStatus` endpoint.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookStatus {
+    /// The outcome of the last time the hook fired
+    pub last_fire: LastFire,
+
+    /// The next time the hook's task is scheduled to be created, if it has a schedule
+    #[serde(default)]
+    pub next_scheduled_date: Option<DateTime<Utc>>,
+}
+
+/// The outcome of the last time a hook fired, in a [`HookStatus`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "result")]
+pub enum LastFire {
+    /// The hook has not fired, such as when it is new
+    #[serde(rename = "no-fire")]
+    NoFire,
+
+    /// The hook fired and created a task
+    #[serde(rename = "success")]
+    SuccessfulFire {
+        /// The taskId of the created task
+        #[serde(rename = "taskId")]
+        task_id: String,
+
+        /// The time at which the task was created
+        time: DateTime<Utc>,
+    },
+
+    /// The hook failed to fire
+    #[serde(rename = "error")]
+    Error {
+        /// The error that occurred, typically an API error with `code` and `message`
+        error: Value,
+
+        /// The time at which the hook fired
+        time: DateTime<Utc>,
+    },
+}
+
+impl Hooks {
+    /// Get the status of a hook, as for `getHookStatus`, as a typed [`HookStatus`].
+    pub async fn hook_status(
+        &self,
+        hook_group_id: &str,
+        hook_id: &str,
+    ) -> Result<HookStatus, Error> {
+        let status = self.getHookStatus(hook_group_id, hook_id).await?;
+        serde_json::from_value(status).context("Invalid hook status in getHookStatus response")
+    }
+
+    /// Trigger a hook, as for `triggerHook`, and wait for the resulting task to finish,
+    /// returning its final status as given by `queue.status`.  A task has finished when it is
+    /// `completed`, `failed`, or `exception`.  If the hook did not create a task, this returns
+    /// None.
+    ///
+    /// The task's status is polled with the given `queue`, at increasing intervals of up to 30
+    /// seconds, with a last poll at the end of `timeout`.  If the task has not finished by then,
+    /// this returns an error.  Polling stops promptly if the queue client's
+    /// [`cancellation_token`](crate::ClientBuilder::cancellation_token) is cancelled.
+    pub async fn trigger_and_wait(
+        &self,
+        queue: &Queue,
+        hook_group_id: &str,
+        hook_id: &str,
+        payload: &Value,
+        timeout: Duration,
+    ) -> Result<Option<Value>, Error> {
+        self.trigger_and_wait_with_interval(
+            queue,
+            hook_group_id,
+            hook_id,
+            payload,
+            timeout,
+            POLL_INITIAL_INTERVAL,
+        )
+        .await
+    }
+
+    async fn trigger_and_wait_with_interval(
+        &self,
+        queue: &Queue,
+        hook_group_id: &str,
+        hook_id: &str,
+        payload: &Value,
+        timeout: Duration,
+        initial_interval: Duration,
+    ) -> Result<Option<Value>, Error> {
+        let deadline = Instant::now() + timeout;
+        let res = self.triggerHook(hook_group_id, hook_id, payload).await?;
+        let task_id = match res.get("taskId") {
+            Some(task_id) => task_id
+                .as_str()
+                .ok_or_else(|| anyhow!("triggerHook response has an invalid taskId"))?,
+            None => return Ok(None),
+        };
+
+        let mut interval = initial_interval;
+        loop {
+            let status = queue.status(task_id).await?;
+            let state = status
+                .get("status")
+                .and_then(|s| s.get("state"))
+                .and_then(|s| s.as_str())
+                .ok_or_else(|| anyhow!("Status of task {} has no state", task_id))?;
+            if let "completed" | "failed" | "exception" = state {
+                return Ok(Some(status));
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                bail!("Task {} did not finish within {:?}", task_id, timeout);
+            }
+            // sleep no later than the deadline, so the last poll is made at the deadline
+            let sleep = std::cmp::min(interval, deadline - now);
+            queue.0.unless_cancelled(tokio::time::sleep(sleep)).await?;
+            interval = std::cmp::min(interval * 2, POLL_MAX_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::json_response;
+    use crate::ClientBuilder;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::json;
+
+    const TRIGGER_PATH: &str = "/api/hooks/v1/hooks/proj/my-hook/trigger";
+    const STATUS_PATH: &str = "/api/queue/v1/task/abc/status";
+
+    fn status(state: &str) -> Value {
+        json!({"status": {"taskId": "abc", "state": state}})
+    }
+
+    fn clients(server: &Server) -> Result<(Hooks, Queue), Error> {
+        let root_url = format!("http://{}", server.addr());
+        Ok((
+            Hooks::new(ClientBuilder::new(&root_url))?,
+            Queue::new(ClientBuilder::new(&root_url))?,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_hook_status() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "GET",
+                "/api/hooks/v1/hooks/proj/my-hook/status",
+            ))
+            .times(1)
+            .respond_with(json_response(json!({
+                "lastFire": {"result": "no-fire"},
+                "nextScheduledDate": "2030-01-01T00:00:00.000Z",
+            }))),
+        );
+        let (hooks, _) = clients(&server)?;
+
+        let status = hooks.hook_status("proj", "my-hook").await?;
+        assert_eq!(
+            status,
+            HookStatus {
+                last_fire: LastFire::NoFire,
+                next_scheduled_date: Some("2030-01-01T00:00:00Z".parse()?),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hook_status_successful_fire() -> Result<(), Error> {
+        let status: HookStatus = serde_json::from_value(json!({
+            "lastFire": {
+                "result": "success",
+                "taskId": "abc",
+                "time": "2030-01-01T00:00:00.000Z",
+            },
+        }))?;
+        assert_eq!(
+            status,
+            HookStatus {
+                last_fire: LastFire::SuccessfulFire {
+                    task_id: "abc".to_owned(),
+                    time: "2030-01-01T00:00:00Z".parse()?,
+                },
+                next_scheduled_date: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hook_status_error() -> Result<(), Error> {
+        let status: HookStatus = serde_json::from_value(json!({
+            "lastFire": {
+                "result": "error",
+                "error": {"code": "InsufficientScopes", "message": "no scopes"},
+                "time": "2030-01-01T00:00:00.000Z",
+            },
+        }))?;
+        assert_eq!(
+            status.last_fire,
+            LastFire::Error {
+                error: json!({"code": "InsufficientScopes", "message": "no scopes"}),
+                time: "2030-01-01T00:00:00Z".parse()?,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_hook_status_unknown_result() {
+        let status = serde_json::from_value::<HookStatus>(json!({
+            "lastFire": {"result": "exploded"},
+        }));
+        assert!(status.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_trigger_and_wait() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", TRIGGER_PATH),
+                request::body(json_decoded(eq(json!({"x": 1})))),
+            ])
+            .times(1)
+            .respond_with(json_response(
+                json!({"taskId": "abc", "status": {"taskId": "abc"}}),
+            )),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", STATUS_PATH))
+                .times(3)
+                .respond_with(cycle![
+                    json_response(status("pending")),
+                    json_response(status("running")),
+                    json_response(status("completed")),
+                ]),
+        );
+
+        let (hooks, queue) = clients(&server)?;
+        let res = hooks
+            .trigger_and_wait_with_interval(
+                &queue,
+                "proj",
+                "my-hook",
+                &json!({"x": 1}),
+                Duration::from_secs(10),
+                Duration::from_millis(1),
+            )
+            .await?;
+        assert_eq!(res, Some(status("completed")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_trigger_and_wait_finished_before_deadline() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", TRIGGER_PATH))
+                .times(1)
+                .respond_with(json_response(json!({"taskId": "abc"}))),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", STATUS_PATH))
+                .times(3)
+                .respond_with(cycle![
+                    json_response(status("running")),
+                    json_response(status("running")),
+                    json_response(status("completed")),
+                ]),
+        );
+
+        // polls at 0ms and 100ms; the next doubled interval would poll at 300ms, past the
+        // deadline, so the last poll is made at the deadline instead
+        let (hooks, queue) = clients(&server)?;
+        let res = hooks
+            .trigger_and_wait_with_interval(
+                &queue,
+                "proj",
+                "my-hook",
+                &json!({}),
+                Duration::from_millis(250),
+                Duration::from_millis(100),
+            )
+            .await?;
+        assert_eq!(res, Some(status("completed")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_trigger_and_wait_no_task() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", TRIGGER_PATH))
+                .times(1)
+                .respond_with(json_response(json!({}))),
+        );
+
+        let (hooks, queue) = clients(&server)?;
+        let res = hooks
+            .trigger_and_wait(
+                &queue,
+                "proj",
+                "my-hook",
+                &json!({}),
+                Duration::from_secs(10),
+            )
+            .await?;
+        assert_eq!(res, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_trigger_and_wait_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", TRIGGER_PATH))
+                .times(1)
+                .respond_with(json_response(json!({"taskId": "abc"}))),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", STATUS_PATH))
+                .times(1..)
+                .respond_with(json_response(status("running"))),
+        );
+
+        let (hooks, queue) = clients(&server)?;
+        let err = hooks
+            .trigger_and_wait_with_interval(
+                &queue,
+                "proj",
+                "my-hook",
+                &json!({}),
+                Duration::from_millis(100),
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Task abc did not finish"));
+        Ok(())
+    }
+}
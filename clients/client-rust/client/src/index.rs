@@ -0,0 +1,150 @@
+use crate::Index;
+use anyhow::{bail, Error};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+/// An entry to insert in the index with [`Index::insert_task`](crate::Index::insert_task).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedTask {
+    /// The task to index
+    pub task_id: String,
+
+    /// The rank of this entry.  Of the tasks indexed at the same namespace, the one with the
+    /// highest rank is kept; if the ranks are equal, the latest is kept.
+    pub rank: f64,
+
+    /// Arbitrary task-specific data, which must be a JSON object of less than 32KB
+    pub data: Value,
+
+    /// The time at which this entry expires from the index
+    pub expires: DateTime<Utc>,
+}
+
+/// Characters other than ASCII alphanumerics allowed in each part of an index namespace,
+/// matching the index service's `namespaceFormat`
+const NAMESPACE_PUNCTUATION: &str = "_!~*'()%-";
+
+/// Check that the given string is a valid index namespace: a non-empty, dot-separated sequence
+/// of non-empty parts, each of ASCII alphanumerics and the characters `_!~*'()%-`.
+fn validate_namespace(namespace: &str) -> Result<(), Error> {
+    let valid_part = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || NAMESPACE_PUNCTUATION.contains(c))
+    };
+    if !namespace.split('.').all(valid_part) {
+        bail!(
+            "Invalid index namespace {:?}: namespaces are dot-separated parts, each consisting of \
+             letters, digits, and the characters {}",
+            namespace,
+            NAMESPACE_PUNCTUATION
+        );
+    }
+    Ok(())
+}
+
+impl Index {
+    /// Insert a task into the index at the given namespace, as for `insertTask`, returning the
+    /// indexed task.  The namespace is checked before the request is sent, so that an invalid
+    /// namespace fails with a clear error.  If the index already has a task of higher rank at
+    /// this namespace, the task is not indexed, but this still succeeds.
+    pub async fn insert_task(&self, namespace: &str, task: &IndexedTask) -> Result<Value, Error> {
+        validate_namespace(namespace)?;
+        if !task.data.is_object() {
+            bail!("Indexed task data must be a JSON object");
+        }
+        self.insertTask(namespace, &serde_json::to_value(task)?)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ClientBuilder;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::json;
+
+    fn indexed_task() -> IndexedTask {
+        IndexedTask {
+            task_id: "SJOKM3WJQ2-yvTkDEB9gXQ".into(),
+            rank: 3.0,
+            data: json!({"build": 42}),
+            expires: "2030-01-01T00:00:00Z".parse().unwrap(),
+        }
+    }
+
+    macro_rules! validate_namespace_tests {
+        ($($name:ident: $input:expr, $valid:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(validate_namespace($input).is_ok(), $valid);
+            }
+        )*
+        }
+    }
+
+    validate_namespace_tests! {
+        single_part: "project", true,
+        dotted: "project.app.v1-2_3", true,
+        punctuation: "a.b!~*'()%-_", true,
+        empty: "", false,
+        empty_part: "project..app", false,
+        leading_dot: ".project", false,
+        trailing_dot: "project.", false,
+        slash: "project/app", false,
+        space: "project app", false,
+        unicode: "caf\u{e9}", false,
+    }
+
+    #[tokio::test]
+    async fn test_insert_task() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("PUT", "/api/index/v1/task/project.app.latest"),
+                request::body(json_decoded(eq(json!({
+                    "taskId": "SJOKM3WJQ2-yvTkDEB9gXQ",
+                    "rank": 3.0,
+                    "data": {"build": 42},
+                    "expires": "2030-01-01T00:00:00Z",
+                })))),
+            ])
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(json!({"namespace": "project.app.latest"}).to_string()),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let index = Index::new(ClientBuilder::new(&root_url))?;
+
+        let res = index
+            .insert_task("project.app.latest", &indexed_task())
+            .await?;
+        assert_eq!(res, json!({"namespace": "project.app.latest"}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_insert_task_invalid_namespace() -> Result<(), Error> {
+        // no request is expected
+        let server = Server::run();
+        let root_url = format!("http://{}", server.addr());
+        let index = Index::new(ClientBuilder::new(&root_url))?;
+
+        let err = index
+            .insert_task("project/app", &indexed_task())
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Invalid index namespace \"project/app\""));
+        Ok(())
+    }
+}
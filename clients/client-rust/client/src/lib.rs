@@ -21,6 +21,10 @@ spepcific to the API endpoints for that service.  Each has a `new` associated fu
 takes an `Into<ClientBuilder>`. As a shortcut, you may pass a string to `new` that will be treated
 as a root URL.
 
+When an application uses several services, [`Taskcluster`](crate::Taskcluster) builds clients for
+all of them from a single `ClientBuilder`, with accessors such as `queue()` and `auth()`.  These
+clients share one HTTP client, and with it a pool of connections to the deployment.
+
 Here is a simple setup and use of an un-authenticated client:
 
 ```
@@ -77,6 +81,16 @@ Ok(())
 # }
 ```
 
+Credentials stored by the [Taskcluster CLI](https://github.com/taskcluster/taskcluster/tree/main/clients/client-shell)
+with `taskcluster config` can be read with `Credentials::from_cli_config`, which returns the root
+URL along with the credentials, or `None` if the CLI has not been configured.  It fails if the
+configuration restricts the credentials with `authorizedScopes`; pass those to
+`ClientBuilder::authorized_scopes` instead.
+
+Temporary credentials expire, after which every request made with them fails with 401
+Unauthorized.  A client built with `ClientBuilder::credentials_refresh` calls the given function
+for fresh credentials when that happens, and retries the request with them.
+
 ### Authorized Scopes
 
 If you wish to perform requests on behalf of a third-party that has smaller set
@@ -118,6 +132,11 @@ Ok(())
 # }
 ```
 
+To make a series of requests with narrower scopes using an existing client, create a copy of it
+with `with_authorized_scopes`, such as `client.with_authorized_scopes(&["just:one-scope"])?`.
+The copy shares the original client's credentials and connections.  This fails for a client
+without credentials.
+
 ## Calling API Methods
 
 API methods are available as methods on the corresponding client object.  They are capitalized in
@@ -172,14 +191,64 @@ loop {
 # }
 ```
 
+For methods that support pagination, a `<method>_paginated` variant returns a
+[`Stream`](futures_util::stream::Stream) of pages, handling the continuation tokens automatically:
+
+```
+# use httptest::{matchers::*, responders::*, Expectation, Server};
+# use tokio;
+# use anyhow::Result;
+# #[tokio::main]
+# async fn main() -> Result<()> {
+# let server = Server::run();
+# server.expect(
+#    Expectation::matching(request::method_path("GET", "/api/auth/v1/clients/"))
+#   .respond_with(
+#       status_code(200)
+#       .append_header("Content-Type", "application/json")
+#       .body("{\"clients\": []}"))
+# );
+# let root_url = format!("http://{}", server.addr());
+use futures_util::stream::TryStreamExt;
+use taskcluster::{Auth, ClientBuilder};
+let auth = Auth::new(ClientBuilder::new(&root_url))?;
+let mut pages = Box::pin(auth.listClients_paginated(None, Some("10")));
+while let Some(res) = pages.try_next().await? {
+    for client in res.get("clients").unwrap().as_array().unwrap() {
+        println!("{:?}", client);
+    }
+}
+# Ok(())
+# }
+```
+
 ### Error Handling
 
-All 5xx (server error) responses are automatically retried.
+All 5xx (server error) responses are automatically retried, as are 429 (Too Many Requests) responses.
+When such a response has a `Retry-After` header, such as a 503 during maintenance, the retry waits for the duration it gives, up to `Retry::max_delay`, in place of the usual backoff interval.
+Retrying `Queue::createTask` is safe: the queue treats a call that repeats an existing task's definition as success, and responds with 409 `RequestConflict` only if the definition differs.
+[`ClientBuilder::retry_predicate`](crate::ClientBuilder::retry_predicate) replaces this choice of which responses to retry with a function of the response.
 All 4xx (client error) responses are converted to `Result::Err`.
-All other responses are treated as successful responses.
-Note that this includes 3xx (redirection) responses; the client does not automatically follow such redirects.
+The per-service methods also treat any other non-2xx response as an error, except for `Queue::getArtifact` and `Queue::getLatestArtifact`, which return the body of the queue's 303 redirect, giving the artifact's URL.
+The low-level [`Client::request`](crate::Client::request) method treats all other responses as successful, including 3xx (redirection) responses; the client does not automatically follow such redirects.
+Use [`Client::request_json`](crate::Client::request_json) to get the same behavior as the per-service methods.
+
+Client methods return `anyhow::Error`, but this can be downcast to an [`ApiError`](crate::ApiError) if needed.
+A 401 response rejecting a request because the local clock is too far from the server's is returned as [`ApiError::ClockSkew`](crate::ApiError::ClockSkew), giving both times, since it is otherwise hard to tell apart from other authentication failures.
+That type distinguishes error responses, including the status code and the JSON error body, from transport errors such as timeouts.
+
+```
+# use anyhow::Result;
+use taskcluster::ApiError;
+# fn check(err: anyhow::Error) -> Result<()> {
+match err.downcast_ref::<ApiError>() {
+    Some(ApiError::Status { code: 404, .. }) => println!("not found"),
+    _ => return Err(err),
+}
+# Ok(())
+# }
+```
 
-Client methods return `anyhow::Error`, but this can be downcast to a `reqwest::Error` if needed.
 As a shortcut for the common case of getting the HTTP status code for an error, use [`err_status_code`](crate::err_status_code).
 The `reqwest::StatusCode` type that this returns is re-exported from this crate.
 
@@ -213,12 +282,96 @@ assert!(resp.status().is_success());
 # }
 ```
 
+To override the client's timeout, authorized scopes, or trace ID for a single request, or to give
+it a deadline, pass [`RequestOptions`](crate::RequestOptions) to
+[`Client::request_with_options`](crate::Client::request_with_options).
+
+## Compression
+
+Responses compressed with gzip or brotli are decompressed automatically, and the client indicates
+its support for these encodings in each request.  This support can be omitted by disabling the
+`gzip` and `brotli` features, which are enabled by default.
+
+## Blocking Clients
+
+With the `blocking` feature enabled, the [`blocking`](crate::blocking) module provides
+synchronous versions of the service types, for use in programs that do not otherwise use an
+asynchronous runtime.
+
+The blocking types have the generated API methods, along with `health`, `is_alive` and
+`with_authorized_scopes`.  The hand-written helpers on the service types, such as
+`Queue::download_artifact`, `Queue::statuses`, `Queue::create_task_group`,
+`Secrets::get_typed`, and `Secrets::list_all`, are only available asynchronously.
+
+## Typed Clients
+
+With the `typed` feature enabled, the [`typed`](crate::typed) module provides versions of the
+service types whose methods take and return structs generated from the API's schemas, such as
+[`typed::queue::TaskStatusResponse`](crate::typed::queue::TaskStatusResponse), in place of
+`serde_json::Value`.  The `Value`-based types remain available, with or without the feature.
+
+## Task Events
+
+With the `events` feature enabled, the [`events`](crate::events) module can subscribe to events
+for the tasks in a task group or with a given route, such as tasks being completed, over a
+WebSocket connection to the deployment.  This avoids polling `queue.status` to follow tasks.
+
+## Tracing
+
+With the `tracing` feature enabled, each request is made within a
+[`tracing`](https://docs.rs/tracing) span named `taskcluster_request`, with fields for the
+`service`, `method`, `path`, number of `attempts`, final `status`, and `elapsed_ms`.  Each retry
+emits an event within that span, giving the backoff duration and the error that caused the retry.
+
+## Logging
+
+Requests are logged with the [`log`](https://docs.rs/log) crate, so that they are visible with
+any logger configured to show the `taskcluster` targets.  At `debug` level, the method and path of
+each request and the status of each response are logged; at `trace` level, request bodies and
+JSON response bodies are logged too, truncated to a reasonable length.  The `Authorization`
+header is never logged, nor are the bodies of requests to the secrets service, and any
+`accessToken` properties are redacted.
+
+## Concurrency
+
+Clients can be used to make many requests concurrently.  To avoid overwhelming the application or
+the deployment when fanning out many calls, such as creating hundreds of tasks, share a
+[`ConcurrencyLimit`](crate::ConcurrencyLimit) among clients with
+[`ClientBuilder::concurrency_limit`](crate::ClientBuilder::concurrency_limit).  No more than the
+given number of requests made by those clients are then in flight at once.
+
+## Cancellation
+
+Dropping the future returned by any request method, such as when it loses a `tokio::select!`,
+abandons the request, including any retries, without side effects on the client.  To cancel all
+of a client's requests at once, such as when an application is shutting down, give the client a
+[`CancellationToken`](crate::CancellationToken) with
+[`ClientBuilder::cancellation_token`](crate::ClientBuilder::cancellation_token).  Once the token
+is cancelled, in-flight requests and retries fail promptly with
+[`ApiError::Cancelled`](crate::ApiError::Cancelled).
+
+## Testing
+
+With the `mock` feature enabled, the [`mock`](crate::mock) module provides a fake transport that
+can be attached to a client in tests, responding to requests with canned responses instead of
+sending them over the network.
+
+A client built with [`ClientBuilder::dry_run`](crate::ClientBuilder::dry_run) sends `GET` and
+`HEAD` requests as usual, but only logs other requests, returning an empty JSON object as if the
+request had succeeded.  This allows checking what a tool would change without changing anything.
+
 ## Uploading and Downloading Objects
 
 The [`taskcluster-upload`](https://crates.io/crates/taskcluster-upload) and [`taskcluster-download`](https://crates.io/crates/taskcluster-download) crates contain dedicated support for resilient uploads and downloads to/from the Taskcluster object service.
 This comes in the form of functions that will both interface with the object service API and perform the negotiated upload/download method.
 In all cases, you must supply a pre-configured [`Object`] client, as well as required parameters to the object service API methods.
 
+Queue artifacts can be downloaded with [`Queue::download_artifact`], which follows the redirect
+returned by the queue to the artifact's storage location, or with
+[`Queue::download_artifact_stream`] to read the content without holding it in memory.
+[`Queue::tail_log`] follows a run's live log as it is written, returning a stream of its lines.
+[`helpers::find_artifact_from_index`] combines an index lookup with an artifact download.
+
 ## Generating URLs
 
 To generate a unsigned URL for an API method, use `<method>_url`:
@@ -237,6 +390,29 @@ assert_eq!(url, "https://tc-tests.example.com/api/auth/v1/clients/?prefix=static
 # }
 ```
 
+To generate a signed URL, containing a Hawk "bewit" so that it can be fetched without any further
+authentication (for example, by a browser or `curl`), use `<method>_signed_url`, or
+[`Client::build_signed_url`](crate::Client::build_signed_url) for arbitrary paths:
+
+```
+# use anyhow::Result;
+# fn main() -> Result<()> {
+use std::time::Duration;
+use taskcluster::{ClientBuilder, Credentials, Queue};
+# use std::env;
+# env::set_var("TASKCLUSTER_ROOT_URL", "https://tc-tests.example.com");
+let root_url = env::var("TASKCLUSTER_ROOT_URL").unwrap();
+let creds = Credentials::new("a-client", "a-token");
+let queue = Queue::new(ClientBuilder::new(&root_url).credentials(creds))?;
+let url = queue.getLatestArtifact_signed_url(
+    "G08bnnBuR6yDhDLJkJ6KiA",
+    "public/build/target.tar.gz",
+    Duration::from_secs(600))?;
+assert!(url.contains("bewit="));
+# Ok(())
+# }
+```
+
 ## Generating Temporary Credentials
 
 The [`create_named_temp_creds`](crate::Credentials::create_named_temp_creds) method creates
@@ -266,9 +442,22 @@ is not recommended.
 ## Generating Timestamps
 
 Taskcluster APIs expects ISO 8601 timestamps, of the sort generated by the JS `Date.toJSON` method.
-The [`chrono`](https://docs.rs/chrono/) crate supports generating compatible timestamps if included with the `serde` feature.
+The [`time`](crate::time) module duplicates the functionality of the `fromNow` function from
+other Taskcluster client libraries, accepting the same time expressions:
+
+```
+# use anyhow::Result;
+# fn main() -> Result<()> {
+use taskcluster::time;
+use serde_json::json;
+
+let json = json!({ "created": time::now(), "expires": time::from_now("2 days")? });
+# Ok(())
+# }
+```
+
+The [`chrono`](https://docs.rs/chrono/) crate also supports generating compatible timestamps if included with the `serde` feature.
 This crate re-exports `chrono` with that feature enabled.
-To duplicate the functionality of the `fromNow` function from other Taskcluster client libraries, use something like this:
 
 ```
 use taskcluster::chrono::{DateTime, Utc, Duration};
@@ -281,22 +470,66 @@ let json = json!({ "expires": expires });
 ## Generating SlugIDs
 
 Use the [slugid](https://crates.io/crates/slugid) crate to create slugIds (such as for a taskId).
+[`Queue::create_task_autoid`] creates a task with a newly generated taskId, returning the taskId
+along with the response.
 
 */
 
+mod artifacts;
+mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
+mod concurrency;
 mod credentials;
+mod error;
+#[cfg(feature = "events")]
+pub mod events;
 mod generated;
+mod github;
+pub mod helpers;
+mod hooks;
+mod index;
+mod logs;
+#[cfg(feature = "mock")]
+pub mod mock;
+mod notify;
+mod purgecache;
+mod reclaim;
 pub mod retry;
+mod secrets;
+mod tasks;
+#[cfg(test)]
+mod test_util;
+pub mod time;
+#[cfg(feature = "typed")]
+pub mod typed;
 mod util;
+mod workers;
 
 // re-export
 pub use chrono;
 
 // internal re-exports
-pub use client::{Client, ClientBuilder};
+pub use artifacts::Artifact;
+pub use auth::{AuthResult, AuthenticationFailed, CachingAuth, HawkRequest};
+pub use client::{
+    Client, ClientBuilder, PingResponse, RequestMetrics, RequestOptions, RequestStats,
+};
+pub use concurrency::ConcurrencyLimit;
 pub use credentials::Credentials;
+pub use error::{ApiError, CredentialsError};
 pub use generated::*;
+pub use github::Build;
+pub use hooks::{HookStatus, LastFire};
+pub use index::IndexedTask;
+pub use notify::{
+    EmailLink, EmailRequest, EmailTemplate, MatrixRequest, NotificationAddress, NotificationType,
+    PulseRequest,
+};
+pub use reclaim::{Reclaimer, TaskClaim};
 pub use reqwest::StatusCode;
 pub use retry::Retry;
+pub use tokio_util::sync::CancellationToken;
 pub use util::err_status_code;
+pub use workers::{RegisteredWorker, Worker};
@@ -0,0 +1,366 @@
+use crate::util::urlencode;
+use crate::Queue;
+use anyhow::{anyhow, Error};
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, StreamExt};
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// The artifact containing the live log of a task run
+const LIVE_LOG: &str = "public/logs/live.log";
+
+/// The time to wait before reconnecting to a live log whose connection ended while the run is
+/// still running
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+type ByteStream = Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>> + Send>>;
+
+/// The state of a [`Queue::tail_log`] stream
+struct LogTail {
+    queue: Queue,
+    task_id: String,
+    run_id: String,
+
+    /// The body of the current connection to the log, if connected
+    body: Option<ByteStream>,
+
+    /// The number of bytes of the log received so far, over all connections
+    received: usize,
+
+    /// The number of bytes at the start of the current connection's body that were already
+    /// received over a previous connection
+    skip: usize,
+
+    /// Received content following the last complete line
+    partial: Vec<u8>,
+
+    /// Complete lines not yet returned
+    lines: VecDeque<String>,
+
+    done: bool,
+}
+
+impl LogTail {
+    /// Get the next line of the log, connecting and reconnecting as necessary.
+    async fn next_line(&mut self) -> Option<Result<String, Error>> {
+        loop {
+            if let Some(line) = self.lines.pop_front() {
+                return Some(Ok(line));
+            }
+            if self.done {
+                return None;
+            }
+
+            if self.body.is_none() {
+                match self.connect().await {
+                    Ok(body) => self.body = Some(body),
+                    Err(err) => return self.fail(err),
+                }
+            }
+
+            match self.body.as_mut().unwrap().next().await {
+                Some(Ok(chunk)) => self.receive(&chunk),
+                Some(Err(err)) => {
+                    self.body = None;
+                    match run_running(&self.queue, &self.task_id, &self.run_id).await {
+                        Ok(true) => tokio::time::sleep(RECONNECT_DELAY).await,
+                        Ok(false) => return self.fail(err.into()),
+                        Err(err) => return self.fail(err),
+                    }
+                }
+                None => {
+                    self.body = None;
+                    match run_running(&self.queue, &self.task_id, &self.run_id).await {
+                        // the log is incomplete, so the connection dropped
+                        Ok(true) => tokio::time::sleep(RECONNECT_DELAY).await,
+                        Ok(false) => {
+                            if !self.partial.is_empty() {
+                                let line = log_line(&self.partial);
+                                self.lines.push_back(line);
+                            }
+                            self.done = true;
+                        }
+                        Err(err) => return self.fail(err),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Connect to the log, following the redirect to its current location.  The log has no
+    /// timeout, since it continues for as long as the run, and may be idle for any part of that.
+    async fn connect(&mut self) -> Result<ByteStream, Error> {
+        let path = format!(
+            "task/{}/runs/{}/artifacts/{}",
+            urlencode(&self.task_id),
+            urlencode(&self.run_id),
+            urlencode(LIVE_LOG)
+        );
+        let resp = self.queue.artifact_response(&path, LIVE_LOG, None).await?;
+        self.skip = self.received;
+        Ok(Box::pin(resp.bytes_stream()))
+    }
+
+    /// Add a chunk of the current connection's body, skipping any content that was already
+    /// received.
+    fn receive(&mut self, chunk: &[u8]) {
+        let skipped = self.skip.min(chunk.len());
+        self.skip -= skipped;
+        let chunk = &chunk[skipped..];
+        self.received += chunk.len();
+
+        self.partial.extend_from_slice(chunk);
+        while let Some(end) = self.partial.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=end).collect();
+            self.lines.push_back(log_line(&line));
+        }
+    }
+
+    fn fail(&mut self, err: Error) -> Option<Result<String, Error>> {
+        self.done = true;
+        Some(Err(err.context(format!(
+            "while tailing log of {}/{}",
+            self.task_id, self.run_id
+        ))))
+    }
+}
+
+/// Determine whether the given run is still pending or running, and so its log is not complete.
+async fn run_running(queue: &Queue, task_id: &str, run_id: &str) -> Result<bool, Error> {
+    let res = queue.status(task_id).await?;
+    let runs = res["status"]["runs"]
+        .as_array()
+        .ok_or_else(|| anyhow!("Invalid status response: no runs"))?;
+    Ok(runs.iter().any(|run| {
+        run["runId"].as_u64().map(|id| id.to_string()).as_deref() == Some(run_id)
+            && matches!(run["state"].as_str(), Some("pending") | Some("running"))
+    }))
+}
+
+/// Convert a line of the log to a string, without its line ending.  Invalid UTF-8 is replaced,
+/// rather than failing the whole log.
+fn log_line(mut line: &[u8]) -> String {
+    if line.ends_with(b"\n") {
+        line = &line[..line.len() - 1];
+    }
+    if line.ends_with(b"\r") {
+        line = &line[..line.len() - 1];
+    }
+    String::from_utf8_lossy(line).into_owned()
+}
+
+impl Queue {
+    /// Follow the live log (`public/logs/live.log`) of a specific run of a task, returning a
+    /// stream of its lines, without line endings, as they are written.
+    ///
+    /// The log is fetched as for [`download_artifact`](crate::Queue::download_artifact), but
+    /// without the client's [`timeout`](crate::ClientBuilder::timeout).  While the run is
+    /// running, the response continues until the run finishes.  If the connection ends or fails
+    /// before then, the stream reconnects, skipping the content already returned, and continues
+    /// with the rest of the log.  The stream ends when the log is complete.  A failure to
+    /// connect, or a connection failure after the run has finished, is returned as the last item
+    /// of the stream.
+    pub fn tail_log(
+        &self,
+        task_id: &str,
+        run_id: &str,
+    ) -> impl Stream<Item = Result<String, Error>> {
+        let tail = LogTail {
+            queue: Queue(self.0.duplicate()),
+            task_id: task_id.to_owned(),
+            run_id: run_id.to_owned(),
+            body: None,
+            received: 0,
+            skip: 0,
+            partial: Vec::new(),
+            lines: VecDeque::new(),
+            done: false,
+        };
+        stream::unfold(tail, |mut tail| async move {
+            tail.next_line().await.map(|line| (line, tail))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::json_response;
+    use crate::{err_status_code, ClientBuilder, Retry, StatusCode};
+    use futures_util::stream::TryStreamExt;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::{json, Value};
+
+    const LOG_PATH: &str = "/api/queue/v1/task/abc/runs/0/artifacts/public%2Flogs%2Flive.log";
+    const STATUS_PATH: &str = "/api/queue/v1/task/abc/status";
+
+    fn queue(server: &Server) -> Result<Queue, Error> {
+        let root_url = format!("http://{}", server.addr());
+        Queue::new(ClientBuilder::new(&root_url).retry(Retry {
+            retries: 0,
+            ..Default::default()
+        }))
+    }
+
+    fn status_response(state: &str) -> Value {
+        json!({
+            "status": {
+                "taskId": "abc",
+                "provisionerId": "proj",
+                "workerType": "test",
+                "taskQueueId": "proj/test",
+                "schedulerId": "-",
+                "projectId": "none",
+                "taskGroupId": "grp",
+                "deadline": "2030-01-02T00:00:00.000Z",
+                "expires": "2031-01-01T00:00:00.000Z",
+                "retriesLeft": 5,
+                "state": state,
+                "runs": [
+                    {
+                        "runId": 0,
+                        "state": state,
+                        "reasonCreated": "scheduled",
+                        "scheduled": "2030-01-01T00:00:00.000Z",
+                    },
+                ],
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_tail_log() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", LOG_PATH)).respond_with(
+                status_code(303).insert_header("location", server.url_str("/livelog")),
+            ),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/livelog"),
+                // the log request is not signed
+                request::headers(not(contains(key("authorization")))),
+            ])
+            .respond_with(status_code(200).body("first\r\nsecond\nlast")),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", STATUS_PATH))
+                .times(1)
+                .respond_with(json_response(status_response("completed"))),
+        );
+
+        let lines: Vec<String> = queue(&server)?.tail_log("abc", "0").try_collect().await?;
+        assert_eq!(lines, vec!["first", "second", "last"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tail_log_reconnect() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", LOG_PATH))
+                .times(2)
+                .respond_with(cycle![
+                    status_code(303).insert_header("location", server.url_str("/livelog")),
+                    status_code(303).insert_header("location", server.url_str("/backing")),
+                ]),
+        );
+        // the connection to the live log ends partway through a line, while the run is running
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/livelog"))
+                .times(1)
+                .respond_with(status_code(200).body("one\ntw")),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/backing"))
+                .times(1)
+                .respond_with(status_code(200).body("one\ntwo\nthree\n")),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", STATUS_PATH))
+                .times(2)
+                .respond_with(cycle![
+                    json_response(status_response("running")),
+                    json_response(status_response("completed")),
+                ]),
+        );
+
+        let lines: Vec<String> = queue(&server)?.tail_log("abc", "0").try_collect().await?;
+        assert_eq!(lines, vec!["one", "two", "three"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tail_log_no_timeout() -> Result<(), Error> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // a live log that is idle for longer than the client's timeout
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let log_url = format!("http://{}/livelog", listener.local_addr()?);
+        tokio::spawn(async move {
+            let (mut conn, _) = listener.accept().await.unwrap();
+            let _ = conn.read(&mut [0u8; 4096]).await;
+            conn.write_all(b"HTTP/1.1 200 OK\r\nconnection: close\r\n\r\nfirst\n")
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            conn.write_all(b"second\n").await.unwrap();
+        });
+
+        // the queue, too, is slower to respond than the client's timeout
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", LOG_PATH)).respond_with(
+                delay_and_then(
+                    Duration::from_millis(300),
+                    status_code(303).insert_header("location", log_url.as_str()),
+                ),
+            ),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", STATUS_PATH))
+                .times(1)
+                .respond_with(json_response(status_response("completed"))),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let queue = Queue::new(
+            ClientBuilder::new(&root_url)
+                .retry(Retry {
+                    retries: 0,
+                    ..Default::default()
+                })
+                .timeout(Duration::from_millis(100)),
+        )?;
+
+        let lines: Vec<String> = queue.tail_log("abc", "0").try_collect().await?;
+        assert_eq!(lines, vec!["first", "second"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tail_log_not_found() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", LOG_PATH))
+                .respond_with(status_code(404)),
+        );
+
+        let results: Vec<_> = queue(&server)?.tail_log("abc", "0").collect().await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            err_status_code(results[0].as_ref().unwrap_err()),
+            Some(StatusCode::NOT_FOUND)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_log_line() {
+        assert_eq!(log_line(b"text\n"), "text");
+        assert_eq!(log_line(b"text\r\n"), "text");
+        assert_eq!(log_line(b"text"), "text");
+        assert_eq!(log_line(b"\xfftext\n"), "\u{fffd}text");
+    }
+}
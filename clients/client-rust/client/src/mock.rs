@@ -0,0 +1,240 @@
+/*!
+A fake transport for testing code that uses this crate, without running an HTTP server.  This
+module is only available with the `mock` feature.
+
+A [`MockTransport`] holds canned responses for `(method, path)` pairs, and records the requests
+made.  It is attached to a client with
+[`ClientBuilder::mock`](crate::ClientBuilder::mock), after which all requests made by that
+client, including those made by the per-service methods, are handled by the transport.  Requests
+are still signed as usual.
+
+```
+# use anyhow::Result;
+# #[tokio::main]
+# async fn main() -> Result<()> {
+use serde_json::json;
+use taskcluster::mock::MockTransport;
+use taskcluster::{ClientBuilder, Queue, StatusCode};
+
+let mock = MockTransport::new();
+mock.respond(
+    "GET",
+    "/api/queue/v1/task/G08bnnBuR6yDhDLJkJ6KiA/status",
+    StatusCode::OK,
+    json!({"status": {"state": "completed"}}),
+);
+
+let queue = Queue::new(ClientBuilder::new("https://tc.example.com").mock(mock.clone()))?;
+let res = queue.status("G08bnnBuR6yDhDLJkJ6KiA").await?;
+assert_eq!(res["status"]["state"], json!("completed"));
+
+let requests = mock.requests();
+assert_eq!(requests.len(), 1);
+assert_eq!(requests[0].method, "GET");
+# Ok(())
+# }
+```
+
+Requests for which no response has been registered receive a 404 response.
+*/
+
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// A request handled by a [`MockTransport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRequest {
+    /// The HTTP method, such as `GET`
+    pub method: String,
+
+    /// The full path of the request URL, such as `/api/queue/v1/ping`
+    pub path: String,
+
+    /// The query string of the request URL, if any
+    pub query: Option<String>,
+
+    /// The JSON request body, if any
+    pub body: Option<Value>,
+}
+
+#[derive(Debug, Clone)]
+struct MockResponse {
+    status: StatusCode,
+    body: Value,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    responses: HashMap<(String, String), VecDeque<MockResponse>>,
+    requests: Vec<MockRequest>,
+}
+
+/// A fake transport that responds to requests with canned responses.  Clones of a
+/// `MockTransport` share the same responses and recorded requests.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl MockTransport {
+    /// Create a new `MockTransport` with no registered responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a response for the given method and (full) path.  If several responses are
+    /// registered for the same method and path, they are returned in order, with the last
+    /// repeating indefinitely.
+    pub fn respond(&self, method: &str, path: &str, status: StatusCode, body: Value) {
+        let mut inner = self.inner.lock().unwrap();
+        inner
+            .responses
+            .entry((method.to_uppercase(), path.to_owned()))
+            .or_insert_with(VecDeque::new)
+            .push_back(MockResponse { status, body });
+    }
+
+    /// Get the requests handled by this transport so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.inner.lock().unwrap().requests.clone()
+    }
+
+    /// Handle a request, returning the registered response.
+    pub(crate) fn execute(&self, req: reqwest::Request) -> reqwest::Response {
+        let method = req.method().as_str().to_owned();
+        let path = req.url().path().to_owned();
+        let body = req
+            .body()
+            .and_then(|b| b.as_bytes())
+            .and_then(|b| serde_json::from_slice(b).ok());
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.requests.push(MockRequest {
+            method: method.clone(),
+            path: path.clone(),
+            query: req.url().query().map(|q| q.to_owned()),
+            body,
+        });
+
+        let response = match inner.responses.get_mut(&(method.clone(), path.clone())) {
+            Some(responses) if responses.len() > 1 => responses.pop_front(),
+            Some(responses) => responses.front().cloned(),
+            None => None,
+        };
+        let response = response.unwrap_or_else(|| MockResponse {
+            status: StatusCode::NOT_FOUND,
+            body: json!({
+                "code": "ResourceNotFound",
+                "message": format!("No mock response for {} {}", method, path),
+            }),
+        });
+
+        let mut res = http::Response::new(response.body.to_string());
+        *res.status_mut() = response.status;
+        res.headers_mut().insert(
+            http::header::CONTENT_TYPE,
+            http::HeaderValue::from_static("application/json"),
+        );
+        res.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err_status_code, ClientBuilder, Retry, StatusCode};
+    use anyhow::Error;
+    use std::time::Duration;
+
+    fn client(mock: &MockTransport) -> Result<crate::Client, Error> {
+        ClientBuilder::new("https://tc-tests.example.com")
+            .path_prefix("api/queue/v1/")
+            .retry(Retry {
+                retries: 2,
+                max_delay: Duration::from_millis(1),
+                ..Default::default()
+            })
+            .mock(mock.clone())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_response() -> Result<(), Error> {
+        let mock = MockTransport::new();
+        mock.respond(
+            "GET",
+            "/api/queue/v1/ping",
+            StatusCode::OK,
+            json!({"alive": true}),
+        );
+        let res = client(&mock)?
+            .request_json("GET", "ping", None, None)
+            .await?;
+        assert_eq!(res, json!({"alive": true}));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_requests() -> Result<(), Error> {
+        let mock = MockTransport::new();
+        mock.respond("POST", "/api/queue/v1/things", StatusCode::OK, json!({}));
+        client(&mock)?
+            .request(
+                "POST",
+                "things",
+                Some(vec![("x", "y")]),
+                Some(&json!({"a": 1})),
+            )
+            .await?;
+        assert_eq!(
+            mock.requests(),
+            vec![MockRequest {
+                method: "POST".into(),
+                path: "/api/queue/v1/things".into(),
+                query: Some("x=y".into()),
+                body: Some(json!({"a": 1})),
+            }]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unregistered() -> Result<(), Error> {
+        let mock = MockTransport::new();
+        let err = client(&mock)?
+            .request("GET", "ping", None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err_status_code(&err), Some(StatusCode::NOT_FOUND));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_response_sequence() -> Result<(), Error> {
+        let mock = MockTransport::new();
+        mock.respond(
+            "GET",
+            "/api/queue/v1/ping",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            json!({}),
+        );
+        mock.respond(
+            "GET",
+            "/api/queue/v1/ping",
+            StatusCode::OK,
+            json!({"alive": true}),
+        );
+        let client = client(&mock)?;
+
+        // the 500 is retried
+        let res = client.request_json("GET", "ping", None, None).await?;
+        assert_eq!(res, json!({"alive": true}));
+        // ..and the last response repeats
+        let res = client.request_json("GET", "ping", None, None).await?;
+        assert_eq!(res, json!({"alive": true}));
+        assert_eq!(mock.requests().len(), 3);
+        Ok(())
+    }
+}
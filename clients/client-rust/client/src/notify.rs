@@ -0,0 +1,553 @@
+use crate::Notify;
+use anyhow::{bail, Context, Error};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// An email to send with [`Notify::send_email`](crate::Notify::send_email).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailRequest {
+    /// Address to which the email should be sent
+    pub address: String,
+
+    /// Subject line of the email, in plain text
+    pub subject: String,
+
+    /// Content of the email, as markdown
+    pub content: String,
+
+    /// A link to include as a button in the email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<EmailLink>,
+
+    /// Reply-to address for the email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+
+    /// The HTML template used to format the content; the service defaults to `Simple`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<EmailTemplate>,
+}
+
+/// A link included in an [`EmailRequest`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EmailLink {
+    /// Text to display on the link
+    pub text: String,
+
+    /// Where the link should point
+    pub href: String,
+}
+
+/// The HTML template for an [`EmailRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailTemplate {
+    Simple,
+    Fullscreen,
+}
+
+/// A Matrix notice to send with [`Notify::send_matrix`](crate::Notify::send_matrix).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixRequest {
+    /// The fully qualified room ID, such as `!whDRjjSmICCgrhFHsQ:mozilla.org`
+    pub room_id: String,
+
+    /// Unformatted text of the message
+    pub body: String,
+
+    /// The format of `formatted_body`, such as `org.matrix.custom.html`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Text to display in clients that support `format`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_body: Option<String>,
+
+    /// The `m.room.message` msgtype, such as `m.text`; the service defaults to `m.notice`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msgtype: Option<String>,
+}
+
+/// A Pulse message to send with [`Notify::send_pulse`](crate::Notify::send_pulse).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PulseRequest {
+    /// Routing key for the message
+    pub routing_key: String,
+
+    /// The message to send; this must be a JSON object
+    pub message: Value,
+}
+
+/// The kind of address in a [`NotificationAddress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationType {
+    /// An email address
+    #[serde(rename = "email")]
+    Email,
+    /// A Pulse routing key
+    #[serde(rename = "pulse")]
+    Pulse,
+    /// A Matrix room ID
+    #[serde(rename = "matrix-room")]
+    Matrix,
+    /// A Slack channel ID
+    #[serde(rename = "slack-channel")]
+    Slack,
+}
+
+/// An address to which notifications can be sent, as used in the notification denylist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationAddress {
+    /// The kind of address
+    pub notification_type: NotificationType,
+
+    /// The address itself, in the form given by `notification_type`
+    pub notification_address: String,
+}
+
+impl NotificationAddress {
+    /// Create a new address of the given type.
+    pub fn new<S: Into<String>>(notification_type: NotificationType, address: S) -> Self {
+        NotificationAddress {
+            notification_type,
+            notification_address: address.into(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let address = &self.notification_address;
+        if address.is_empty() {
+            bail!("Notification address must not be empty");
+        }
+        let valid = match self.notification_type {
+            NotificationType::Email => {
+                address.contains('@') && !address.chars().any(char::is_whitespace)
+            }
+            NotificationType::Pulse => !address.chars().any(char::is_whitespace),
+            NotificationType::Matrix => address.starts_with('!') && address.contains(':'),
+            NotificationType::Slack => address.chars().all(|c| c.is_ascii_alphanumeric()),
+        };
+        if !valid {
+            bail!(
+                "{:?} is not a valid {}",
+                address,
+                match self.notification_type {
+                    NotificationType::Email => "email address",
+                    NotificationType::Pulse => "Pulse routing key",
+                    NotificationType::Matrix => "Matrix room ID",
+                    NotificationType::Slack => "Slack channel ID",
+                }
+            );
+        }
+        Ok(())
+    }
+}
+
+impl EmailRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.address.is_empty() {
+            bail!("Email address must not be empty");
+        }
+        if self.subject.is_empty() {
+            bail!("Email subject must not be empty");
+        }
+        if self.content.is_empty() {
+            bail!("Email content must not be empty");
+        }
+        if let Some(ref link) = self.link {
+            if link.text.is_empty() || link.href.is_empty() {
+                bail!("Email link text and href must not be empty");
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MatrixRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.room_id.is_empty() {
+            bail!("Matrix room ID must not be empty");
+        }
+        if self.body.is_empty() {
+            bail!("Matrix body must not be empty");
+        }
+        Ok(())
+    }
+}
+
+impl PulseRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.routing_key.is_empty() {
+            bail!("Pulse routing key must not be empty");
+        }
+        if !self.message.is_object() {
+            bail!("Pulse message must be a JSON object");
+        }
+        Ok(())
+    }
+}
+
+impl Notify {
+    /// Send an email.  The request is checked for empty required fields before it is sent.
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// # async fn f() -> Result<()> {
+    /// use taskcluster::{ClientBuilder, EmailRequest, Notify};
+    ///
+    /// let notify = Notify::new(ClientBuilder::new("https://tc.example.com"))?;
+    /// notify
+    ///     .send_email(&EmailRequest {
+    ///         address: "dev@example.com".into(),
+    ///         subject: "Build finished".into(),
+    ///         content: "The build is **done**.".into(),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_email(&self, request: &EmailRequest) -> Result<(), Error> {
+        request.validate()?;
+        self.email(&serde_json::to_value(request)?).await
+    }
+
+    /// Send a notice to a Matrix room.  The request is checked for empty required fields before
+    /// it is sent.
+    pub async fn send_matrix(&self, request: &MatrixRequest) -> Result<(), Error> {
+        request.validate()?;
+        self.matrix(&serde_json::to_value(request)?).await
+    }
+
+    /// Publish a message on Pulse.  The request is checked for an empty routing key and a
+    /// non-object message before it is sent.
+    pub async fn send_pulse(&self, request: &PulseRequest) -> Result<(), Error> {
+        request.validate()?;
+        self.pulse(&serde_json::to_value(request)?).await
+    }
+
+    /// List all addresses in the notification denylist, following pagination.
+    ///
+    /// This fails if the results do not fit in the number of pages configured with
+    /// [`ClientBuilder::max_pages`](crate::ClientBuilder::max_pages).
+    pub async fn list_denylist(&self) -> Result<Vec<NotificationAddress>, Error> {
+        self.0
+            .request_all("GET", "denylist/list", None, "addresses")
+            .await?
+            .into_iter()
+            .map(|address| {
+                serde_json::from_value(address).context("Invalid address in denylist response")
+            })
+            .collect()
+    }
+
+    /// Add an address to the notification denylist, so that notifications to it are ignored.
+    /// The address is checked to be valid for its type before it is sent.
+    pub async fn add_denylist_address(&self, address: &NotificationAddress) -> Result<(), Error> {
+        address.validate()?;
+        self.addDenylistAddress(&serde_json::to_value(address)?)
+            .await
+    }
+
+    /// Remove an address from the notification denylist.  The address is checked to be valid
+    /// for its type before it is sent.
+    pub async fn delete_denylist_address(
+        &self,
+        address: &NotificationAddress,
+    ) -> Result<(), Error> {
+        address.validate()?;
+        self.deleteDenylistAddress(&serde_json::to_value(address)?)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ClientBuilder;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::json;
+
+    fn notify(server: &Server) -> Result<Notify, Error> {
+        let root_url = format!("http://{}", server.addr());
+        Notify::new(ClientBuilder::new(&root_url))
+    }
+
+    #[tokio::test]
+    async fn test_send_email() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/notify/v1/email"),
+                request::body(json_decoded(eq(json!({
+                    "address": "dev@example.com",
+                    "subject": "hi",
+                    "content": "hello",
+                    "link": {"text": "task", "href": "https://example.com"},
+                    "replyTo": "noreply@example.com",
+                    "template": "fullscreen",
+                })))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+
+        notify(&server)?
+            .send_email(&EmailRequest {
+                address: "dev@example.com".into(),
+                subject: "hi".into(),
+                content: "hello".into(),
+                link: Some(EmailLink {
+                    text: "task".into(),
+                    href: "https://example.com".into(),
+                }),
+                reply_to: Some("noreply@example.com".into()),
+                template: Some(EmailTemplate::Fullscreen),
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_email_empty_subject() -> Result<(), Error> {
+        let server = Server::run();
+        let err = notify(&server)?
+            .send_email(&EmailRequest {
+                address: "dev@example.com".into(),
+                content: "hello".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Email subject must not be empty");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_matrix() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/notify/v1/matrix"),
+                request::body(json_decoded(eq(json!({
+                    "roomId": "!room:example.com",
+                    "body": "hello",
+                    "format": "org.matrix.custom.html",
+                    "formattedBody": "<b>hello</b>",
+                })))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+
+        notify(&server)?
+            .send_matrix(&MatrixRequest {
+                room_id: "!room:example.com".into(),
+                body: "hello".into(),
+                format: Some("org.matrix.custom.html".into()),
+                formatted_body: Some("<b>hello</b>".into()),
+                ..Default::default()
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_matrix_empty_room() -> Result<(), Error> {
+        let server = Server::run();
+        let err = notify(&server)?
+            .send_matrix(&MatrixRequest {
+                body: "hello".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Matrix room ID must not be empty");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_pulse() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/notify/v1/pulse"),
+                request::body(json_decoded(eq(json!({
+                    "routingKey": "my.key",
+                    "message": {"x": 1},
+                })))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+
+        notify(&server)?
+            .send_pulse(&PulseRequest {
+                routing_key: "my.key".into(),
+                message: json!({"x": 1}),
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_pulse_not_object() -> Result<(), Error> {
+        let server = Server::run();
+        let err = notify(&server)?
+            .send_pulse(&PulseRequest {
+                routing_key: "my.key".into(),
+                message: json!("hello"),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Pulse message must be a JSON object");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_denylist() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/notify/v1/denylist/list"),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(
+                        json!({
+                            "addresses": [
+                                {"notificationType": "email", "notificationAddress": "a@example.com"},
+                                {"notificationType": "matrix-room", "notificationAddress": "!r:example.com"},
+                            ],
+                            "continuationToken": "page2",
+                        })
+                        .to_string(),
+                    ),
+            ),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/notify/v1/denylist/list"),
+                request::query(url_decoded(contains(("continuationToken", "page2")))),
+            ])
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .insert_header("content-type", "application/json")
+                    .body(
+                        json!({
+                            "addresses": [
+                                {"notificationType": "slack-channel", "notificationAddress": "C012AB3CD"},
+                            ],
+                        })
+                        .to_string(),
+                    ),
+            ),
+        );
+
+        let addresses = notify(&server)?.list_denylist().await?;
+        assert_eq!(
+            addresses,
+            vec![
+                NotificationAddress::new(NotificationType::Email, "a@example.com"),
+                NotificationAddress::new(NotificationType::Matrix, "!r:example.com"),
+                NotificationAddress::new(NotificationType::Slack, "C012AB3CD"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_denylist_address() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/notify/v1/denylist/add"),
+                request::body(json_decoded(eq(json!({
+                    "notificationType": "pulse",
+                    "notificationAddress": "my.key",
+                })))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+
+        notify(&server)?
+            .add_denylist_address(&NotificationAddress::new(NotificationType::Pulse, "my.key"))
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_denylist_address() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("DELETE", "/api/notify/v1/denylist/delete"),
+                request::body(json_decoded(eq(json!({
+                    "notificationType": "email",
+                    "notificationAddress": "a@example.com",
+                })))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+
+        notify(&server)?
+            .delete_denylist_address(&NotificationAddress::new(
+                NotificationType::Email,
+                "a@example.com",
+            ))
+            .await?;
+        Ok(())
+    }
+
+    macro_rules! denylist_validation_tests {
+        ($($name:ident: $type:ident, $address:expr, $valid:expr;)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let address = NotificationAddress::new(NotificationType::$type, $address);
+                    assert_eq!(address.validate().is_ok(), $valid);
+                }
+            )*
+        };
+    }
+
+    denylist_validation_tests! {
+        test_validate_email: Email, "a@example.com", true;
+        test_validate_email_no_at: Email, "example.com", false;
+        test_validate_email_space: Email, "a @example.com", false;
+        test_validate_email_empty: Email, "", false;
+        test_validate_pulse: Pulse, "my.routing.key", true;
+        test_validate_pulse_space: Pulse, "my key", false;
+        test_validate_matrix: Matrix, "!whDRjjSmICCgrhFHsQ:mozilla.org", true;
+        test_validate_matrix_alias: Matrix, "#room:mozilla.org", false;
+        test_validate_slack: Slack, "C012AB3CD", true;
+        test_validate_slack_name: Slack, "#general", false;
+    }
+
+    #[tokio::test]
+    async fn test_add_denylist_address_invalid() -> Result<(), Error> {
+        let server = Server::run();
+        let err = notify(&server)?
+            .add_denylist_address(&NotificationAddress::new(
+                NotificationType::Matrix,
+                "dev@example.com",
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"dev@example.com\" is not a valid Matrix room ID"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use crate::PurgeCache;
+use anyhow::{anyhow, bail, Error};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+
+/// The interval between checks that a purge request has been recorded
+const VERIFY_INTERVAL: Duration = Duration::from_secs(1);
+
+impl PurgeCache {
+    /// Request that a cache be purged, as for `purgeCache`, and then confirm that the request was
+    /// recorded by polling `purgeRequests` until it lists a purge of this cache later than any it
+    /// listed before the request.  If the purge request does not appear within `timeout`, this
+    /// returns an error.
+    ///
+    /// The service timestamps purge requests with its own clock, so purges are only compared
+    /// with each other, and the local clock need not agree with the service's.  An earlier purge
+    /// of the same cache is not mistaken for this one.
+    pub async fn purge_and_verify(
+        &self,
+        worker_pool_id: &str,
+        cache_name: &str,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        self.purge_and_verify_with_interval(worker_pool_id, cache_name, timeout, VERIFY_INTERVAL)
+            .await
+    }
+
+    async fn purge_and_verify_with_interval(
+        &self,
+        worker_pool_id: &str,
+        cache_name: &str,
+        timeout: Duration,
+        interval: Duration,
+    ) -> Result<(), Error> {
+        let deadline = Instant::now() + timeout;
+        // the purge recorded for this call is later than any recorded before it
+        let res = self.purgeRequests(worker_pool_id, None).await?;
+        let previous = latest_purge(&res, cache_name)?;
+        let since = previous.map(|previous| previous.to_rfc3339());
+
+        self.purgeCache(worker_pool_id, &json!({ "cacheName": cache_name }))
+            .await?;
+
+        loop {
+            let res = self.purgeRequests(worker_pool_id, since.as_deref()).await?;
+            if latest_purge(&res, cache_name)? > previous {
+                return Ok(());
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                bail!(
+                    "Purge of cache {} for {} was not recorded within {:?}",
+                    cache_name,
+                    worker_pool_id,
+                    timeout
+                );
+            }
+            // sleep no later than the deadline, so the last check is made at the deadline
+            let sleep = std::cmp::min(interval, deadline - now);
+            self.0.unless_cancelled(tokio::time::sleep(sleep)).await?;
+        }
+    }
+}
+
+/// Get the time of the latest purge of the given cache in a `purgeRequests` response, if any.
+fn latest_purge(res: &Value, cache_name: &str) -> Result<Option<DateTime<Utc>>, Error> {
+    let requests = res
+        .get("requests")
+        .and_then(|r| r.as_array())
+        .ok_or_else(|| anyhow!("purgeRequests response has no requests"))?;
+    Ok(requests
+        .iter()
+        .filter(|request| request.get("cacheName").and_then(|c| c.as_str()) == Some(cache_name))
+        .filter_map(|request| {
+            request
+                .get("before")
+                .and_then(|b| b.as_str())
+                .and_then(|b| b.parse::<DateTime<Utc>>().ok())
+        })
+        .max())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::json_response;
+    use crate::ClientBuilder;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+
+    const PURGE_PATH: &str = "/api/purge-cache/v1/purge-cache/proj%2Fpool";
+
+    fn purge_cache(server: &Server) -> Result<PurgeCache, Error> {
+        let root_url = format!("http://{}", server.addr());
+        PurgeCache::new(ClientBuilder::new(&root_url))
+    }
+
+    #[tokio::test]
+    async fn test_purge_and_verify() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", PURGE_PATH),
+                request::body(json_decoded(eq(json!({"cacheName": "my-cache"})))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+        // an earlier purge of the cache is listed until the new purge is recorded; both are
+        // timestamped by a service clock well behind the local clock
+        let earlier = (Utc::now() - chrono::Duration::seconds(60)).to_rfc3339();
+        let later = (Utc::now() - chrono::Duration::seconds(30)).to_rfc3339();
+        let earlier_response = || {
+            json_response(json!({"requests": [
+                {"workerPoolId": "proj/pool", "cacheName": "my-cache", "before": earlier},
+            ]}))
+        };
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", PURGE_PATH),
+                request::query(url_decoded(not(contains(key("since"))))),
+            ])
+            .times(1)
+            .respond_with(earlier_response()),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", PURGE_PATH),
+                request::query(url_decoded(contains(key("since")))),
+            ])
+            .times(2)
+            .respond_with(cycle![
+                earlier_response(),
+                json_response(json!({"requests": [
+                    {"workerPoolId": "proj/pool", "cacheName": "other-cache", "before": later},
+                    {"workerPoolId": "proj/pool", "cacheName": "my-cache", "before": later},
+                ]})),
+            ]),
+        );
+
+        purge_cache(&server)?
+            .purge_and_verify_with_interval(
+                "proj/pool",
+                "my-cache",
+                Duration::from_secs(10),
+                Duration::from_millis(1),
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_purge_and_verify_timeout() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("POST", PURGE_PATH))
+                .times(1)
+                .respond_with(status_code(200)),
+        );
+        server.expect(
+            Expectation::matching(request::method_path("GET", PURGE_PATH))
+                .times(1..)
+                .respond_with(json_response(json!({"requests": []}))),
+        );
+
+        let err = purge_cache(&server)?
+            .purge_and_verify_with_interval(
+                "proj/pool",
+                "my-cache",
+                Duration::from_millis(100),
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Purge of cache my-cache for proj/pool was not recorded"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_latest_purge() -> Result<(), Error> {
+        let res = json!({"requests": [
+            {"cacheName": "my-cache", "before": "2029-12-31T00:00:00.000Z"},
+            {"cacheName": "other-cache", "before": "2030-01-02T00:00:00.000Z"},
+            {"cacheName": "my-cache", "before": "2030-01-01T00:00:00.000Z"},
+        ]});
+        assert_eq!(
+            latest_purge(&res, "my-cache")?,
+            Some("2030-01-01T00:00:00Z".parse()?)
+        );
+        assert_eq!(latest_purge(&res, "no-cache")?, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{Credentials, Queue};
+use anyhow::{anyhow, Context, Error};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// The credentials and `takenUntil` time of a claimed run, from the response to `claimWork`,
+/// `claimTask`, or `reclaimTask`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskClaim {
+    /// Temporary credentials for performing requests on behalf of the task
+    pub credentials: Credentials,
+
+    /// The time at which the claim expires, unless it is reclaimed
+    pub taken_until: DateTime<Utc>,
+}
+
+impl TaskClaim {
+    /// Parse a claim from the response to `claimWork` (one of its `tasks`), `claimTask`, or
+    /// `reclaimTask`.
+    pub fn from_response(response: &Value) -> Result<Self, Error> {
+        TaskClaim::deserialize(response).context("while parsing task claim")
+    }
+}
+
+/// A background task reclaiming a claimed run before its claim expires, created with
+/// [`Queue::reclaim_task`](crate::Queue::reclaim_task).  Reclaiming stops when this is dropped.
+#[derive(Debug)]
+pub struct Reclaimer {
+    claim: watch::Receiver<TaskClaim>,
+    task: JoinHandle<Error>,
+}
+
+impl Reclaimer {
+    /// The latest claim, with the credentials to use for requests on behalf of the task.  These
+    /// change with each reclaim.
+    pub fn claim(&self) -> TaskClaim {
+        self.claim.borrow().clone()
+    }
+
+    /// Wait until reclaiming fails, returning the error.  This happens when the run has been
+    /// resolved, such as when the task is cancelled or its deadline passes, in which case the
+    /// error has status 409.  Execution of the task should then be abandoned, without resolving
+    /// the run.  This waits forever while reclaiming succeeds.  Reclaiming has stopped once this
+    /// returns, so it consumes the reclaimer.
+    pub async fn failed(mut self) -> Error {
+        match (&mut self.task).await {
+            Ok(err) => err,
+            Err(err) => anyhow!("reclaim task stopped: {}", err),
+        }
+    }
+}
+
+impl Drop for Reclaimer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// The time to wait before reclaiming a claim that expires at `taken_until`: until a third of the
+/// time remaining at `now` is left, leaving time to retry a failed reclaim before the claim
+/// expires.
+fn reclaim_delay(taken_until: DateTime<Utc>, now: DateTime<Utc>) -> std::time::Duration {
+    ((taken_until - now) * 2 / 3)
+        .to_std()
+        .unwrap_or_else(|_| std::time::Duration::from_secs(0))
+}
+
+impl Queue {
+    /// Reclaim the given run in the background, as for `reclaimTask`, each time its claim is
+    /// close to expiring, starting from the given claim.  This must be called within a Tokio
+    /// runtime.  The returned [`Reclaimer`](crate::Reclaimer) provides the latest claim,
+    /// including fresh credentials for the task, and reports when reclaiming fails.  Dropping
+    /// it stops reclaiming.
+    ///
+    /// Each reclaim is made when a third of the time remaining in the previous claim is left,
+    /// and is retried as usual for the client.
+    pub fn reclaim_task(&self, task_id: &str, run_id: u32, claim: TaskClaim) -> Reclaimer {
+        let queue = Queue(self.0.duplicate());
+        let task_id = task_id.to_owned();
+        let run_id = run_id.to_string();
+        let (sender, receiver) = watch::channel(claim);
+
+        let task = tokio::spawn(async move {
+            loop {
+                let taken_until = sender.borrow().taken_until;
+                tokio::time::sleep(reclaim_delay(taken_until, Utc::now())).await;
+
+                let claim = queue
+                    .reclaimTask(&task_id, &run_id)
+                    .await
+                    .and_then(|res| TaskClaim::from_response(&res));
+                match claim {
+                    Ok(claim) => {
+                        // the receiver is held by the Reclaimer, which aborts this task when it
+                        // is dropped, so this cannot fail
+                        let _ = sender.send(claim);
+                    }
+                    Err(err) => {
+                        return err.context(format!("while reclaiming {}/{}", task_id, run_id))
+                    }
+                }
+            }
+        });
+
+        Reclaimer {
+            claim: receiver,
+            task,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err_status_code, ClientBuilder, StatusCode};
+    use chrono::Duration;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::json;
+
+    fn claim_json(client_id: &str, taken_until: DateTime<Utc>) -> Value {
+        json!({
+            "status": {"taskId": "abc"},
+            "runId": 0,
+            "takenUntil": crate::time::timestamp(taken_until),
+            "credentials": {"clientId": client_id, "accessToken": "token"},
+        })
+    }
+
+    #[test]
+    fn test_reclaim_delay() -> Result<(), Error> {
+        let now: DateTime<Utc> = "2030-01-01T00:00:00Z".parse()?;
+        assert_eq!(
+            reclaim_delay(now + Duration::minutes(30), now),
+            std::time::Duration::from_secs(20 * 60)
+        );
+        assert_eq!(
+            reclaim_delay(now + Duration::milliseconds(300), now),
+            std::time::Duration::from_millis(200)
+        );
+        assert_eq!(reclaim_delay(now, now), std::time::Duration::from_secs(0));
+        assert_eq!(
+            reclaim_delay(now - Duration::minutes(1), now),
+            std::time::Duration::from_secs(0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_task_claim_from_response() -> Result<(), Error> {
+        let taken_until: DateTime<Utc> = "2030-01-01T00:00:00Z".parse()?;
+        let claim = TaskClaim::from_response(&claim_json("task-client", taken_until))?;
+        assert_eq!(
+            claim,
+            TaskClaim {
+                credentials: Credentials::new("task-client", "token"),
+                taken_until,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reclaim_task() -> Result<(), Error> {
+        let server = Server::run();
+        // the reclaimed claim lasts long enough that there is only one reclaim in this test
+        let reclaimed = claim_json("reclaimed-client", Utc::now() + Duration::seconds(10));
+        server.expect(
+            Expectation::matching(request::method_path(
+                "POST",
+                "/api/queue/v1/task/abc/runs/0/reclaim",
+            ))
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .append_header("Content-Type", "application/json")
+                    .body(reclaimed.to_string()),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let queue = Queue::new(ClientBuilder::new(&root_url))?;
+
+        // the first reclaim is due after 200ms
+        let claim = TaskClaim::from_response(&claim_json(
+            "claimed-client",
+            Utc::now() + Duration::milliseconds(300),
+        ))?;
+        let reclaimer = queue.reclaim_task("abc", 0, claim);
+        assert_eq!(reclaimer.claim().credentials.client_id, "claimed-client");
+
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        assert_eq!(reclaimer.claim().credentials.client_id, "reclaimed-client");
+        drop(reclaimer);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reclaim_task_failed() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "POST",
+                "/api/queue/v1/task/abc/runs/0/reclaim",
+            ))
+            .times(1)
+            .respond_with(status_code(409)),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let queue = Queue::new(ClientBuilder::new(&root_url))?;
+
+        // the claim has already expired, so the reclaim is made immediately
+        let claim = TaskClaim::from_response(&claim_json("claimed-client", Utc::now()))?;
+        let reclaimer = queue.reclaim_task("abc", 0, claim);
+        let err = reclaimer.failed().await;
+        assert_eq!(err_status_code(&err), Some(StatusCode::CONFLICT));
+        Ok(())
+    }
+}
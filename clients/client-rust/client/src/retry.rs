@@ -4,14 +4,22 @@
 /// not for general use.
 use backoff::backoff::Backoff as BackoffTrait;
 use backoff::ExponentialBackoff;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Configuration for a client's automatic retrying.  The field names here match those
 /// of the JS client.
+///
+/// Each delay is randomized by up to `randomization_factor` in either direction, so that many
+/// clients failing at once do not retry in lockstep.  This is partial jitter rather than "full
+/// jitter" (a delay chosen uniformly between zero and the exponential delay), to keep the same
+/// defaults and behavior as the JS client and to keep a minimum delay between retries.  A
+/// `randomization_factor` of 1.0 spreads each delay over the whole range from zero to twice the
+/// exponential delay, for a similar effect to full jitter.
 #[derive(Debug, Clone)]
 pub struct Retry {
-    /// Number of retries (not counting the first try) for transient errors. Zero
-    /// to disable retries entirely. (default 5)
+    /// Number of retries (not counting the first try) for transient errors, so an operation is
+    /// tried at most `retries + 1` times.  Zero disables retries entirely, so an operation is
+    /// tried exactly once and any error is returned immediately. (default 5)
     pub retries: u32,
 
     /// Maximum interval between retries (default 30s)
@@ -21,8 +29,9 @@ pub struct Retry {
     /// and 500ms a good value for background processes. (default 100ms)
     pub delay_factor: Duration,
 
-    /// Randomization factor added as.
-    /// delay = delay * random([1 - randomizationFactor; 1 + randomizationFactor]) (default 0.25)
+    /// Randomization factor added as
+    /// delay = delay * random([1 - randomizationFactor; 1 + randomizationFactor]).  Values
+    /// between 0.0, for no randomization, and 1.0 are meaningful. (default 0.25)
     pub randomization_factor: f64,
 }
 
@@ -44,19 +53,18 @@ pub struct Backoff<'a> {
     retry: &'a Retry,
     tries: u32,
     backoff: ExponentialBackoff,
+    start: Instant,
+    max_elapsed_time: Option<Duration>,
 }
 
 impl<'a> Backoff<'a> {
     pub fn new(retry: &Retry) -> Backoff {
         let mut backoff = ExponentialBackoff {
-            max_elapsed_time: None, // we count retries instead
+            max_elapsed_time: None, // we count retries and track elapsed time ourselves
             max_interval: retry.max_delay,
             initial_interval: retry.delay_factor,
             multiplier: 2.0, // hard-coded value in JS client
-            #[cfg(not(test))]
             randomization_factor: retry.randomization_factor,
-            #[cfg(test)]
-            randomization_factor: 0.0,
             ..Default::default()
         };
         backoff.reset();
@@ -64,9 +72,19 @@ impl<'a> Backoff<'a> {
             retry,
             tries: 0,
             backoff,
+            start: Instant::now(),
+            max_elapsed_time: None,
         }
     }
 
+    /// Limit the total time spent on the operation, including the time taken by each try and
+    /// the delays between them.  A retry that would begin after this time has elapsed is not
+    /// made, even if `retries` would allow it.  None, the default, is no limit.
+    pub fn with_max_elapsed_time(mut self, max_elapsed_time: Option<Duration>) -> Self {
+        self.max_elapsed_time = max_elapsed_time;
+        self
+    }
+
     /// Return the next backoff interval or, if the operation should not be retried,
     /// None.
     pub fn next_backoff(&mut self) -> Option<Duration> {
@@ -74,9 +92,30 @@ impl<'a> Backoff<'a> {
         if self.tries > self.retry.retries {
             None
         } else {
-            self.backoff.next_backoff()
+            self.backoff
+                .next_backoff()
+                .filter(|duration| self.within_max_elapsed_time(*duration))
         }
     }
+
+    /// Return true if a retry after waiting for the given duration would begin before the
+    /// configured [`max_elapsed_time`](Backoff::with_max_elapsed_time), if any.
+    pub fn within_max_elapsed_time(&self, duration: Duration) -> bool {
+        match self.max_elapsed_time {
+            Some(max_elapsed_time) => self
+                .start
+                .elapsed()
+                .checked_add(duration)
+                .map_or(false, |elapsed| elapsed <= max_elapsed_time),
+            None => true,
+        }
+    }
+
+    /// Return the number of retries allowed so far, that is, the number of calls to
+    /// [`next_backoff`](Backoff::next_backoff) that returned a duration.
+    pub fn retries(&self) -> u32 {
+        self.tries.min(self.retry.retries)
+    }
 }
 
 #[cfg(test)]
@@ -87,6 +126,7 @@ mod test {
     async fn backoff_three_retries() {
         let retry = Retry {
             retries: 3,
+            randomization_factor: 0.0,
             ..Default::default()
         };
         let mut backoff = Backoff::new(&retry);
@@ -99,4 +139,80 @@ mod test {
         // ..retry 3, fail
         assert_eq!(backoff.next_backoff(), None); // out of retries
     }
+
+    #[test]
+    fn backoff_no_retries() {
+        let retry = Retry {
+            retries: 0,
+            ..Default::default()
+        };
+        let mut backoff = Backoff::new(&retry);
+        assert_eq!(backoff.next_backoff(), None);
+        assert_eq!(backoff.retries(), 0);
+        // asking again still does not retry
+        assert_eq!(backoff.next_backoff(), None);
+        assert_eq!(backoff.retries(), 0);
+    }
+
+    #[test]
+    fn backoff_retries() {
+        let retry = Retry {
+            retries: 2,
+            ..Default::default()
+        };
+        let mut backoff = Backoff::new(&retry);
+        assert_eq!(backoff.retries(), 0);
+        backoff.next_backoff();
+        assert_eq!(backoff.retries(), 1);
+        backoff.next_backoff();
+        assert_eq!(backoff.retries(), 2);
+        assert_eq!(backoff.next_backoff(), None);
+        assert_eq!(backoff.retries(), 2);
+    }
+
+    #[tokio::test]
+    async fn backoff_max_elapsed_time() {
+        let retry = Retry {
+            retries: 1000,
+            max_delay: Duration::from_millis(10),
+            delay_factor: Duration::from_millis(10),
+            randomization_factor: 0.0,
+            ..Default::default()
+        };
+        let mut backoff =
+            Backoff::new(&retry).with_max_elapsed_time(Some(Duration::from_millis(200)));
+        while let Some(duration) = backoff.next_backoff() {
+            tokio::time::sleep(duration).await;
+        }
+        // each delay is at least 10ms, so the retries stopped by the deadline after at most 20
+        // of them, long before running out
+        assert!(backoff.retries() > 0);
+        assert!(backoff.retries() <= 20);
+    }
+
+    #[test]
+    fn backoff_within_max_elapsed_time() {
+        let retry = Retry::default();
+        let backoff = Backoff::new(&retry);
+        assert!(backoff.within_max_elapsed_time(Duration::from_secs(u64::MAX)));
+        let backoff = backoff.with_max_elapsed_time(Some(Duration::from_secs(60)));
+        assert!(backoff.within_max_elapsed_time(Duration::from_secs(1)));
+        assert!(!backoff.within_max_elapsed_time(Duration::from_secs(61)));
+        assert!(!backoff.within_max_elapsed_time(Duration::from_secs(u64::MAX)));
+    }
+
+    #[test]
+    fn backoff_jitter() {
+        let retry = Retry::default();
+        let mut delays = vec![];
+        for _ in 0..20 {
+            let delay = Backoff::new(&retry).next_backoff().unwrap();
+            // 100ms +/- 25%
+            assert!(delay >= Duration::from_millis(75));
+            assert!(delay <= Duration::from_millis(125));
+            delays.push(delay);
+        }
+        // with overwhelming probability, the delays are not all the same
+        assert!(delays.iter().any(|d| *d != delays[0]));
+    }
 }
@@ -0,0 +1,278 @@
+use crate::util::{context_if_not_found, page_items};
+use crate::Secrets;
+use anyhow::{bail, Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::future;
+use futures_util::stream::{Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+impl Secrets {
+    /// Get a secret, deserializing its value into the given type.
+    ///
+    /// If the secret does not exist, the error has status code 404 (see
+    /// [`err_status_code`](crate::err_status_code)).  If the secret's value does not match the
+    /// type, the error's source is a `serde_json::Error`.
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// # async fn f() -> Result<()> {
+    /// use serde::Deserialize;
+    /// use taskcluster::{ClientBuilder, Secrets};
+    ///
+    /// #[derive(Deserialize)]
+    /// struct ApiKey {
+    ///     key: String,
+    /// }
+    ///
+    /// let secrets = Secrets::new(ClientBuilder::new("https://tc.example.com"))?;
+    /// let api_key: ApiKey = secrets.get_typed("project/my-project/api-key").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_typed<T: DeserializeOwned>(&self, name: &str) -> Result<T, Error> {
+        let mut res = self
+            .get(name)
+            .await
+            .map_err(|err| context_if_not_found(err, || format!("Secret {} not found", name)))?;
+        let secret = res
+            .get_mut("secret")
+            .map(|s| s.take())
+            .with_context(|| format!("Response for secret {} has no value", name))?;
+        serde_json::from_value(secret)
+            .with_context(|| format!("Secret {} does not match the expected type", name))
+    }
+
+    /// Set a secret to the serialized form of the given value, which must serialize to a JSON
+    /// object, expiring at the given time.
+    pub async fn set_typed<T: Serialize>(
+        &self,
+        name: &str,
+        value: &T,
+        expires: &DateTime<Utc>,
+    ) -> Result<(), Error> {
+        let secret = serde_json::to_value(value)
+            .with_context(|| format!("Could not serialize secret {}", name))?;
+        if !secret.is_object() {
+            bail!("Secret {} must serialize to a JSON object", name);
+        }
+        self.set(name, &json!({"secret": secret, "expires": expires}))
+            .await
+    }
+
+    /// List the names of all secrets, following pagination to get all of them.  The names are
+    /// returned whether or not the client has scopes to read the secrets.
+    ///
+    /// This fails if the results do not fit in the number of pages configured with
+    /// [`ClientBuilder::max_pages`](crate::ClientBuilder::max_pages).  To list any number of
+    /// secrets, use [`list_all_stream`](crate::Secrets::list_all_stream).
+    pub async fn list_all(&self) -> Result<Vec<String>, Error> {
+        self.0
+            .request_all("GET", "secrets", None, "secrets")
+            .await?
+            .into_iter()
+            .map(secret_name)
+            .collect()
+    }
+
+    /// Stream the names of all secrets, as for [`list_all`](crate::Secrets::list_all), fetching
+    /// each page of results as it is needed.
+    pub fn list_all_stream(&self) -> impl Stream<Item = Result<String, Error>> + '_ {
+        self.list_paginated(None)
+            .and_then(|page| future::ready(page_items(page, "secrets")))
+            .try_flatten()
+            .and_then(|name| future::ready(secret_name(name)))
+    }
+}
+
+fn secret_name(name: Value) -> Result<String, Error> {
+    match name {
+        Value::String(name) => Ok(name),
+        _ => bail!("Invalid secret name in list response"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::json_response;
+    use crate::{err_status_code, ClientBuilder, StatusCode};
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde::Deserialize;
+
+    const SECRET_PATH: &str = "/api/secrets/v1/secret/project%2Ftest";
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct ApiKey {
+        key: String,
+    }
+
+    const LIST_PATH: &str = "/api/secrets/v1/secrets";
+
+    fn expect_list_pages(server: &Server) {
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", LIST_PATH),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "secrets": ["project/a", "project/b"],
+                "continuationToken": "page2",
+            }))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", LIST_PATH),
+                request::query(url_decoded(contains(("continuationToken", "page2")))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({"secrets": ["project/c"]}))),
+        );
+    }
+
+    fn secrets(server: &Server) -> Result<Secrets, Error> {
+        let root_url = format!("http://{}", server.addr());
+        Secrets::new(ClientBuilder::new(&root_url))
+    }
+
+    fn secret_response(secret: serde_json::Value) -> impl httptest::responders::Responder {
+        status_code(200)
+            .insert_header("content-type", "application/json")
+            .body(
+                json!({
+                    "secret": secret,
+                    "expires": "2030-01-01T00:00:00.000Z",
+                })
+                .to_string(),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_get_typed() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", SECRET_PATH))
+                .respond_with(secret_response(json!({"key": "sekrit"}))),
+        );
+
+        let api_key: ApiKey = secrets(&server)?.get_typed("project/test").await?;
+        assert_eq!(
+            api_key,
+            ApiKey {
+                key: "sekrit".into()
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_typed_not_found() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", SECRET_PATH))
+                .respond_with(status_code(404)),
+        );
+
+        let err = secrets(&server)?
+            .get_typed::<ApiKey>("project/test")
+            .await
+            .unwrap_err();
+        assert_eq!(err_status_code(&err), Some(StatusCode::NOT_FOUND));
+        assert_eq!(err.to_string(), "Secret project/test not found");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_typed_mismatch() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", SECRET_PATH))
+                .respond_with(secret_response(json!({"password": "sekrit"}))),
+        );
+
+        let err = secrets(&server)?
+            .get_typed::<ApiKey>("project/test")
+            .await
+            .unwrap_err();
+        assert_eq!(err_status_code(&err), None);
+        assert!(err.root_cause().is::<serde_json::Error>());
+        assert_eq!(
+            err.to_string(),
+            "Secret project/test does not match the expected type"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_typed() -> Result<(), Error> {
+        let server = Server::run();
+        let expires: DateTime<Utc> = "2030-01-01T00:00:00Z".parse()?;
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("PUT", SECRET_PATH),
+                request::body(json_decoded(eq(json!({
+                    "secret": {"key": "sekrit"},
+                    "expires": expires,
+                })))),
+            ])
+            .times(1)
+            .respond_with(status_code(200)),
+        );
+
+        secrets(&server)?
+            .set_typed(
+                "project/test",
+                &ApiKey {
+                    key: "sekrit".into(),
+                },
+                &expires,
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_typed_not_object() -> Result<(), Error> {
+        let server = Server::run();
+        let expires: DateTime<Utc> = "2030-01-01T00:00:00Z".parse()?;
+
+        let err = secrets(&server)?
+            .set_typed("project/test", &"sekrit", &expires)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Secret project/test must serialize to a JSON object"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_all() -> Result<(), Error> {
+        let server = Server::run();
+        expect_list_pages(&server);
+
+        let names = secrets(&server)?.list_all().await?;
+        assert_eq!(names, vec!["project/a", "project/b", "project/c"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_all_stream() -> Result<(), Error> {
+        let server = Server::run();
+        expect_list_pages(&server);
+
+        let secrets = secrets(&server)?;
+        let names: Vec<String> = secrets.list_all_stream().try_collect().await?;
+        assert_eq!(names, vec!["project/a", "project/b", "project/c"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_secret_name_invalid() {
+        let err = secret_name(json!({"name": "project/a"})).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid secret name in list response");
+    }
+}
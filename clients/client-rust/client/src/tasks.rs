@@ -0,0 +1,469 @@
+#[cfg(feature = "typed")]
+use crate::typed::queue::{Task, TaskStatus, TaskStatusResponse};
+use crate::Queue;
+#[cfg(feature = "typed")]
+use anyhow::Context;
+use anyhow::{anyhow, Error};
+use futures_util::stream::{self, StreamExt};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// The maximum number of concurrent `status` calls made by [`Queue::statuses`]
+const STATUSES_CONCURRENCY: usize = 10;
+
+/// The maximum number of concurrent `createTask` calls made by [`Queue::create_task_group`]
+const CREATE_TASK_GROUP_CONCURRENCY: usize = 10;
+
+impl Queue {
+    /// Create a task as for `createTask`, with a newly generated taskId, returning that taskId
+    /// along with the response.  The taskId is a "nice" v4 slugid, which does not start with
+    /// `-` and so is safe to use as a command-line argument.
+    pub async fn create_task_autoid(&self, payload: &Value) -> Result<(String, Value), Error> {
+        let task_id = slugid::nice();
+        let res = self.createTask(&task_id, payload).await?;
+        Ok((task_id, res))
+    }
+
+    /// Create each of the given `(taskId, payload)` tasks, as for `createTask`, with several calls
+    /// in flight at once.  This is intended for submitting a task graph, whose tasks share a
+    /// `taskGroupId`.
+    ///
+    /// The batch is checked before any task is created: it is an error for two tasks to have the
+    /// same taskId, or for their payloads to give different `taskGroupId`s.  Otherwise, the
+    /// results are in the same order as `tasks`, and a failure to create one task (such as a 409
+    /// conflict with an existing task) does not prevent creating the others, so each task has its
+    /// own result.
+    ///
+    /// At most 10 calls are in flight at once, to avoid being rate-limited.  Calls that are
+    /// nonetheless rejected with a 429 response are retried as usual for the client.
+    pub async fn create_task_group(
+        &self,
+        tasks: Vec<(String, Value)>,
+    ) -> Result<Vec<Result<Value, Error>>, Error> {
+        check_task_group(&tasks)?;
+        // collect the (not yet started) futures first, as in `statuses`
+        let created: Vec<_> = tasks
+            .iter()
+            .map(|(task_id, payload)| self.createTask(task_id, payload))
+            .collect();
+        Ok(stream::iter(created)
+            .buffered(CREATE_TASK_GROUP_CONCURRENCY)
+            .collect()
+            .await)
+    }
+
+    /// Get the status of each of the given tasks, as for `status`, with several calls in flight
+    /// at once.  This is much faster than calling `status` for each task in turn, when
+    /// monitoring a large task graph.
+    ///
+    /// The results are in the same order as `task_ids`.  A failure to get the status of one task
+    /// does not prevent getting the others, so each task has its own result.
+    pub async fn statuses(&self, task_ids: &[&str]) -> Vec<Result<Value, Error>> {
+        // collect the (not yet started) futures first, as a closure in the stream would make
+        // this future not `Send`
+        let statuses: Vec<_> = task_ids
+            .iter()
+            .map(|task_id| self.status(task_id))
+            .collect();
+        stream::iter(statuses)
+            .buffered(STATUSES_CONCURRENCY)
+            .collect()
+            .await
+    }
+
+    /// Get the definition of a task, as for `task`, as the generated
+    /// [`typed::queue::Task`](crate::typed::queue::Task).  This is only available with the
+    /// `typed` feature.
+    #[cfg(feature = "typed")]
+    pub async fn task_definition(&self, task_id: &str) -> Result<Task, Error> {
+        let task = self.task(task_id).await?;
+        serde_json::from_value(task).context("Invalid task response")
+    }
+
+    /// Get the status of a task, as for `status`, as the generated
+    /// [`typed::queue::TaskStatus`](crate::typed::queue::TaskStatus), including the details of
+    /// each run.  This is only available with the `typed` feature.
+    #[cfg(feature = "typed")]
+    pub async fn task_status(&self, task_id: &str) -> Result<TaskStatus, Error> {
+        let res: TaskStatusResponse = serde_json::from_value(self.status(task_id).await?)
+            .context("Invalid status response")?;
+        Ok(res.status)
+    }
+}
+
+/// Check that the given tasks have distinct taskIds and agree on their `taskGroupId`.
+fn check_task_group(tasks: &[(String, Value)]) -> Result<(), Error> {
+    let mut task_ids = HashSet::new();
+    let mut task_group_id: Option<&Value> = None;
+    for (task_id, payload) in tasks {
+        if !task_ids.insert(task_id.as_str()) {
+            return Err(anyhow!(
+                "Task {} appears more than once in the task group",
+                task_id
+            ));
+        }
+        match (task_group_id, payload.get("taskGroupId")) {
+            (None, Some(id)) => task_group_id = Some(id),
+            (Some(first), Some(id)) if first != id => {
+                return Err(anyhow!(
+                    "Task {} has taskGroupId {}, but other tasks have taskGroupId {}",
+                    task_id,
+                    id,
+                    first
+                ))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::{json_response, json_response_with_status, Record};
+    use crate::{err_status_code, ClientBuilder, StatusCode};
+    #[cfg(feature = "typed")]
+    use chrono::{DateTime, Utc};
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::json;
+
+    #[cfg(feature = "typed")]
+    const TASK_PATH: &str = "/api/queue/v1/task/abc";
+
+    fn task_definition() -> Value {
+        json!({
+            "taskQueueId": "proj/test",
+            "created": "2030-01-01T00:00:00.000Z",
+            "deadline": "2030-01-02T00:00:00.000Z",
+            "payload": {"command": ["true"]},
+            "metadata": {
+                "name": "t",
+                "description": "d",
+                "owner": "o@example.com",
+                "source": "https://example.com",
+            },
+        })
+    }
+
+    fn queue(server: &Server) -> Result<Queue, Error> {
+        let root_url = format!("http://{}", server.addr());
+        Queue::new(ClientBuilder::new(&root_url))
+    }
+
+    fn is_nice_slugid(slug: &str) -> bool {
+        let uuid = match base64::decode_config(slug, base64::URL_SAFE_NO_PAD) {
+            Ok(uuid) if slug.len() == 22 && uuid.len() == 16 => uuid,
+            _ => return false,
+        };
+        uuid[0] < 0x80 && uuid[6] >> 4 == 4 && uuid[8] & 0xc0 == 0x80
+    }
+
+    #[tokio::test]
+    async fn test_create_task_autoid() -> Result<(), Error> {
+        let paths = Record::paths();
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method("PUT"),
+                request::path(matches("^/api/queue/v1/task/[A-Za-z0-9_-]{22}$")),
+                request::body(json_decoded(eq(task_definition()))),
+                paths.clone(),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({"status": {"state": "pending"}}))),
+        );
+
+        let (task_id, res) = queue(&server)?
+            .create_task_autoid(&task_definition())
+            .await?;
+        assert!(is_nice_slugid(&task_id), "{} is not a nice slugid", task_id);
+        assert_eq!(
+            paths.values(),
+            vec![format!("/api/queue/v1/task/{}", task_id)]
+        );
+        assert_eq!(res, json!({"status": {"state": "pending"}}));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_nice_slugid() {
+        assert!(is_nice_slugid("SJOKM3WJQ2-yvTkDEB9gXQ"));
+        assert!(!is_nice_slugid("-JOKM3WJQ2-yvTkDEB9gXQ"));
+        assert!(!is_nice_slugid("abc"));
+    }
+
+    #[tokio::test]
+    async fn test_statuses() -> Result<(), Error> {
+        let server = Server::run();
+        for (task_id, path) in &[
+            ("t1", "/api/queue/v1/task/t1/status"),
+            ("t3", "/api/queue/v1/task/t3/status"),
+        ] {
+            server.expect(
+                Expectation::matching(request::method_path("GET", *path))
+                    .times(1)
+                    .respond_with(json_response(json!({"status": {"taskId": task_id}}))),
+            );
+        }
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/task/t2/status"))
+                .times(1)
+                .respond_with(json_response_with_status(
+                    404,
+                    json!({"code": "ResourceNotFound", "message": "no such task"}),
+                )),
+        );
+
+        let results = queue(&server)?.statuses(&["t1", "t2", "t3"]).await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &json!({"status": {"taskId": "t1"}})
+        );
+        assert_eq!(
+            err_status_code(results[1].as_ref().unwrap_err()),
+            Some(StatusCode::NOT_FOUND)
+        );
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &json!({"status": {"taskId": "t3"}})
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_statuses_empty() -> Result<(), Error> {
+        let server = Server::run();
+        assert!(queue(&server)?.statuses(&[]).await.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_task_group() -> Result<(), Error> {
+        let server = Server::run();
+        for (task_id, path) in &[
+            ("t1", "/api/queue/v1/task/t1"),
+            ("t3", "/api/queue/v1/task/t3"),
+        ] {
+            server.expect(
+                Expectation::matching(request::method_path("PUT", *path))
+                    .times(1)
+                    .respond_with(json_response(json!({"status": {"taskId": task_id}}))),
+            );
+        }
+        server.expect(
+            Expectation::matching(request::method_path("PUT", "/api/queue/v1/task/t2"))
+                .times(1)
+                .respond_with(json_response_with_status(
+                    409,
+                    json!({"code": "RequestConflict", "message": "taskId t2 already used"}),
+                )),
+        );
+
+        let mut payload = task_definition();
+        payload["taskGroupId"] = json!("grp");
+        let tasks = vec![
+            ("t1".to_owned(), payload.clone()),
+            ("t2".to_owned(), payload.clone()),
+            ("t3".to_owned(), payload),
+        ];
+        let results = queue(&server)?.create_task_group(tasks).await?;
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &json!({"status": {"taskId": "t1"}})
+        );
+        assert_eq!(
+            err_status_code(results[1].as_ref().unwrap_err()),
+            Some(StatusCode::CONFLICT)
+        );
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &json!({"status": {"taskId": "t3"}})
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_task_group_invalid() -> Result<(), Error> {
+        // no requests are made for an invalid batch
+        let server = Server::run();
+        let tasks = vec![
+            ("t1".to_owned(), json!({"taskGroupId": "grp1"})),
+            ("t2".to_owned(), json!({"taskGroupId": "grp2"})),
+        ];
+        let err = queue(&server)?.create_task_group(tasks).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Task t2 has taskGroupId \"grp2\", but other tasks have taskGroupId \"grp1\""
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_task_group() {
+        assert!(check_task_group(&[]).is_ok());
+        assert!(check_task_group(&[
+            ("t1".to_owned(), json!({"taskGroupId": "grp"})),
+            ("t2".to_owned(), json!({})),
+            ("t3".to_owned(), json!({"taskGroupId": "grp"})),
+        ])
+        .is_ok());
+        assert_eq!(
+            check_task_group(&[("t1".to_owned(), json!({})), ("t1".to_owned(), json!({})),])
+                .unwrap_err()
+                .to_string(),
+            "Task t1 appears more than once in the task group"
+        );
+    }
+
+    #[cfg(feature = "typed")]
+    fn status_response() -> Value {
+        json!({
+            "status": {
+                "taskId": "abc",
+                "provisionerId": "proj",
+                "workerType": "test",
+                "taskQueueId": "proj/test",
+                "schedulerId": "-",
+                "projectId": "none",
+                "taskGroupId": "grp",
+                "deadline": "2030-01-02T00:00:00.000Z",
+                "expires": "2031-01-01T00:00:00.000Z",
+                "retriesLeft": 4,
+                "state": "running",
+                "runs": [
+                    {
+                        "runId": 0,
+                        "state": "exception",
+                        "reasonCreated": "scheduled",
+                        "reasonResolved": "worker-shutdown",
+                        "workerGroup": "us-east-1",
+                        "workerId": "i-123",
+                        "takenUntil": "2030-01-01T00:20:00.000Z",
+                        "scheduled": "2030-01-01T00:00:00.000Z",
+                        "started": "2030-01-01T00:01:00.000Z",
+                        "resolved": "2030-01-01T00:10:00.000Z",
+                    },
+                    {
+                        "runId": 1,
+                        "state": "running",
+                        "reasonCreated": "retry",
+                        "workerGroup": "us-west-2",
+                        "workerId": "i-456",
+                        "takenUntil": "2030-01-01T00:30:00.000Z",
+                        "scheduled": "2030-01-01T00:10:00.000Z",
+                        "started": "2030-01-01T00:11:00.000Z",
+                    },
+                    {
+                        "runId": 2,
+                        "state": "pending",
+                        "reasonCreated": "rerun",
+                        "scheduled": "2030-01-01T00:12:00.000Z",
+                    },
+                ],
+            },
+        })
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test]
+    async fn test_task_status() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/task/abc/status"))
+                .times(1)
+                .respond_with(json_response(status_response())),
+        );
+        let status = queue(&server)?.task_status("abc").await?;
+
+        assert_eq!(status.task_id, "abc");
+        assert_eq!(status.task_queue_id, "proj/test");
+        assert_eq!(status.retries_left, 4);
+        assert_eq!(status.state, "running");
+        assert_eq!(
+            status.deadline,
+            "2030-01-02T00:00:00Z".parse::<DateTime<Utc>>()?
+        );
+        let runs: Vec<_> = status
+            .runs
+            .iter()
+            .map(|run| (run.run_id, run.state.as_str(), run.reason_created.as_str()))
+            .collect();
+        assert_eq!(
+            runs,
+            vec![
+                (0, "exception", "scheduled"),
+                (1, "running", "retry"),
+                (2, "pending", "rerun"),
+            ]
+        );
+        let run = &status.runs[0];
+        assert_eq!(run.reason_resolved.as_deref(), Some("worker-shutdown"));
+        assert_eq!(run.worker_id.as_deref(), Some("i-123"));
+        assert_eq!(run.resolved, Some("2030-01-01T00:10:00Z".parse()?));
+        assert_eq!(status.runs[2].started, None);
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test]
+    async fn test_task_status_invalid() -> Result<(), Error> {
+        let server = Server::run();
+        let mut res = status_response();
+        res["status"]["runs"][0]["scheduled"] = json!("not a date");
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/task/abc/status"))
+                .times(1)
+                .respond_with(json_response(res)),
+        );
+        assert!(queue(&server)?.task_status("abc").await.is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test]
+    async fn test_task_definition() -> Result<(), Error> {
+        let server = Server::run();
+        let mut definition = task_definition();
+        let defaults = json!({
+            "provisionerId": "proj",
+            "workerType": "test",
+            "schedulerId": "-",
+            "projectId": "none",
+            "taskGroupId": "grp",
+            "dependencies": ["dep"],
+            "requires": "all-completed",
+            "routes": [],
+            "priority": "lowest",
+            "retries": 5,
+            "expires": "2031-01-01T00:00:00.000Z",
+            "scopes": [],
+            "tags": {"kind": "test"},
+            "extra": {},
+        });
+        for (name, value) in defaults.as_object().unwrap() {
+            definition[name] = value.clone();
+        }
+        server.expect(
+            Expectation::matching(request::method_path("GET", TASK_PATH))
+                .times(1)
+                .respond_with(json_response(definition)),
+        );
+        let task = queue(&server)?.task_definition("abc").await?;
+
+        assert_eq!(task.task_queue_id, "proj/test");
+        assert_eq!(task.project_id.as_deref(), Some("none"));
+        assert_eq!(task.dependencies, vec!["dep".to_owned()]);
+        assert_eq!(task.retries, 5);
+        assert_eq!(
+            task.created,
+            "2030-01-01T00:00:00Z".parse::<DateTime<Utc>>()?
+        );
+        assert_eq!(task.payload, json!({"command": ["true"]}));
+        assert_eq!(task.metadata.owner, "o@example.com");
+        assert_eq!(task.tags.get("kind").map(String::as_str), Some("test"));
+        Ok(())
+    }
+}
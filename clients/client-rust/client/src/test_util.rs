@@ -0,0 +1,96 @@
+//! Helpers shared by the tests of several modules.
+
+use httptest::http::{HeaderMap, Request, Uri};
+use httptest::matchers::{ExecutionContext, Matcher};
+use httptest::responders::{status_code, Responder};
+use serde_json::Value;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// An httptest responder giving a 200 response with the given JSON body
+pub(crate) fn json_response(body: Value) -> impl Responder {
+    json_response_with_status(200, body)
+}
+
+/// An httptest responder giving a response with the given status and JSON body
+pub(crate) fn json_response_with_status(status: u16, body: Value) -> impl Responder {
+    status_code(status)
+        .insert_header("content-type", "application/json")
+        .body(body.to_string())
+}
+
+/// A function deriving the value to record from a request's URI and headers
+type RecordFn<T> = dyn Fn(&Uri, &HeaderMap) -> T + Send + Sync;
+
+/// An httptest matcher that records a value derived from each request, in the order the requests
+/// arrive.  It matches every request, so it is combined with other matchers using `all_of!`, and
+/// cloned to keep a handle on the recorded values.
+#[derive(Clone)]
+pub(crate) struct Record<T> {
+    values: Arc<Mutex<Vec<T>>>,
+    record: Arc<RecordFn<T>>,
+}
+
+impl<T> Record<T> {
+    fn new<F>(record: F) -> Self
+    where
+        F: Fn(&Uri, &HeaderMap) -> T + Send + Sync + 'static,
+    {
+        Record {
+            values: Arc::new(Mutex::new(Vec::new())),
+            record: Arc::new(record),
+        }
+    }
+
+    /// The values recorded so far
+    pub(crate) fn values(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.values.lock().unwrap().clone()
+    }
+}
+
+impl Record<String> {
+    /// Record the path of each request
+    pub(crate) fn paths() -> Self {
+        Record::new(|uri, _| uri.path().to_owned())
+    }
+}
+
+impl Record<Option<String>> {
+    /// Record the value of the given header of each request, if present
+    pub(crate) fn header(name: &'static str) -> Self {
+        Record::new(move |_, headers| {
+            headers
+                .get(name)
+                .map(|value| value.to_str().unwrap().to_owned())
+        })
+    }
+}
+
+impl Record<Instant> {
+    /// Record the time at which each request arrives
+    pub(crate) fn arrivals() -> Self {
+        Record::new(|_, _| Instant::now())
+    }
+}
+
+impl<T> fmt::Debug for Record<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Record")
+    }
+}
+
+impl<B, T: Send> Matcher<Request<B>> for Record<T> {
+    fn matches(&mut self, input: &Request<B>, _ctx: &mut ExecutionContext) -> bool {
+        let value = (self.record)(input.uri(), input.headers());
+        self.values.lock().unwrap().push(value);
+        true
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Debug>::fmt(self, f)
+    }
+}
@@ -0,0 +1,192 @@
+//! Helpers for generating the timestamps used in task definitions, such as `created`,
+//! `deadline`, and `expires`, equivalent to the `fromNow` function of other Taskcluster client
+//! libraries.
+//!
+//! ```
+//! # use anyhow::Result;
+//! # fn main() -> Result<()> {
+//! use taskcluster::time;
+//! use serde_json::json;
+//!
+//! let task = json!({
+//!     "created": time::now(),
+//!     "deadline": time::from_now("1 day 2 hours")?,
+//!     "expires": time::from_now("1 year")?,
+//!     // ...
+//! });
+//! # Ok(())
+//! # }
+//! ```
+use anyhow::{anyhow, Error};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+
+/// The units of a time expression, in the order they must appear, with their accepted spellings
+/// and lengths in seconds.  As in other Taskcluster clients, a month is 30 days and a year is 365
+/// days.
+const UNITS: &[(&[&str], i64)] = &[
+    (&["y", "yr", "year", "years"], 365 * 24 * 60 * 60),
+    (&["mo", "month", "months"], 30 * 24 * 60 * 60),
+    (&["w", "wk", "week", "weeks"], 7 * 24 * 60 * 60),
+    (&["d", "day", "days"], 24 * 60 * 60),
+    (&["h", "hr", "hour", "hours"], 60 * 60),
+    (&["m", "min", "minute", "minutes"], 60),
+    (&["s", "sec", "second", "seconds"], 1),
+];
+
+/// Format the given time as a timestamp in the format expected by Taskcluster APIs, the same as
+/// that of the JS `Date.toJSON` method, such as `2030-01-01T00:00:00.000Z`.
+pub fn timestamp(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Return a timestamp for the current time, such as for a task's `created` property.
+pub fn now() -> String {
+    timestamp(Utc::now())
+}
+
+/// Return a timestamp for the current time plus the given offset, a time expression as accepted
+/// by [`parse_offset`], such as `1 day 2 hours` or `-30 min`.
+pub fn from_now(offset: &str) -> Result<String, Error> {
+    Utc::now()
+        .checked_add_signed(parse_offset(offset)?)
+        .map(timestamp)
+        .ok_or_else(|| anyhow!("Time expression {:?} is out of range", offset))
+}
+
+/// Parse a time expression, such as `1 day 2 hours` or `1d2h`, into a duration.
+///
+/// An expression is a sequence of numbers, each followed by a unit, with the units in the order
+/// years, months, weeks, days, hours, minutes, and seconds, each appearing at most once.  Units
+/// may be abbreviated, as in `2 hr` or `3min`, and whitespace is optional.  An expression
+/// starting with `-` is a negative duration, and an empty expression is zero.
+pub fn parse_offset(offset: &str) -> Result<Duration, Error> {
+    let invalid = || anyhow!("{:?} is not a time expression", offset);
+
+    let mut rest = offset.trim_start();
+    let mut sign = 1;
+    if let Some(r) = rest.strip_prefix('-') {
+        sign = -1;
+        rest = r;
+    } else if let Some(r) = rest.strip_prefix('+') {
+        rest = r;
+    }
+
+    let mut seconds: i64 = 0;
+    let mut next_unit = 0;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let count: i64 = rest[..digits].parse().map_err(|_| invalid())?;
+        rest = rest[digits..].trim_start();
+
+        let letters = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let unit = rest[..letters].to_ascii_lowercase();
+        rest = &rest[letters..];
+
+        let i = UNITS[next_unit..]
+            .iter()
+            .position(|(names, _)| names.contains(&unit.as_str()))
+            .ok_or_else(invalid)?
+            + next_unit;
+        next_unit = i + 1;
+
+        seconds = count
+            .checked_mul(UNITS[i].1)
+            .and_then(|s| s.checked_add(seconds))
+            .ok_or_else(invalid)?;
+    }
+
+    seconds
+        .checked_mul(1000)
+        .map(|ms| Duration::milliseconds(sign * ms))
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    macro_rules! parse_offset_tests {
+        ($($name:ident: $input:expr, $seconds:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert_eq!(parse_offset($input).unwrap(), Duration::seconds($seconds));
+            }
+        )*
+        }
+    }
+
+    parse_offset_tests! {
+        empty: "", 0,
+        seconds: "10 seconds", 10,
+        minutes_short: "5min", 5 * 60,
+        minutes_m: "5 m", 5 * 60,
+        hours: "2 hours", 2 * 60 * 60,
+        days_and_hours: "1 day 2 hours", 26 * 60 * 60,
+        compact: "1d2h3min", 26 * 60 * 60 + 3 * 60,
+        weeks: "2 wk", 14 * 24 * 60 * 60,
+        months: "1 month", 30 * 24 * 60 * 60,
+        months_short: "3mo", 90 * 24 * 60 * 60,
+        years: "1 year", 365 * 24 * 60 * 60,
+        all_units: "1y 1mo 1w 1d 1h 1m 1s", 403 * 24 * 60 * 60 + 60 * 60 + 60 + 1,
+        case_insensitive: "1 Day 2 HOURS", 26 * 60 * 60,
+        whitespace: "  1 d  2 h  ", 26 * 60 * 60,
+        negative: "-1 hour", -60 * 60,
+        negative_spaced: " - 1d 2h", -26 * 60 * 60,
+        positive: "+30 sec", 30,
+    }
+
+    macro_rules! parse_offset_error_tests {
+        ($($name:ident: $input:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                assert!(parse_offset($input).is_err());
+            }
+        )*
+        }
+    }
+
+    parse_offset_error_tests! {
+        no_unit: "10",
+        no_number: "hours",
+        unknown_unit: "3 fortnights",
+        out_of_order: "2 hours 1 day",
+        repeated_unit: "1 day 1 day",
+        fraction: "1.5 hours",
+        trailing_garbage: "1 day!",
+        overflow: "99999999999999999 years",
+    }
+
+    #[test]
+    fn test_timestamp() -> Result<(), Error> {
+        let time: DateTime<Utc> = "2030-01-02T03:04:05.678901Z".parse()?;
+        assert_eq!(timestamp(time), "2030-01-02T03:04:05.678Z");
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_now() -> Result<(), Error> {
+        let before = Utc::now();
+        let deadline: DateTime<Utc> = from_now("1 day 2 hours")?.parse()?;
+        let after = Utc::now();
+        // allow for the timestamp's truncation to milliseconds
+        assert!(deadline >= before + Duration::hours(26) - Duration::milliseconds(1));
+        assert!(deadline <= after + Duration::hours(26));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_now_invalid() {
+        assert!(from_now("tomorrow").is_err());
+    }
+}
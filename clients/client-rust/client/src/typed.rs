@@ -0,0 +1,164 @@
+/*!
+Typed versions of the service clients.  This module is only available with the `typed` feature.
+
+Each client type in this module has the same request methods as the corresponding type at the
+top level of this crate, with the same arguments, except that the payload and the result are
+structs generated from the method's input and output schemas rather than `serde_json::Value`.
+The generated structs for each service are in a module named for it, such as
+[`queue`](crate::typed::queue).  Fields that the schema does not require are `Option`s, and
+schemas that have no simple Rust equivalent, such as those using `oneOf`, remain `Value`s.
+
+```
+# use httptest::{matchers::*, responders::*, Expectation, Server};
+# use anyhow::Result;
+# #[tokio::main]
+# async fn main() -> Result<()> {
+# let server = Server::run();
+# server.expect(
+#    Expectation::matching(request::method_path("GET", "/api/secrets/v1/secret/my-secret"))
+#   .respond_with(
+#       status_code(200)
+#       .append_header("Content-Type", "application/json")
+#       .body("{\"secret\": {\"password\": \"hunter2\"}, \"expires\": \"2030-01-01T00:00:00.000Z\"}"))
+# );
+# let root_url = format!("http://{}", server.addr());
+use taskcluster::typed::Secrets;
+let secrets = Secrets::new(root_url)?;
+let secret = secrets.get("my-secret").await?;
+assert_eq!(secret.secret["password"], "hunter2");
+# Ok(())
+# }
+```
+
+A response that does not match the output schema is an error.  The untyped client is available
+from each typed client's `untyped` method, such as for generating URLs or for methods whose
+responses are better handled as a `Value`.
+*/
+
+pub use crate::generated::typed::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ClientBuilder;
+    use anyhow::Error;
+    use chrono::{DateTime, Utc};
+    use futures_util::stream::TryStreamExt;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+    use serde_json::json;
+
+    fn client_builder(server: &Server) -> ClientBuilder {
+        ClientBuilder::new(format!("http://{}", server.addr()))
+    }
+
+    #[tokio::test]
+    async fn test_typed_output() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/task/abc/status"))
+                .respond_with(json_encoded(json!({
+                    "status": {
+                        "taskId": "abc",
+                        "provisionerId": "proj",
+                        "workerType": "test",
+                        "taskQueueId": "proj/test",
+                        "schedulerId": "-",
+                        "projectId": "none",
+                        "taskGroupId": "grp",
+                        "deadline": "2030-01-02T00:00:00.000Z",
+                        "expires": "2031-01-01T00:00:00.000Z",
+                        "retriesLeft": 5,
+                        "state": "pending",
+                        "runs": [{
+                            "runId": 0,
+                            "state": "pending",
+                            "reasonCreated": "scheduled",
+                            "scheduled": "2030-01-01T00:00:00.000Z",
+                        }],
+                    },
+                }))),
+        );
+        let queue = Queue::new(client_builder(&server))?;
+
+        let status = queue.status("abc").await?.status;
+        assert_eq!(status.task_queue_id, "proj/test");
+        assert_eq!(
+            status.deadline,
+            "2030-01-02T00:00:00Z".parse::<DateTime<Utc>>()?
+        );
+        assert_eq!(status.runs[0].run_id, 0);
+        assert_eq!(status.runs[0].resolved, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_typed_output_invalid() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/api/queue/v1/task/abc/status"))
+                .respond_with(json_encoded(json!({"status": {"taskId": "abc"}}))),
+        );
+        let queue = Queue::new(client_builder(&server))?;
+
+        let err = queue.status("abc").await.unwrap_err();
+        assert_eq!(err.to_string(), "Invalid status response");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_typed_input() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("PUT", "/api/secrets/v1/secret/my-secret"),
+                // optional fields that are not set are omitted
+                request::body(json_decoded(eq(json!({
+                    "secret": {"password": "hunter2"},
+                    "expires": "2030-01-01T00:00:00Z",
+                })))),
+            ])
+            .respond_with(status_code(200)),
+        );
+        let secrets = Secrets::new(client_builder(&server))?;
+
+        secrets
+            .set(
+                "my-secret",
+                &secrets::Secret {
+                    secret: json!({"password": "hunter2"}),
+                    expires: "2030-01-01T00:00:00Z".parse()?,
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_typed_paginated() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/secrets/v1/secrets"),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .respond_with(json_encoded(json!({
+                "secrets": ["a"],
+                "continuationToken": "next",
+            }))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/secrets/v1/secrets"),
+                request::query(url_decoded(contains(("continuationToken", "next")))),
+            ])
+            .respond_with(json_encoded(json!({"secrets": ["b"]}))),
+        );
+        let secrets = Secrets::new(client_builder(&server))?;
+
+        let pages: Vec<secrets::SecretList> = secrets.list_paginated(None).try_collect().await?;
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].continuation_token, Some("next".to_owned()));
+        assert_eq!(pages[1].secrets, vec!["b"]);
+        Ok(())
+    }
+}
@@ -1,6 +1,16 @@
+use crate::ApiError;
+use anyhow::{anyhow, bail, Error};
+use bytes::Bytes;
+use chrono::{DateTime, TimeZone, Utc};
+use futures_util::stream::{self, Stream, TryStreamExt};
 use percent_encoding::{utf8_percent_encode, AsciiSet, PercentEncode, NON_ALPHANUMERIC};
+use reqwest::header::{HeaderMap, DATE, RETRY_AFTER, WWW_AUTHENTICATE};
 use reqwest::StatusCode;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::io;
 use std::iter::{FromIterator, IntoIterator, Iterator};
+use std::time::Duration;
 
 // based on https://docs.python.org/3/library/urllib.parse.html#urllib.parse.quote
 // which defines what the Python client does here
@@ -10,30 +20,185 @@ const NOT_ENCODED: &AsciiSet = &NON_ALPHANUMERIC
     .remove(b'-')
     .remove(b'~');
 
+/// Percent-encode a path parameter.  The generated clients pass every path parameter through
+/// this, so that a parameter containing `/` or other special characters remains a single path
+/// segment.
 pub(crate) fn urlencode(input: &str) -> PercentEncode {
     utf8_percent_encode(input, NOT_ENCODED)
 }
 
-/// If this error was due to a reqwest error created from an HTTP response, return the status code
-/// from that response.  If the error is not a `reqwest::Error`, or was not caused by an HTTP
+/// If this error was due to an HTTP response, return the status code from that response.  If the
+/// error is not an [`ApiError`](crate::ApiError) or `reqwest::Error`, or was not caused by an HTTP
 /// response, returns None.
 pub fn err_status_code(err: &anyhow::Error) -> Option<StatusCode> {
-    if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+    if let Some(err) = err.downcast_ref::<ApiError>() {
+        err.status()
+    } else if let Some(err) = err.downcast_ref::<reqwest::Error>() {
         err.status()
     } else {
         None
     }
 }
 
+/// Add context to a 404 error, to identify which lookup failed; other errors are unchanged.
+pub(crate) fn context_if_not_found<F: FnOnce() -> String>(
+    err: anyhow::Error,
+    message: F,
+) -> anyhow::Error {
+    if err_status_code(&err) == Some(StatusCode::NOT_FOUND) {
+        err.context(message())
+    } else {
+        err
+    }
+}
+
 pub(crate) fn collect_scopes<R: FromIterator<String>>(
     scopes: impl IntoIterator<Item = impl AsRef<str>>,
 ) -> R {
     scopes.into_iter().map(|s| s.as_ref().to_string()).collect()
 }
 
+/// Stream the items in the array under `key` in a page of results.
+pub(crate) fn page_items(
+    mut page: Value,
+    key: &str,
+) -> Result<impl Stream<Item = Result<Value, Error>>, Error> {
+    match page.get_mut(key).map(Value::take) {
+        Some(Value::Array(items)) => Ok(stream::iter(items.into_iter().map(Ok))),
+        _ => bail!("Response has no array {}", key),
+    }
+}
+
+/// Parse a `Retry-After` header, in either of its forms (a number of seconds, or an HTTP date), into
+/// the duration to wait before retrying.  Returns None if the header is missing or invalid.
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    // a date in the past means "retry now"
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_else(|_| Duration::from_secs(0)),
+    )
+}
+
+/// Parse the server's time from the headers of a response rejecting a request whose Hawk
+/// timestamp is too far from the server's clock.  This is the `ts` attribute of a Hawk
+/// `WWW-Authenticate` header, such as `Hawk ts="1365741469", tsm="...", error="Stale timestamp"`,
+/// if present, as Hawk specifies for correcting clock skew, and otherwise the `Date` header.
+/// Returns None if neither gives a valid time.
+pub(crate) fn parse_server_time(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+    parse_hawk_ts(headers).or_else(|| {
+        let value = headers.get(DATE)?.to_str().ok()?.trim();
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        Some(date.with_timezone(&Utc))
+    })
+}
+
+/// Parse the `ts` attribute of a Hawk `WWW-Authenticate` header, if any.
+fn parse_hawk_ts(headers: &HeaderMap) -> Option<DateTime<Utc>> {
+    let value = headers.get(WWW_AUTHENTICATE)?.to_str().ok()?.trim();
+    let mut parts = value.splitn(2, ' ');
+    if !parts.next()?.eq_ignore_ascii_case("hawk") {
+        return None;
+    }
+    let ts = parts.next()?.split(',').find_map(|attribute| {
+        let mut parts = attribute.trim().splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some("ts"), Some(value)) => value.trim_matches('"').parse::<i64>().ok(),
+            _ => None,
+        }
+    })?;
+    Utc.timestamp_opt(ts, 0).single()
+}
+
+/// Read the body of the given response, failing if it is larger than `max_bytes`, if given.  The
+/// body is read in chunks, so that an oversized body is not buffered in full, even if it has no
+/// `Content-Length`.
+pub(crate) async fn read_body(
+    resp: reqwest::Response,
+    max_bytes: Option<usize>,
+) -> Result<Bytes, Error> {
+    let max = match max_bytes {
+        Some(max) => max,
+        None => return Ok(resp.bytes().await?),
+    };
+    let too_large = || anyhow!("Response body exceeds the maximum of {} bytes", max);
+
+    if resp.content_length().map_or(false, |len| len > max as u64) {
+        return Err(too_large());
+    }
+    let mut body = Vec::new();
+    let mut chunks = resp.bytes_stream();
+    while let Some(chunk) = chunks.try_next().await? {
+        if body.len() + chunk.len() > max {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body.into())
+}
+
+/// Determine whether an error from sending a request or receiving its response is likely to be
+/// transient, and thus worth retrying.  Timeouts, dropped connections, and temporary DNS
+/// resolution failures are retried, while errors in building the request, host names that do
+/// not exist, and TLS failures such as invalid certificates are not.
+pub(crate) fn is_retryable(err: &reqwest::Error) -> bool {
+    if err.is_builder() || err.is_redirect() || err.is_decode() {
+        return false;
+    }
+    if err.is_timeout() {
+        return true;
+    }
+    let mut tls = false;
+    let mut source = err.source();
+    while let Some(cause) = source {
+        // an I/O error, such as a refused or reset connection, is the underlying failure, even
+        // during a TLS handshake
+        if let Some(err) = cause.downcast_ref::<io::Error>() {
+            return !is_host_not_found(err);
+        }
+        tls |= cause.is::<native_tls::Error>();
+        source = cause.source();
+    }
+    // a TLS failure not caused by I/O, such as an invalid certificate, will fail again, while
+    // anything else, such as a connection closed before the response was complete, may succeed
+    // on a retry
+    !tls
+}
+
+/// The messages with which system resolvers report that a host name does not exist, as opposed
+/// to a temporary failure such as `EAI_AGAIN`.  The standard library reports resolver failures
+/// on Unix with no more specific kind than this message.
+const HOST_NOT_FOUND: &[&str] = &[
+    // glibc EAI_NONAME and EAI_NODATA
+    "Name or service not known",
+    "No address associated with hostname",
+    // musl EAI_NONAME
+    "Name does not resolve",
+    // macOS and BSD EAI_NONAME
+    "nodename nor servname provided, or not known",
+];
+
+/// Windows' WSAHOST_NOT_FOUND, which it reports as the OS error of a failed resolution
+const WSAHOST_NOT_FOUND: i32 = 11001;
+
+/// Determine whether an I/O error is a resolver's report that a host name does not exist.
+fn is_host_not_found(err: &io::Error) -> bool {
+    if cfg!(windows) && err.raw_os_error() == Some(WSAHOST_NOT_FOUND) {
+        return true;
+    }
+    let message = err.to_string();
+    HOST_NOT_FOUND.iter().any(|m| message.ends_with(m))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{ClientBuilder, Index, Queue};
     use httptest::{matchers::*, responders::*, Expectation, Server};
     use tokio;
 
@@ -53,6 +218,264 @@ mod test {
         slashes: "abc/def", "abc%2Fdef",
         spaces: "abc def", "abc%20def",
         control: "abc\ndef", "abc%0Adef",
+        unicode: "caf\u{e9}", "caf%C3%A9",
+    }
+
+    /// A path parameter containing characters that must be encoded, and its encoding
+    const SPECIAL_PARAM: &str = "a.b/c d\u{e9}";
+    const SPECIAL_PARAM_ENCODED: &str = "a.b%2Fc%20d%C3%A9";
+
+    #[test]
+    fn generated_url_encodes_path_params() -> Result<(), anyhow::Error> {
+        let index = Index::new(ClientBuilder::new("https://tc-tests.example.com"))?;
+        assert_eq!(
+            index.findTask_url(SPECIAL_PARAM)?,
+            format!(
+                "https://tc-tests.example.com/api/index/v1/task/{}",
+                SPECIAL_PARAM_ENCODED
+            )
+        );
+
+        let queue = Queue::new(ClientBuilder::new("https://tc-tests.example.com"))?;
+        assert_eq!(
+            queue.getArtifact_url("abc", "0", SPECIAL_PARAM)?,
+            format!(
+                "https://tc-tests.example.com/api/queue/v1/task/abc/runs/0/artifacts/{}",
+                SPECIAL_PARAM_ENCODED
+            )
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn generated_request_encodes_path_params() -> Result<(), anyhow::Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path(
+                "GET",
+                "/api/index/v1/task/a.b%2Fc%20d%C3%A9",
+            ))
+            .times(1)
+            .respond_with(status_code(200).body("{}")),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let index = Index::new(ClientBuilder::new(&root_url))?;
+        index.findTask(SPECIAL_PARAM).await?;
+        Ok(())
+    }
+
+    fn retry_after_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        assert_eq!(
+            parse_retry_after(&retry_after_headers("120")),
+            Some(Duration::from_secs(120))
+        );
+    }
+
+    #[test]
+    fn retry_after_past_date() {
+        assert_eq!(
+            parse_retry_after(&retry_after_headers("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Some(Duration::from_secs(0))
+        );
+    }
+
+    #[test]
+    fn retry_after_future_date() {
+        let date = (Utc::now() + chrono::Duration::hours(1)).to_rfc2822();
+        let delay = parse_retry_after(&retry_after_headers(&date)).unwrap();
+        assert!(delay > Duration::from_secs(3500) && delay <= Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn retry_after_invalid() {
+        assert_eq!(parse_retry_after(&retry_after_headers("soon")), None);
+        assert_eq!(parse_retry_after(&HeaderMap::new()), None);
+    }
+
+    fn date_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(DATE, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn server_time() {
+        assert_eq!(
+            parse_server_time(&date_headers("Tue, 01 Jan 2030 00:00:00 GMT")),
+            "2030-01-01T00:00:00Z".parse::<DateTime<Utc>>().ok()
+        );
+    }
+
+    #[test]
+    fn server_time_hawk_ts() {
+        let mut headers = date_headers("Tue, 01 Jan 2030 00:00:00 GMT");
+        headers.insert(
+            WWW_AUTHENTICATE,
+            r#"Hawk ts="1893456060", tsm="abc=", error="Stale timestamp""#
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            parse_server_time(&headers),
+            "2030-01-01T00:01:00Z".parse::<DateTime<Utc>>().ok()
+        );
+    }
+
+    #[test]
+    fn server_time_bare_hawk() {
+        let mut headers = date_headers("Tue, 01 Jan 2030 00:00:00 GMT");
+        headers.insert(WWW_AUTHENTICATE, "hawk".parse().unwrap());
+        assert_eq!(
+            parse_server_time(&headers),
+            "2030-01-01T00:00:00Z".parse::<DateTime<Utc>>().ok()
+        );
+    }
+
+    #[test]
+    fn server_time_invalid() {
+        assert_eq!(parse_server_time(&date_headers("soon")), None);
+        assert_eq!(parse_server_time(&HeaderMap::new()), None);
+        let mut headers = HeaderMap::new();
+        headers.insert(WWW_AUTHENTICATE, r#"Hawk ts="soon""#.parse().unwrap());
+        assert_eq!(parse_server_time(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn retryable_builder_error() {
+        let err = reqwest::Client::new()
+            .get("http://example.com/")
+            .header("bad header\n", "value")
+            .build()
+            .unwrap_err();
+        assert!(err.is_builder());
+        assert!(!is_retryable(&err));
+    }
+
+    #[tokio::test]
+    async fn retryable_timeout() {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(request::method_path("GET", "/"))
+                .respond_with(delay_and_then(Duration::from_secs(5), status_code(200))),
+        );
+        let err = reqwest::Client::new()
+            .get(&format!("http://{}/", server.addr()))
+            .timeout(Duration::from_millis(10))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(err.is_timeout());
+        assert!(is_retryable(&err));
+    }
+
+    #[tokio::test]
+    async fn retryable_connection_refused() {
+        // bind a port, then close it so nothing is listening there
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let err = reqwest::Client::new()
+            .get(&format!("http://{}/", addr))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(err.is_connect());
+        assert!(is_retryable(&err));
+    }
+
+    #[tokio::test]
+    async fn retryable_connection_closed() {
+        // a server that closes the connection without responding
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            use std::io::Read;
+            let (mut conn, _) = listener.accept().unwrap();
+            let _ = conn.read(&mut [0u8; 1024]);
+        });
+        let err = reqwest::Client::new()
+            .get(&format!("http://{}/", addr))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(is_retryable(&err));
+    }
+
+    // this needs a working resolver, without which the lookup fails temporarily, and is retryable
+    #[tokio::test]
+    #[ignore]
+    async fn retryable_dns_error() {
+        // the `.invalid` TLD is guaranteed not to resolve
+        let err = reqwest::Client::new()
+            .get("http://no-such-host.invalid/")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(!is_retryable(&err));
+    }
+
+    #[tokio::test]
+    async fn retryable_tls_error() {
+        // a server that responds to the TLS handshake with plain HTTP
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            use std::io::{Read, Write};
+            let (mut conn, _) = listener.accept().unwrap();
+            let _ = conn.read(&mut [0u8; 1024]);
+            let _ = conn.write_all(b"HTTP/1.1 400 Bad Request\r\ncontent-length: 0\r\n\r\n");
+        });
+        let err = reqwest::Client::new()
+            .get(&format!("https://{}/", addr))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(!is_retryable(&err));
+    }
+
+    #[test]
+    fn host_not_found() {
+        for message in HOST_NOT_FOUND {
+            let err = io::Error::new(
+                io::ErrorKind::Other,
+                format!("failed to lookup address information: {}", message),
+            );
+            assert!(
+                is_host_not_found(&err),
+                "{:?} is not host-not-found",
+                message
+            );
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn host_not_found_windows() {
+        let err = io::Error::from_raw_os_error(WSAHOST_NOT_FOUND);
+        assert!(is_host_not_found(&err));
+    }
+
+    #[test]
+    fn host_not_found_temporary_failure() {
+        let err = io::Error::new(
+            io::ErrorKind::Other,
+            "failed to lookup address information: Temporary failure in name resolution",
+        );
+        assert!(!is_host_not_found(&err));
+    }
+
+    #[test]
+    fn host_not_found_reset() {
+        let err = io::Error::new(io::ErrorKind::ConnectionReset, "Connection reset by peer");
+        assert!(!is_host_not_found(&err));
     }
 
     #[tokio::test]
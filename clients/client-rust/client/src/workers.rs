@@ -0,0 +1,330 @@
+use crate::util::{page_items, urlencode};
+use crate::{Credentials, WorkerManager};
+use anyhow::{anyhow, Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::future;
+use futures_util::stream::{Stream, TryStreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// The result of registering a worker with
+/// [`WorkerManager::register_worker`](crate::WorkerManager::register_worker).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredWorker {
+    /// Time at which the credentials expire.  The worker must re-register or terminate before
+    /// this time.
+    pub expires: DateTime<Utc>,
+
+    /// Credentials for the worker, suitable for use with
+    /// [`ClientBuilder::credentials`](crate::ClientBuilder::credentials).
+    pub credentials: Credentials,
+
+    /// Configuration for the worker, from the worker pool definition
+    pub worker_config: Value,
+
+    /// A secret for use in calls to `reregisterWorker`
+    pub secret: String,
+}
+
+/// A worker, as returned by `listWorkersForWorkerPool`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Worker {
+    /// The worker pool to which this worker belongs
+    pub worker_pool_id: String,
+
+    /// The worker group to which this worker belongs
+    pub worker_group: String,
+
+    /// The worker's ID
+    pub worker_id: String,
+
+    /// The provider that started the worker and is responsible for managing it
+    pub provider_id: String,
+
+    /// The time at which the worker was created
+    pub created: DateTime<Utc>,
+
+    /// The time at which the worker will be deleted
+    pub expires: DateTime<Utc>,
+
+    /// The time at which the worker last changed state
+    pub last_modified: DateTime<Utc>,
+
+    /// The time at which the worker's state was last verified with the provider
+    pub last_checked: DateTime<Utc>,
+
+    /// The worker's state: one of `requested`, `running`, `stopping`, or `stopped`
+    pub state: String,
+
+    /// The number of tasks the worker can handle at once
+    pub capacity: u32,
+}
+
+impl WorkerManager {
+    /// Stream all workers in all worker pools, optionally only those in the given `state`, such
+    /// as `running`.  This lists the worker pools with `listWorkerPools`, and then the workers
+    /// in each pool with `listWorkersForWorkerPool`, following the pagination of both.  The
+    /// service does not filter workers by state, so workers in other states are still fetched,
+    /// but are not included in the stream.
+    pub fn list_workers<'a>(
+        &'a self,
+        state: Option<&'a str>,
+    ) -> impl Stream<Item = Result<Worker, Error>> + 'a {
+        self.listWorkerPools_paginated(None)
+            .and_then(|page| future::ready(page_items(page, "workerPools")))
+            .try_flatten()
+            .and_then(|pool| future::ready(worker_pool_id(&pool)))
+            .map_ok(move |worker_pool_id| {
+                let path = format!("workers/{}", urlencode(&worker_pool_id));
+                self.0
+                    .paginate("GET", &path, None)
+                    .and_then(|page| future::ready(page_items(page, "workers")))
+                    .try_flatten()
+            })
+            .try_flatten()
+            .and_then(|worker| {
+                future::ready(
+                    serde_json::from_value::<Worker>(worker)
+                        .context("Invalid worker in listWorkersForWorkerPool response"),
+                )
+            })
+            .try_filter(move |worker| future::ready(state.map_or(true, |s| worker.state == s)))
+    }
+
+    /// Register a running worker, returning the credentials it should use to perform its work.
+    /// The form of the `worker_identity_proof` depends on the provider.
+    ///
+    /// ```no_run
+    /// # use anyhow::Result;
+    /// # use serde_json::json;
+    /// # async fn f() -> Result<()> {
+    /// use taskcluster::{ClientBuilder, Queue, WorkerManager};
+    ///
+    /// let root_url = "https://tc.example.com";
+    /// let wm = WorkerManager::new(ClientBuilder::new(root_url))?;
+    /// let registered = wm
+    ///     .register_worker(
+    ///         "proj/pool",
+    ///         "static",
+    ///         "my-group",
+    ///         "my-worker",
+    ///         &json!({"staticSecret": "..."}),
+    ///     )
+    ///     .await?;
+    /// let queue = Queue::new(ClientBuilder::new(root_url).credentials(registered.credentials))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn register_worker(
+        &self,
+        worker_pool_id: &str,
+        provider_id: &str,
+        worker_group: &str,
+        worker_id: &str,
+        worker_identity_proof: &Value,
+    ) -> Result<RegisteredWorker, Error> {
+        let res = self
+            .registerWorker(&json!({
+                "workerPoolId": worker_pool_id,
+                "providerId": provider_id,
+                "workerGroup": worker_group,
+                "workerId": worker_id,
+                "workerIdentityProof": worker_identity_proof,
+            }))
+            .await?;
+        Ok(serde_json::from_value(res)?)
+    }
+}
+
+fn worker_pool_id(pool: &Value) -> Result<String, Error> {
+    pool.get("workerPoolId")
+        .and_then(Value::as_str)
+        .map(str::to_owned)
+        .ok_or_else(|| anyhow!("Worker pool in listWorkerPools response has no workerPoolId"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::json_response;
+    use crate::ClientBuilder;
+    use httptest::{matchers::*, responders::*, Expectation, Server};
+
+    const POOLS_PATH: &str = "/api/worker-manager/v1/worker-pools";
+
+    fn worker(worker_pool_id: &str, worker_id: &str, state: &str) -> Value {
+        json!({
+            "workerPoolId": worker_pool_id,
+            "workerGroup": "grp",
+            "workerId": worker_id,
+            "providerId": "static",
+            "created": "2030-01-01T00:00:00.000Z",
+            "expires": "2030-01-08T00:00:00.000Z",
+            "lastModified": "2030-01-01T00:10:00.000Z",
+            "lastChecked": "2030-01-01T00:20:00.000Z",
+            "state": state,
+            "capacity": 1,
+        })
+    }
+
+    /// Expect two pages of worker pools, the first pool having two pages of workers and the
+    /// second a single page
+    fn expect_pages(server: &Server) {
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", POOLS_PATH),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "workerPools": [{"workerPoolId": "proj/a"}],
+                "continuationToken": "pools2",
+            }))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", POOLS_PATH),
+                request::query(url_decoded(contains(("continuationToken", "pools2")))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "workerPools": [{"workerPoolId": "proj/b"}],
+            }))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/worker-manager/v1/workers/proj%2Fa"),
+                request::query(url_decoded(not(contains(key("continuationToken"))))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "workers": [worker("proj/a", "w1", "running")],
+                "continuationToken": "workers2",
+            }))),
+        );
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("GET", "/api/worker-manager/v1/workers/proj%2Fa"),
+                request::query(url_decoded(contains(("continuationToken", "workers2")))),
+            ])
+            .times(1)
+            .respond_with(json_response(json!({
+                "workers": [worker("proj/a", "w2", "stopped")],
+            }))),
+        );
+        server.expect(
+            Expectation::matching(request::method_path(
+                "GET",
+                "/api/worker-manager/v1/workers/proj%2Fb",
+            ))
+            .times(1)
+            .respond_with(json_response(json!({
+                "workers": [worker("proj/b", "w3", "running")],
+            }))),
+        );
+    }
+
+    fn worker_manager(server: &Server) -> Result<WorkerManager, Error> {
+        let root_url = format!("http://{}", server.addr());
+        WorkerManager::new(ClientBuilder::new(&root_url))
+    }
+
+    #[tokio::test]
+    async fn test_list_workers() -> Result<(), Error> {
+        let server = Server::run();
+        expect_pages(&server);
+
+        let wm = worker_manager(&server)?;
+        let workers: Vec<Worker> = wm.list_workers(None).try_collect().await?;
+        let ids: Vec<_> = workers
+            .iter()
+            .map(|w| (w.worker_pool_id.as_str(), w.worker_id.as_str()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![("proj/a", "w1"), ("proj/a", "w2"), ("proj/b", "w3")]
+        );
+        assert_eq!(workers[1].state, "stopped");
+        assert_eq!(
+            workers[0].last_checked,
+            "2030-01-01T00:20:00Z".parse::<DateTime<Utc>>()?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_workers_state() -> Result<(), Error> {
+        let server = Server::run();
+        expect_pages(&server);
+
+        let wm = worker_manager(&server)?;
+        let workers: Vec<Worker> = wm.list_workers(Some("running")).try_collect().await?;
+        let ids: Vec<_> = workers.iter().map(|w| w.worker_id.as_str()).collect();
+        assert_eq!(ids, vec!["w1", "w3"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_register_worker() -> Result<(), Error> {
+        let server = Server::run();
+        server.expect(
+            Expectation::matching(all_of![
+                request::method_path("POST", "/api/worker-manager/v1/worker/register"),
+                request::body(json_decoded(eq(json!({
+                    "workerPoolId": "proj/pool",
+                    "providerId": "static",
+                    "workerGroup": "grp",
+                    "workerId": "wkr",
+                    "workerIdentityProof": {"staticSecret": "sekrit"},
+                })))),
+            ])
+            .times(1)
+            .respond_with(
+                status_code(200)
+                    .append_header("Content-Type", "application/json")
+                    .body(
+                        json!({
+                            "expires": "2030-01-01T00:00:00.000Z",
+                            "credentials": {
+                                "clientId": "worker/proj/pool/grp/wkr",
+                                "accessToken": "token",
+                                "certificate": "{}",
+                            },
+                            "workerConfig": {"x": 1},
+                            "secret": "s",
+                        })
+                        .to_string(),
+                    ),
+            ),
+        );
+        let root_url = format!("http://{}", server.addr());
+        let wm = WorkerManager::new(ClientBuilder::new(&root_url))?;
+
+        let registered = wm
+            .register_worker(
+                "proj/pool",
+                "static",
+                "grp",
+                "wkr",
+                &json!({"staticSecret": "sekrit"}),
+            )
+            .await?;
+        assert_eq!(
+            registered.credentials,
+            Credentials {
+                client_id: "worker/proj/pool/grp/wkr".into(),
+                access_token: "token".into(),
+                certificate: Some("{}".into()),
+            }
+        );
+        assert_eq!(
+            registered.expires,
+            "2030-01-01T00:00:00Z".parse::<DateTime<Utc>>()?
+        );
+        assert_eq!(registered.worker_config, json!({"x": 1}));
+        assert_eq!(registered.secret, "s");
+        Ok(())
+    }
+}
@@ -330,3 +330,78 @@ async fn test_auth_expand_scopes() -> Result<()> {
 
     Ok(())
 }
+
+/// Test an authenticated call over HTTP/2.  Not every deployment accepts HTTP/2 without
+/// negotiation, so this only runs if TASKCLUSTER_TEST_HTTP2 is also set.
+#[tokio::test]
+async fn test_auth_http2() -> Result<()> {
+    let auth = match (get_root_url(), env::var("TASKCLUSTER_TEST_HTTP2")) {
+        (Some(root_url), Ok(_)) => {
+            let creds = Credentials::new("tester", "no-secret");
+            Auth::new(
+                ClientBuilder::new(&root_url)
+                    .http2_prior_knowledge()
+                    .credentials(creds),
+            )?
+        }
+        _ => return Ok(()),
+    };
+
+    let res = auth
+        .testAuthenticate(&json!({
+            "clientScopes": ["test:authenticate"],
+            "requiredScopes": ["test:authenticate"],
+        }))
+        .await?;
+    assert_eq!(res.get("clientId"), Some(&json!("tester")));
+
+    Ok(())
+}
+
+/// Test a call to a deployment whose TLS certificate is issued by a private CA.  This only runs
+/// if TASKCLUSTER_TEST_ROOT_CA is also set, to the path of the CA's PEM-encoded certificate.
+#[tokio::test]
+async fn test_auth_ping_root_certificate() -> Result<()> {
+    let auth = match (get_root_url(), env::var("TASKCLUSTER_TEST_ROOT_CA")) {
+        (Some(root_url), Ok(ca_path)) => {
+            let cert = reqwest::Certificate::from_pem(&std::fs::read(ca_path)?)?;
+            Auth::new(ClientBuilder::new(&root_url).add_root_certificate(cert))?
+        }
+        _ => return Ok(()),
+    };
+
+    auth.ping().await?;
+    Ok(())
+}
+
+/// Test a call to a deployment fronted by mutual TLS, presenting a client certificate.  This only
+/// runs if TASKCLUSTER_TEST_CLIENT_IDENTITY is also set, to the path of a PKCS#12 archive holding
+/// the certificate and key, with its password in TASKCLUSTER_TEST_CLIENT_IDENTITY_PASSWORD if it
+/// has one.
+#[tokio::test]
+async fn test_auth_ping_identity() -> Result<()> {
+    let auth = match (get_root_url(), env::var("TASKCLUSTER_TEST_CLIENT_IDENTITY")) {
+        (Some(root_url), Ok(identity_path)) => {
+            let password =
+                env::var("TASKCLUSTER_TEST_CLIENT_IDENTITY_PASSWORD").unwrap_or_default();
+            let identity =
+                reqwest::Identity::from_pkcs12_der(&std::fs::read(identity_path)?, &password)?;
+            Auth::new(ClientBuilder::new(&root_url).identity(identity))?
+        }
+        _ => return Ok(()),
+    };
+
+    auth.ping().await?;
+    Ok(())
+}
+
+/// Test a call with certificate verification disabled, which should succeed whether or not the
+/// deployment's certificate is valid
+#[tokio::test]
+async fn test_auth_ping_danger_accept_invalid_certs() -> Result<()> {
+    if let Some(root_url) = get_root_url() {
+        let auth = Auth::new(ClientBuilder::new(&root_url).danger_accept_invalid_certs(true))?;
+        auth.ping().await?;
+    }
+    Ok(())
+}
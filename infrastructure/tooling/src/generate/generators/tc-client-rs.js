@@ -12,14 +12,15 @@ const MOD_TEMPLATE = t => `
 #![allow(unused_imports)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 /* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
-use crate::{Client, ClientBuilder, Credentials, Retry};
+use crate::{Client, ClientBuilder, Credentials, PingResponse, Retry};
 use anyhow::Error;
+use futures_util::stream::Stream;
 use serde_json::Value;
 use std::time::Duration;
 use crate::util::urlencode;
 
 ${t.description}
-pub struct ${t.className} (Client);
+pub struct ${t.className} (pub(crate) Client);
 
 #[allow(non_snake_case)]
 impl ${t.className} {
@@ -29,6 +30,72 @@ impl ${t.className} {
             .into()
             .path_prefix("api/${t.serviceName}/${t.apiVersion}/")
             .build()?))
+    }
+
+    /// Check the health of the service, returning the parsed response from its \`ping\` endpoint.
+    pub async fn health(&self) -> Result<PingResponse, Error> {
+        self.0.ping().await
+    }
+
+    /// Check whether the service is up, returning false if it cannot be reached or does not
+    /// report that it is alive.
+    pub async fn is_alive(&self) -> bool {
+        self.0.is_alive().await
+    }
+
+    /// Create a copy of this ${t.className} instance whose requests carry the given authorized scopes.
+    /// See [\`Client::with_authorized_scopes\`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self(self.0.with_authorized_scopes(scopes)?))
+    }${t.methods}
+}`;
+
+const BLOCKING_MOD_TEMPLATE = t => `
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::{ClientBuilder, Credentials, PingResponse, Retry};
+use crate::blocking::{block_on_stream, new_runtime};
+use anyhow::Error;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [\`${t.className}\`](crate::${t.className}).  Each method blocks the
+/// current thread until the corresponding asynchronous method completes.
+pub struct ${t.className} {
+    client: crate::${t.className},
+    runtime: Runtime,
+}
+
+#[allow(non_snake_case)]
+impl ${t.className} {
+    /// Create a new blocking ${t.className} instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::${t.className}::new(client_builder)?,
+            runtime: new_runtime()?,
+        })
+    }
+
+    /// See [\`${t.className}::health\`](crate::${t.className}::health).
+    pub fn health(&self) -> Result<PingResponse, Error> {
+        self.runtime.block_on(self.client.health())
+    }
+
+    /// See [\`${t.className}::is_alive\`](crate::${t.className}::is_alive).
+    pub fn is_alive(&self) -> bool {
+        self.runtime.block_on(self.client.is_alive())
+    }
+
+    /// Create a copy of this ${t.className} instance, with its own runtime, whose requests carry the
+    /// given authorized scopes.
+    /// See [\`Client::with_authorized_scopes\`](crate::Client::with_authorized_scopes).
+    pub fn with_authorized_scopes(&self, scopes: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            client: self.client.with_authorized_scopes(scopes)?,
+            runtime: new_runtime()?,
+        })
     }${t.methods}
 }`;
 
@@ -36,8 +103,10 @@ impl ${t.className} {
 const with_self = args => [{ name: '&self' }].concat(args);
 const without_payload = args => args.filter(({ name }) => name !== 'payload');
 const with_ttl = args => args.concat([{ name: 'ttl', type: 'Duration' }]);
+const without_continuation_token = args => args.filter(({ name }) => name !== 'continuationToken');
 const with_lifetimes = args => args.map(({ name, type }) => ({ name, type: type.replace(/&str/g, "&'a str") }));
 const call_args = args => args.map(({ name }) => name).join(', ');
+const call_args_no_continuation_token = args => args.map(({ name }) => name === 'continuationToken' ? 'None' : name).join(', ');
 const define_args = args => args.map(({ name, type }) => name === '&self' ? name : `${name}: ${type}`).join(', ');
 
 const QUERY_TEMPLATE = t => `\
@@ -45,6 +114,15 @@ const QUERY_TEMPLATE = t => `\
         query.get_or_insert_with(Vec::new).push(("${t.name}", q));
     }`;
 
+const PAGINATED_FUNC_TEMPLATE = t => `\
+/// Stream all pages of results from the ${t.name} endpoint, following \`continuationToken\`
+pub fn ${t.name}_paginated<'a>(${define_args(with_self(with_lifetimes(without_continuation_token(t.args)))).replace('&self', "&'a self")}) -> impl Stream<Item = Result<Value, Error>> + 'a {
+    let method = "${t.method.toUpperCase()}";
+    let (path, query) = Self::${t.name}_details(${call_args_no_continuation_token(without_payload(t.args))});
+    self.0.paginate(method, ${t.staticPath ? 'path' : '&path'}, query)
+}
+`;
+
 const DETAILS_FUNC_TEMPLATE = t => `\
 /// Determine the HTTP request details for ${t.name}
 fn ${t.name}_details<'a>(${define_args(with_lifetimes(without_payload(t.args)))}) -> (${t.staticPath ? "&'static str" : 'String'}, Option<Vec<(&'static str, &'a str)>>) {
@@ -60,15 +138,36 @@ ${t.query.map(name => QUERY_TEMPLATE({ name })).join('\n')}\
 }
 `;
 
+// long-polling API methods, which may legitimately take longer than the usual timeout
+const LONG_POLL_METHODS = [
+  'queue.claimWork',
+];
+
+// API methods whose normal response is a 303 redirect, with the output in its body.  These
+// return that body rather than treating the redirect as an error, as \`request_json\` would.
+const REDIRECT_METHODS = [
+  'queue.getArtifact',
+  'queue.getLatestArtifact',
+];
+
+// the arguments to Client::request or Client::request_json, with a longer timeout for
+// long-polling methods
+const request_call = (fn, t) => t.longPoll ?
+  `${fn}_with_timeout(method, ${t.staticPath ? 'path' : '&path'}, query, body, self.0.long_poll_timeout())` :
+  `${fn}(method, ${t.staticPath ? 'path' : '&path'}, query, body)`;
+
 const REQ_FUNC_TEMPLATE = t => `\
 ${t.doc}pub async fn ${t.name}(${define_args(with_self(t.args))}) -> Result<${t.okResult}, Error> {
     let method = "${t.method.toUpperCase()}";
     let (path, query) = Self::${t.name}_details(${call_args(without_payload(t.args))});
     let body = ${t.input ? 'Some(payload)' : 'None'};
-    let resp = self.0.request(method, ${t.staticPath ? 'path' : '&path'}, query, body).await?;
-${t.output ? `\
+${t.output && t.redirect ? `\
+    let resp = self.0.${request_call('request', t)}.await?;
     Ok(resp.json().await?)\
+` : t.output ? `\
+    self.0.${request_call('request_json', t)}.await\
 ` : `\
+    let resp = self.0.${request_call('request', t)}.await?;
     resp.bytes().await?;
     Ok(())`}
 }
@@ -90,8 +189,328 @@ pub fn ${t.name}_signed_url(${define_args(with_ttl(with_self(t.args)))}) -> Resu
 }
 `;
 
-const generateServiceClient = (className, reference) => {
+const BLOCKING_REQ_FUNC_TEMPLATE = t => `\
+/// ${t.title || t.name}
+///
+/// See [\`${t.className}::${t.name}\`](crate::${t.className}::${t.name}).
+pub fn ${t.name}(${define_args(with_self(t.args))}) -> Result<${t.okResult}, Error> {
+    self.runtime.block_on(self.client.${t.name}(${call_args(t.args)}))
+}
+`;
+
+const BLOCKING_PAGINATED_FUNC_TEMPLATE = t => `\
+/// Iterate over all pages of results from the ${t.name} endpoint, following \`continuationToken\`
+pub fn ${t.name}_paginated<'a>(${define_args(with_self(with_lifetimes(without_continuation_token(t.args)))).replace('&self', "&'a self")}) -> impl Iterator<Item = Result<Value, Error>> + 'a {
+    block_on_stream(&self.runtime, self.client.${t.name}_paginated(${call_args(without_continuation_token(t.args))}))
+}
+`;
+
+const BLOCKING_URL_FUNC_TEMPLATE = t => `\
+/// Generate an unsigned URL for the ${t.name} endpoint
+pub fn ${t.name}_url(${define_args(with_self(t.args))}) -> Result<String, Error> {
+    self.client.${t.name}_url(${call_args(t.args)})
+}
+`;
+
+const BLOCKING_SIGNED_URL_FUNC_TEMPLATE = t => `\
+/// Generate a signed URL for the ${t.name} endpoint
+pub fn ${t.name}_signed_url(${define_args(with_ttl(with_self(t.args)))}) -> Result<String, Error> {
+    self.client.${t.name}_signed_url(${call_args(with_ttl(t.args))})
+}
+`;
+
+const TYPED_MOD_TEMPLATE = t => `
+#![allow(unused_imports)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+/* THIS FILE IS AUTOMATICALLY GENERATED. DO NOT EDIT */
+use crate::ClientBuilder;
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Typed version of [\`${t.className}\`](crate::${t.className}).  Each method takes and returns
+/// the types in this module, generated from the service's schemas, in place of
+/// \`serde_json::Value\`.  A schema that cannot be expressed as a Rust type, such as one with
+/// \`oneOf\`, is still represented as a \`Value\`.
+pub struct ${t.className} {
+    client: crate::${t.className},
+}
+
+#[allow(non_snake_case)]
+impl ${t.className} {
+    /// Create a new typed ${t.className} instance, based on the given client.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        Ok(Self {
+            client: crate::${t.className}::new(client_builder)?,
+        })
+    }
+
+    /// The untyped client wrapped by this instance, such as for generating URLs.
+    pub fn untyped(&self) -> &crate::${t.className} {
+        &self.client
+    }${t.methods}
+}${t.types}`;
+
+const TYPED_REQ_FUNC_TEMPLATE = t => `\
+/// ${t.title || t.name}
+///
+/// See [\`${t.className}::${t.name}\`](crate::${t.className}::${t.name}).
+pub async fn ${t.name}(${define_args(with_self(t.args))}) -> Result<${t.okResult}, Error> {
+${t.input && t.inputType !== 'Value' ? `\
+    let payload = &serde_json::to_value(payload)?;
+` : ''}\
+${t.output && t.okResult !== 'Value' ? `\
+    let output = self.client.${t.name}(${call_args(t.args)}).await?;
+    serde_json::from_value(output).context("Invalid ${t.name} response")\
+` : `\
+    self.client.${t.name}(${call_args(t.args)}).await\
+`}
+}
+`;
+
+const TYPED_PAGINATED_FUNC_TEMPLATE = t => `\
+/// Stream all pages of results from the ${t.name} endpoint, following \`continuationToken\`
+pub fn ${t.name}_paginated<'a>(${define_args(with_self(with_lifetimes(without_continuation_token(t.args)))).replace('&self', "&'a self")}) -> impl Stream<Item = Result<${t.okResult}, Error>> + 'a {
+${t.okResult !== 'Value' ? `\
+    self.client.${t.name}_paginated(${call_args(without_continuation_token(t.args))}).map(|page| {
+        serde_json::from_value::<${t.okResult}>(page?).context("Invalid ${t.name} response")
+    })\
+` : `\
+    self.client.${t.name}_paginated(${call_args(without_continuation_token(t.args))})\
+`}
+}
+`;
+
+const TYPED_STRUCT_TEMPLATE = t => `\
+${t.doc}#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ${t.name} {
+${t.fields.map(f => `\
+${f.doc}${f.attrs.length ? `    #[serde(${f.attrs.join(', ')})]\n` : ''}\
+    pub ${f.name}: ${f.type},
+`).join('\n')}\
+}
+`;
+
+// Rust keywords that may not be used as field names
+const RUST_KEYWORDS = [
+  'as', 'async', 'await', 'break', 'const', 'continue', 'crate', 'dyn', 'else', 'enum', 'extern',
+  'false', 'fn', 'for', 'if', 'impl', 'in', 'let', 'loop', 'match', 'mod', 'move', 'mut', 'pub',
+  'ref', 'return', 'self', 'static', 'struct', 'super', 'trait', 'true', 'type', 'unsafe', 'use',
+  'where', 'while', 'abstract', 'become', 'box', 'do', 'final', 'macro', 'override', 'priv',
+  'typeof', 'unsized', 'virtual', 'yield', 'try',
+];
+
+// type names used in the typed modules, which generated structs must not shadow
+const RESERVED_TYPE_NAMES = [
+  'ClientBuilder', 'Context', 'DateTime', 'Deserialize', 'Error', 'HashMap', 'Option', 'Result',
+  'Serialize', 'Stream', 'StreamExt', 'String', 'Utc', 'Value', 'Vec', 'Box',
+];
+
+const pascalCase = s => s
+  .split(/[^a-zA-Z0-9]+/)
+  .filter(w => w.length)
+  .map(w => `${w[0].toUpperCase()}${w.slice(1)}`)
+  .join('');
+
+const snakeCase = s => {
+  let name = s
+    .replace(/([a-z0-9])([A-Z])/g, '$1_$2')
+    .replace(/([A-Z]+)([A-Z][a-z])/g, '$1_$2')
+    .replace(/[^a-zA-Z0-9]+/g, '_')
+    .replace(/^_+|_+$/g, '')
+    .toLowerCase();
+  if (/^[0-9]/.test(name)) {
+    name = `_${name}`;
+  }
+  return RUST_KEYWORDS.includes(name) ? `${name}_` : name;
+};
+
+// the first paragraph of a schema's description, or its title, as doc comment lines
+const schemaDoc = (schema, indent) => {
+  const text = (schema.description || schema.title || '').trim().split(/\n\s*\n/)[0];
+  if (!text) {
+    return '';
+  }
+  return text.split('\n').map(l => `${indent}///${l.trim().length ? ` ${l.trim()}` : ''}\n`).join('');
+};
+
+// Translates the JSON schemas of one service's methods into Rust types, generating a struct for
+// each object schema with properties.  Schemas that have no simple Rust equivalent, such as
+// those with \`oneOf\`, are represented as \`Value\`.
+class TypeGenerator {
+  constructor(schemas, className) {
+    // schemas by $id
+    this.schemas = schemas;
+    // names of generated structs, by the key of the schema from which they are generated
+    this.names = new Map();
+    // keys of the schemas from which structs are generated, by struct name
+    this.keys = new Map(RESERVED_TYPE_NAMES.concat([className]).map(name => [name, null]));
+    // generated structs, in the order they were first used
+    this.structs = [];
+  }
+
+  // Get the Rust type for the schema with the given $id
+  typeForId(id) {
+    const schema = this.schemas.get(id);
+    if (!schema) {
+      return 'Value';
+    }
+    return this.typeFor(schema, { id, key: id, name: pascalCase(path.posix.basename(id, '.json#')) });
+  }
+
+  // Resolve a $ref relative to the schema with the given $id, returning the referenced schema,
+  // the $id of the schema containing it, a unique key for it, and a candidate type name.  A
+  // reference to part of a schema, such as \`upload-method-put-url.json#/definitions/request\`,
+  // is named for both the schema and the part, such as \`UploadMethodPutUrlRequest\`.
+  resolve(ref, baseId) {
+    const [file, pointer = ''] = ref.split('#');
+    const id = file.length ?
+      `${path.posix.resolve(path.posix.dirname(baseId.replace(/#$/, '')), file)}#` :
+      baseId;
+    let schema = this.schemas.get(id);
+    const segments = pointer.split('/').filter(s => s.length)
+      .map(s => s.replace(/~1/g, '/').replace(/~0/g, '~'));
+    for (const segment of segments) {
+      schema = schema && schema[segment];
+    }
+    let name = pascalCase(path.posix.basename(id, '.json#'));
+    if (segments.length) {
+      name += pascalCase(segments[segments.length - 1]);
+    }
+    return { schema, id, key: `${id}${pointer}`, name };
+  }
+
+  typeFor(schema, { id, key, name }) {
+    if (schema.$ref) {
+      const resolved = this.resolve(schema.$ref, id);
+      if (!resolved.schema) {
+        return 'Value';
+      }
+      return this.typeFor(resolved.schema, resolved);
+    }
+    if (schema.oneOf || schema.anyOf || schema.allOf || schema.not) {
+      return 'Value';
+    }
+
+    let type = schema.type;
+    let nullable = false;
+    if (Array.isArray(type)) {
+      nullable = type.includes('null');
+      type = type.filter(t => t !== 'null');
+      if (type.length !== 1) {
+        return 'Value';
+      }
+      type = type[0];
+    }
+    if (type === undefined && schema.properties) {
+      type = 'object';
+    }
+
+    let rustType;
+    switch (type) {
+      case 'string':
+        rustType = schema.format === 'date-time' ? 'DateTime<Utc>' : 'String';
+        break;
+      case 'integer':
+        rustType = 'i64';
+        break;
+      case 'number':
+        rustType = 'f64';
+        break;
+      case 'boolean':
+        rustType = 'bool';
+        break;
+      case 'array':
+        if (schema.items && !Array.isArray(schema.items)) {
+          rustType = `Vec<${this.typeFor(schema.items, { id, key: `${key}/items`, name: `${name}Item` })}>`;
+        } else {
+          rustType = 'Vec<Value>';
+        }
+        break;
+      case 'object':
+        if (schema.properties && Object.keys(schema.properties).length) {
+          rustType = this.structFor(schema, { id, key, name });
+        } else if (schema.additionalProperties && typeof schema.additionalProperties === 'object' &&
+          Object.keys(schema.additionalProperties).length) {
+          const valueType = this.typeFor(schema.additionalProperties, {
+            id, key: `${key}/additionalProperties`, name: `${name}Value`,
+          });
+          rustType = `HashMap<String, ${valueType}>`;
+        } else {
+          rustType = 'Value';
+        }
+        break;
+      default:
+        return 'Value';
+    }
+
+    return nullable ? `Option<${rustType}>` : rustType;
+  }
+
+  // Get the name of the struct for the given object schema, generating it if necessary
+  structFor(schema, { id, key, name }) {
+    if (this.names.has(key)) {
+      return this.names.get(key);
+    }
+
+    // disambiguate structs generated from different schemas with the same name by prefixing
+    // the name of the file containing the schema
+    if (this.keys.has(name)) {
+      const prefixed = `${pascalCase(path.posix.basename(id, '.json#'))}${name}`;
+      name = prefixed;
+      for (let i = 2; this.keys.has(name); i++) {
+        name = `${prefixed}${i}`;
+      }
+    }
+    this.names.set(key, name);
+    this.keys.set(name, key);
+
+    const struct = { name, doc: schemaDoc(schema, ''), fields: [] };
+    this.structs.push(struct);
+
+    const required = schema.required || [];
+    for (const [prop, propSchema] of Object.entries(schema.properties)) {
+      const fieldName = snakeCase(prop);
+      let type = this.typeFor(propSchema, {
+        id, key: `${key}/properties/${prop}`, name: `${name}${pascalCase(prop)}`,
+      });
+      if (type === name) {
+        type = `Box<${type}>`;
+      }
+      const attrs = [];
+      if (fieldName !== prop) {
+        attrs.push(`rename = "${prop}"`);
+      }
+      if (!required.includes(prop)) {
+        if (!type.startsWith('Option<')) {
+          type = `Option<${type}>`;
+        }
+        attrs.push('default', 'skip_serializing_if = "Option::is_none"');
+      }
+      // a field whose schema is only a reference is documented by the referenced schema
+      const docSchema = propSchema.$ref && !propSchema.description ?
+        this.resolve(propSchema.$ref, id).schema || {} :
+        propSchema;
+      struct.fields.push({ name: fieldName, type, attrs, doc: schemaDoc(docSchema, '    ') });
+    }
+
+    return name;
+  }
+
+  // Generate the Rust source for all structs
+  generate() {
+    return this.structs.map(struct => '\n\n' + TYPED_STRUCT_TEMPLATE(struct).trim()).join('');
+  }
+}
+
+const generateServiceClient = (className, reference, schemas) => {
   const methods = [];
+  const blockingMethods = [];
+  const typedMethods = [];
+  const types = new TypeGenerator(schemas, className);
 
   for (let entry of reference.entries) {
     if (entry.type !== 'function') {
@@ -103,6 +522,9 @@ const generateServiceClient = (className, reference) => {
     // * pub methodName -- calls the method
     // * pub methodName_url -- generates a URL for the method (GET only)
     // * pub methodName_signed_url -- generates a signed URL (GET only)
+    // * pub methodName_paginated -- streams all pages of results (paginated methods only)
+    //
+    // and, in the typed client, methodName and methodName_paginated with typed input and output
 
     // "template" variables for the templates defined above
     const t = {
@@ -118,16 +540,28 @@ const generateServiceClient = (className, reference) => {
       path: undefined,
       // true if there's an input payload
       input: undefined,
+      // type of the input payload in the typed client
+      inputType: undefined,
       // true if there's an ouptut body
       output: undefined,
       // type of the Ok variant of the result
       okResult: undefined,
+      // type of the Ok variant of the result in the typed client
+      typedOkResult: undefined,
       // true if there are query parameters
       hasQuery: undefined,
       // vec of query parameters
       query: undefined,
       // documentation comments
       doc: undefined,
+      // method title
+      title: entry.title,
+      // true if this is a long-polling method
+      longPoll: LONG_POLL_METHODS.includes(`${reference.serviceName}.${entry.name}`),
+      // true if this method responds with a redirect whose body is the output
+      redirect: REDIRECT_METHODS.includes(`${reference.serviceName}.${entry.name}`),
+      // name of the service client type
+      className,
     };
 
     // slice off the leading `/` in the route
@@ -160,15 +594,18 @@ const generateServiceClient = (className, reference) => {
 
     if (entry.input) {
       t.input = true;
+      t.inputType = types.typeForId(`/schemas/${reference.serviceName}/${entry.input}`);
       t.args.push({ name: 'payload', type: '&Value' });
     }
 
     if (entry.output) {
       t.output = true;
       t.okResult = 'Value';
+      t.typedOkResult = types.typeForId(`/schemas/${reference.serviceName}/${entry.output}`);
     } else {
       t.output = false;
       t.okResult = '()';
+      t.typedOkResult = '()';
     }
 
     if (entry.query.length > 0) {
@@ -203,7 +640,33 @@ const generateServiceClient = (className, reference) => {
       methods.push('\n\n' + indent(SIGNED_URL_FUNC_TEMPLATE(t)));
     }
 
+    if (t.hasQuery && t.query.includes('continuationToken') && t.output && !t.input) {
+      methods.push('\n\n' + indent(PAGINATED_FUNC_TEMPLATE(t)));
+    }
+
     methods.push('\n\n' + indent(DETAILS_FUNC_TEMPLATE(t)));
+
+    blockingMethods.push('\n\n' + indent(BLOCKING_REQ_FUNC_TEMPLATE(t)));
+    if (entry.method === 'get') {
+      blockingMethods.push('\n\n' + indent(BLOCKING_URL_FUNC_TEMPLATE(t)));
+      blockingMethods.push('\n\n' + indent(BLOCKING_SIGNED_URL_FUNC_TEMPLATE(t)));
+    }
+    if (t.hasQuery && t.query.includes('continuationToken') && t.output && !t.input) {
+      blockingMethods.push('\n\n' + indent(BLOCKING_PAGINATED_FUNC_TEMPLATE(t)));
+    }
+
+    const typed = {
+      ...t,
+      args: t.args.map(({ name, type }) => ({
+        name,
+        type: name === 'payload' ? `&${t.inputType}` : type,
+      })),
+      okResult: t.typedOkResult,
+    };
+    typedMethods.push('\n\n' + indent(TYPED_REQ_FUNC_TEMPLATE(typed)));
+    if (t.hasQuery && t.query.includes('continuationToken') && t.output && !t.input) {
+      typedMethods.push('\n\n' + indent(TYPED_PAGINATED_FUNC_TEMPLATE(typed)));
+    }
   }
 
   let description = reference.description.split('\n');
@@ -213,45 +676,97 @@ const generateServiceClient = (className, reference) => {
   }
   description = description.map(l => `///${l.length ? ` ${l}` : ''}`).join('\n');
 
-  return MOD_TEMPLATE({
-    serviceName: reference.serviceName,
-    apiVersion: reference.apiVersion,
-    description,
-    className,
-    methods: methods.join(''),
-  });
+  return {
+    client: MOD_TEMPLATE({
+      serviceName: reference.serviceName,
+      apiVersion: reference.apiVersion,
+      description,
+      className,
+      methods: methods.join(''),
+    }),
+    blockingClient: BLOCKING_MOD_TEMPLATE({
+      className,
+      methods: blockingMethods.join(''),
+    }),
+    typedClient: TYPED_MOD_TEMPLATE({
+      className,
+      methods: typedMethods.join(''),
+      types: types.generate(),
+    }),
+  };
 };
 
-const generateModFile = apis => {
+const SERVICES_TEMPLATE = services => `
+use crate::ClientBuilder;
+use anyhow::Error;
+
+/// Clients for all Taskcluster services, built from a single configuration.  The clients share
+/// one HTTP client, and with it a pool of connections to the deployment.
+pub struct Taskcluster {
+${services.map(({ className, fieldName }) => `    ${fieldName}: ${className},`).join('\n')}
+}
+
+impl Taskcluster {
+    /// Create clients for all services from the given builder, such as
+    /// \`ClientBuilder::new(root_url).credentials(credentials)\`.
+    pub fn new<CB: Into<ClientBuilder>>(client_builder: CB) -> Result<Self, Error> {
+        let client_builder = client_builder.into().share_http_client()?;
+        Ok(Self {
+${services.map(({ className, fieldName }) => `            ${fieldName}: ${className}::new(client_builder.clone())?,`).join('\n')}
+        })
+    }
+${services.map(({ className, fieldName }) => `
+    /// The client for the ${className} service
+    pub fn ${fieldName}(&self) -> &${className} {
+        &self.${fieldName}
+    }`).join('\n')}
+}`;
+
+const generateModFile = (apis, { blocking, typed }) => {
   const mods = [];
   const uses = [];
+  const services = [];
 
   for (let [className, { referenceKind }] of Object.entries(apis)) {
     if (referenceKind !== 'api') {
       continue;
     }
     const moduleName = className.toLowerCase();
-    mods.push(`mod ${moduleName};`);
+    mods.push(`${typed ? 'pub ' : ''}mod ${moduleName};`);
     uses.push(`pub use ${moduleName}::${className};`);
+    services.push({ className, fieldName: className.replace(/([a-z])([A-Z])/g, '$1_$2').toLowerCase() });
+  }
+  if (blocking || typed) {
+    return `${mods.sort().join('\n')}\n\n${uses.sort().join('\n')}\n`;
   }
-  return `${mods.sort().join('\n')}\n\n${uses.sort().join('\n')}\n`;
+  services.sort((a, b) => a.fieldName < b.fieldName ? -1 : 1);
+  return `#[cfg(feature = "blocking")]\npub(crate) mod blocking;\n#[cfg(feature = "typed")]\npub(crate) mod typed;\n\n${mods.sort().join('\n')}\n\n${uses.sort().join('\n')}\n${SERVICES_TEMPLATE(services)}\n`;
 };
 
 exports.tasks = [{
   title: 'Generate Taskcluster-Client-Rust',
-  requires: ['apis'],
+  requires: ['apis', 'references-json'],
   provides: ['target-taskcluster-client-rust'],
   run: async (requirements, utils) => {
     const apis = requirements['apis'];
+    const schemas = new Map(requirements['references-json']
+      .filter(({ content }) => content.$id)
+      .map(({ content }) => [content.$id, content]));
     const moduleDir = path.join(REPO_ROOT, 'clients', 'client-rust', 'client', 'src', 'generated');
+    const blockingModuleDir = path.join(moduleDir, 'blocking');
+    const typedModuleDir = path.join(moduleDir, 'typed');
 
     // clean up the clients directory to eliminate any "leftovers"
     utils.status({ message: 'cleanup' });
     await rimraf(moduleDir);
     await mkdirp(moduleDir);
+    await mkdirp(blockingModuleDir);
+    await mkdirp(typedModuleDir);
 
     utils.status({ message: 'mod.rs' });
-    await writeRsFile(path.join(moduleDir, 'mod.rs'), generateModFile(apis));
+    await writeRsFile(path.join(moduleDir, 'mod.rs'), generateModFile(apis, { blocking: false }));
+    await writeRsFile(path.join(blockingModuleDir, 'mod.rs'), generateModFile(apis, { blocking: true }));
+    await writeRsFile(path.join(typedModuleDir, 'mod.rs'), generateModFile(apis, { typed: true }));
 
     for (let [className, { reference, referenceKind }] of Object.entries(apis)) {
       if (referenceKind !== 'api') {
@@ -260,7 +775,10 @@ exports.tasks = [{
       const moduleName = className.toLowerCase();
 
       utils.status({ message: `${moduleName}.rs` });
-      await writeRsFile(path.join(moduleDir, `${moduleName}.rs`), generateServiceClient(className, reference));
+      const { client, blockingClient, typedClient } = generateServiceClient(className, reference, schemas);
+      await writeRsFile(path.join(moduleDir, `${moduleName}.rs`), client);
+      await writeRsFile(path.join(blockingModuleDir, `${moduleName}.rs`), blockingClient);
+      await writeRsFile(path.join(typedModuleDir, `${moduleName}.rs`), typedClient);
     }
   },
 }];